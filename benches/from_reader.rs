@@ -0,0 +1,64 @@
+//! Compares loading binary glTF through `Gltf::from_reader`, which reads the
+//! chunks one at a time, with buffering the whole file through
+//! `binary::Glb::from_reader` first.
+//!
+//! Run with `cargo bench --bench from_reader`.
+
+extern crate gltf;
+
+use gltf::binary::Glb;
+use std::borrow::Cow;
+use std::time::{Duration, Instant};
+use std::{fs, io};
+
+/// Size of the `BIN` chunk of the benchmarked asset.
+const BIN_LENGTH: usize = 64 * 1024 * 1024;
+
+/// Number of times each path is run.
+const ITERATIONS: u32 = 10;
+
+fn large_glb() -> Vec<u8> {
+    let data = fs::read("examples/Box.glb").unwrap();
+    let glb = Glb::from_slice(&data).unwrap();
+    let large = Glb {
+        header: glb.header,
+        json: glb.json,
+        bin: Some(Cow::Owned(vec![0; BIN_LENGTH])),
+    };
+    large.to_vec().unwrap()
+}
+
+fn buffered(data: &[u8]) -> gltf::Gltf {
+    let glb = Glb::from_reader(io::Cursor::new(data)).unwrap();
+    let json = gltf::json::deserialize::from_slice(&glb.json).unwrap();
+    let document = gltf::Document::from_json_without_validation(json);
+    let blob = glb.bin.map(Cow::into_owned);
+    gltf::Gltf { document, blob }
+}
+
+fn streamed(data: &[u8]) -> gltf::Gltf {
+    gltf::Gltf::from_reader_without_validation(io::Cursor::new(data)).unwrap()
+}
+
+fn measure<F: Fn(&[u8]) -> gltf::Gltf>(name: &str, data: &[u8], load: F) {
+    let mut total = Duration::new(0, 0);
+    for _ in 0 .. ITERATIONS {
+        let start = Instant::now();
+        let gltf = load(data);
+        total += start.elapsed();
+        assert_eq!(gltf.blob.map(|blob| blob.len()), Some(BIN_LENGTH));
+    }
+    let average = total / ITERATIONS;
+    println!(
+        "{:>8}: {}.{:03} ms per load",
+        name,
+        average.as_secs() * 1000 + u64::from(average.subsec_millis()),
+        average.subsec_micros() % 1000,
+    );
+}
+
+fn main() {
+    let data = large_glb();
+    measure("buffered", &data, buffered);
+    measure("streamed", &data, streamed);
+}
@@ -20,6 +20,29 @@ pub fn main(input: TokenStream) -> TokenStream {
     tokens.parse().unwrap()
 }
 
+/// Returns the JSON property name of a field, which is the `rename` of its
+/// `#[serde]` attribute if present and the camel case field name otherwise.
+fn json_name(field: &syn::Field) -> String {
+    use inflections::Inflect;
+    use syn::{Lit, MetaItem, NestedMetaItem};
+
+    for attr in &field.attrs {
+        if let MetaItem::List(ref name, ref items) = attr.value {
+            if name != "serde" {
+                continue;
+            }
+            for item in items {
+                if let NestedMetaItem::MetaItem(MetaItem::NameValue(ref key, Lit::Str(ref value, _))) = *item {
+                    if key == "rename" {
+                        return value.clone();
+                    }
+                }
+            }
+        }
+    }
+    field.ident.as_ref().unwrap().as_ref().to_camel_case()
+}
+
 fn expand(ast: &syn::MacroInput) -> quote::Tokens {
     let fields = match ast.body {
         syn::Body::Struct(syn::VariantData::Struct(ref fields)) => fields,
@@ -27,10 +50,8 @@ fn expand(ast: &syn::MacroInput) -> quote::Tokens {
     };
     let ident = &ast.ident;
     let minimal_validations: Vec<quote::Tokens> = fields.iter()
-        .map(|f| f.ident.as_ref().unwrap())
-        .map(|ident| {
-            use inflections::Inflect;
-            let field = ident.as_ref().to_camel_case();
+        .map(|f| (f.ident.as_ref().unwrap(), json_name(f)))
+        .map(|(ident, field)| {
             quote!(
                 self.#ident.validate_minimally(
                     _root,
@@ -41,10 +62,8 @@ fn expand(ast: &syn::MacroInput) -> quote::Tokens {
         })
         .collect();
     let complete_validations: Vec<quote::Tokens> = fields.iter()
-        .map(|f| f.ident.as_ref().unwrap())
-        .map(|ident| {
-            use inflections::Inflect;
-            let field = ident.as_ref().to_camel_case();
+        .map(|f| (f.ident.as_ref().unwrap(), json_name(f)))
+        .map(|(ident, field)| {
             quote!(
                 self.#ident.validate_completely(
                     _root,
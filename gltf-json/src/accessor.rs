@@ -5,7 +5,7 @@ use std::fmt;
 use validation::Checked;
 
 /// The component data type.
-#[derive(Clone, Copy, Debug, Deserialize)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
 pub enum ComponentType {
     /// Corresponds to `GL_BYTE`.
     I8 = 1,
@@ -27,7 +27,7 @@ pub enum ComponentType {
 }
 
 /// Specifies whether an attribute, vector, or matrix.
-#[derive(Clone, Copy, Debug, Deserialize)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
 pub enum Type {
     /// Scalar quantity.
     Scalar = 1,
@@ -186,8 +186,11 @@ pub mod sparse {
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
 pub struct Accessor {
     /// The parent buffer view this accessor reads from.
-    #[serde(rename = "bufferView")]
-    pub buffer_view: Index<buffer::View>,
+    ///
+    /// When not defined, the accessor must be initialized with zeros, to
+    /// which sparse substitution may then be applied.
+    #[serde(rename = "bufferView", skip_serializing_if = "Option::is_none")]
+    pub buffer_view: Option<Index<buffer::View>>,
 
     /// The offset relative to the start of the parent `BufferView` in bytes.
     #[serde(default, rename = "byteOffset")]
@@ -400,4 +403,54 @@ impl Type {
             Mat4 => 16,
         }
     }
+
+    /// Returns `true` if this type is a matrix.
+    pub fn is_matrix(&self) -> bool {
+        match *self {
+            Type::Mat2 | Type::Mat3 | Type::Mat4 => true,
+            _ => false,
+        }
+    }
+
+    /// Returns the number of bytes of one element of this type with
+    /// components of type `component_type`.
+    ///
+    /// Each column of a matrix starts on a 4-byte boundary, so the columns of
+    /// `MAT2` and `MAT3` matrices of 1-byte components and of `MAT3` matrices
+    /// of 2-byte components are padded. This is also the stride of tightly
+    /// packed elements.
+    pub fn element_size(&self, component_type: ComponentType) -> usize {
+        let columns = match *self {
+            Type::Mat2 => 2,
+            Type::Mat3 => 3,
+            Type::Mat4 => 4,
+            _ => return self.multiplicity() * component_type.size(),
+        };
+        let column_size = columns * component_type.size();
+        columns * ((column_size + 3) / 4 * 4)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ComponentType, Type};
+
+    #[test]
+    fn matrix_element_size() {
+        assert!(Type::Mat3.is_matrix());
+        assert!(!Type::Vec4.is_matrix());
+
+        assert_eq!(Type::Mat3.multiplicity(), 9);
+        // Each 3 byte column is padded to 4 bytes.
+        assert_eq!(Type::Mat3.element_size(ComponentType::U8), 12);
+        // Each 6 byte column is padded to 8 bytes.
+        assert_eq!(Type::Mat3.element_size(ComponentType::I16), 24);
+        assert_eq!(Type::Mat3.element_size(ComponentType::F32), 36);
+
+        assert_eq!(Type::Mat2.element_size(ComponentType::U8), 8);
+        assert_eq!(Type::Mat2.element_size(ComponentType::U16), 8);
+        assert_eq!(Type::Mat4.element_size(ComponentType::I8), 16);
+        assert_eq!(Type::Vec3.element_size(ComponentType::U8), 3);
+        assert_eq!(Type::Scalar.element_size(ComponentType::U32), 4);
+    }
 }
@@ -1,9 +1,17 @@
+#[cfg(feature = "extensions")]
+use serde_json::{Map, Value};
+
 /// A camera's projection.
 ///
 /// A node can reference a camera to apply a transform to place the camera in the
 /// scene.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Camera {}
+pub struct Camera {
+    /// Unrecognized extensions, keyed by extension name.
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: Map<String, Value>,
+}
 
 /// Values for an orthographic camera.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
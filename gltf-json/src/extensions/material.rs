@@ -1,6 +1,108 @@
+#[cfg(feature = "extensions")]
+use serde_json::{Map, Value};
+#[cfg(any(
+    feature = "KHR_materials_clearcoat",
+    feature = "KHR_materials_emissive_strength",
+    feature = "KHR_materials_ior",
+    feature = "KHR_materials_sheen",
+    feature = "KHR_materials_specular",
+    feature = "KHR_materials_transmission",
+    feature = "KHR_materials_volume",
+))]
+use validation::{Error, Validate};
+#[cfg(any(
+    feature = "KHR_materials_clearcoat",
+    feature = "KHR_materials_emissive_strength",
+    feature = "KHR_materials_ior",
+    feature = "KHR_materials_sheen",
+    feature = "KHR_materials_specular",
+    feature = "KHR_materials_transmission",
+    feature = "KHR_materials_volume",
+))]
+use {Path, Root};
+#[cfg(any(
+    feature = "KHR_materials_clearcoat",
+    feature = "KHR_materials_sheen",
+    feature = "KHR_materials_specular",
+    feature = "KHR_materials_transmission",
+    feature = "KHR_materials_volume",
+))]
+use texture;
+#[cfg(feature = "KHR_materials_clearcoat")]
+use material::NormalTexture as MaterialNormalTexture;
+
 /// The material appearance of a primitive.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Material {}
+pub struct Material {
+    /// Specifies a clear coating layer on top of the material.
+    #[cfg(feature = "KHR_materials_clearcoat")]
+    #[serde(
+        default,
+        rename = "KHR_materials_clearcoat",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub clearcoat: Option<Clearcoat>,
+
+    /// Scales the emissive factor beyond the [0.0, 1.0] range.
+    #[cfg(feature = "KHR_materials_emissive_strength")]
+    #[serde(
+        default,
+        rename = "KHR_materials_emissive_strength",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub emissive_strength: Option<EmissiveStrength>,
+
+    /// Specifies the index of refraction of the material.
+    #[cfg(feature = "KHR_materials_ior")]
+    #[serde(
+        default,
+        rename = "KHR_materials_ior",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ior: Option<Ior>,
+
+    /// Specifies a sheen layer, as seen on cloth, on top of the material.
+    #[cfg(feature = "KHR_materials_sheen")]
+    #[serde(
+        default,
+        rename = "KHR_materials_sheen",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub sheen: Option<Sheen>,
+
+    /// Specifies the strength and color of the specular reflection of the
+    /// material.
+    #[cfg(feature = "KHR_materials_specular")]
+    #[serde(
+        default,
+        rename = "KHR_materials_specular",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub specular: Option<Specular>,
+
+    /// Specifies the optical transmission of the material.
+    #[cfg(feature = "KHR_materials_transmission")]
+    #[serde(
+        default,
+        rename = "KHR_materials_transmission",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub transmission: Option<Transmission>,
+
+    /// Specifies the volume of the material.
+    #[cfg(feature = "KHR_materials_volume")]
+    #[serde(
+        default,
+        rename = "KHR_materials_volume",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub volume: Option<Volume>,
+
+    /// Unrecognized extensions, keyed by extension name.
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: Map<String, Value>,
+}
 
 /// A set of parameter values that are used to define the metallic-roughness
 /// material model from Physically-Based Rendering (PBR) methodology.
@@ -14,3 +116,357 @@ pub struct NormalTexture {}
 /// Defines the occlusion texture of a material.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
 pub struct OcclusionTexture {}
+
+/// The `KHR_materials_clearcoat` extension of a material.
+#[cfg(feature = "KHR_materials_clearcoat")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct Clearcoat {
+    /// The clearcoat layer intensity.
+    #[serde(default)]
+    pub clearcoat_factor: ClearcoatFactor,
+
+    /// The clearcoat layer intensity texture, sampled from the R channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clearcoat_texture: Option<texture::Info>,
+
+    /// The clearcoat layer roughness.
+    #[serde(default)]
+    pub clearcoat_roughness_factor: ClearcoatFactor,
+
+    /// The clearcoat layer roughness texture, sampled from the G channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clearcoat_roughness_texture: Option<texture::Info>,
+
+    /// The clearcoat layer normal map texture.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clearcoat_normal_texture: Option<MaterialNormalTexture>,
+}
+
+/// A number in the inclusive range [0.0, 1.0] with a default value of 0.0.
+#[cfg(feature = "KHR_materials_clearcoat")]
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+pub struct ClearcoatFactor(pub f32);
+
+#[cfg(feature = "KHR_materials_clearcoat")]
+impl Validate for ClearcoatFactor {
+    fn validate_completely<P, R>(&self, _: &Root, path: P, report: &mut R)
+        where P: Fn() -> Path, R: FnMut(&Fn() -> Path, Error)
+    {
+        if self.0 < 0.0 || self.0 > 1.0 {
+            report(&path, Error::Invalid);
+        }
+    }
+}
+
+/// The `KHR_materials_emissive_strength` extension of a material.
+#[cfg(feature = "KHR_materials_emissive_strength")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct EmissiveStrength {
+    /// The strength adjustment to be multiplied with the emissive factor.
+    #[serde(default)]
+    pub emissive_strength: EmissiveStrengthFactor,
+}
+
+/// A non-negative number with a default value of 1.0.
+#[cfg(feature = "KHR_materials_emissive_strength")]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct EmissiveStrengthFactor(pub f32);
+
+#[cfg(feature = "KHR_materials_emissive_strength")]
+impl Default for EmissiveStrengthFactor {
+    fn default() -> Self {
+        EmissiveStrengthFactor(1.0)
+    }
+}
+
+#[cfg(feature = "KHR_materials_emissive_strength")]
+impl Validate for EmissiveStrengthFactor {
+    fn validate_completely<P, R>(&self, _: &Root, path: P, report: &mut R)
+        where P: Fn() -> Path, R: FnMut(&Fn() -> Path, Error)
+    {
+        if self.0 < 0.0 {
+            report(&path, Error::Invalid);
+        }
+    }
+}
+
+/// The `KHR_materials_ior` extension of a material.
+#[cfg(feature = "KHR_materials_ior")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct Ior {
+    /// The index of refraction.
+    #[serde(default)]
+    pub ior: IorFactor,
+}
+
+/// A number that is either 0.0 or at least 1.0, with a default value of 1.5.
+#[cfg(feature = "KHR_materials_ior")]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct IorFactor(pub f32);
+
+#[cfg(feature = "KHR_materials_ior")]
+impl Default for IorFactor {
+    fn default() -> Self {
+        IorFactor(1.5)
+    }
+}
+
+#[cfg(feature = "KHR_materials_ior")]
+impl Validate for IorFactor {
+    fn validate_completely<P, R>(&self, _: &Root, path: P, report: &mut R)
+        where P: Fn() -> Path, R: FnMut(&Fn() -> Path, Error)
+    {
+        if self.0 != 0.0 && self.0 < 1.0 {
+            report(&path, Error::Invalid);
+        }
+    }
+}
+
+/// The `KHR_materials_sheen` extension of a material.
+#[cfg(feature = "KHR_materials_sheen")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct Sheen {
+    /// The sheen color in linear space.
+    #[serde(default)]
+    pub sheen_color_factor: SheenColorFactor,
+
+    /// The sheen color texture, in sRGB color space, sampled from the RGB
+    /// channels.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sheen_color_texture: Option<texture::Info>,
+
+    /// The sheen roughness.
+    #[serde(default)]
+    pub sheen_roughness_factor: SheenRoughnessFactor,
+
+    /// The sheen roughness texture, sampled from the A channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sheen_roughness_texture: Option<texture::Info>,
+}
+
+/// An RGB color with components in the inclusive range [0.0, 1.0] and a
+/// default value of black.
+#[cfg(feature = "KHR_materials_sheen")]
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+pub struct SheenColorFactor(pub [f32; 3]);
+
+/// A number in the inclusive range [0.0, 1.0] with a default value of 0.0.
+#[cfg(feature = "KHR_materials_sheen")]
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+pub struct SheenRoughnessFactor(pub f32);
+
+#[cfg(feature = "KHR_materials_sheen")]
+impl Validate for SheenColorFactor {
+    fn validate_completely<P, R>(&self, _: &Root, path: P, report: &mut R)
+        where P: Fn() -> Path, R: FnMut(&Fn() -> Path, Error)
+    {
+        for x in &self.0 {
+            if *x < 0.0 || *x > 1.0 {
+                report(&path, Error::Invalid);
+                // Only report once
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(feature = "KHR_materials_sheen")]
+impl Validate for SheenRoughnessFactor {
+    fn validate_completely<P, R>(&self, _: &Root, path: P, report: &mut R)
+        where P: Fn() -> Path, R: FnMut(&Fn() -> Path, Error)
+    {
+        if self.0 < 0.0 || self.0 > 1.0 {
+            report(&path, Error::Invalid);
+        }
+    }
+}
+
+/// The `KHR_materials_specular` extension of a material.
+#[cfg(feature = "KHR_materials_specular")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct Specular {
+    /// The strength of the specular reflection.
+    #[serde(default)]
+    pub specular_factor: SpecularFactor,
+
+    /// A texture that defines the strength of the specular reflection,
+    /// sampled from the A channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub specular_texture: Option<texture::Info>,
+
+    /// The F0 color of the specular reflection in linear space.
+    #[serde(default)]
+    pub specular_color_factor: SpecularColorFactor,
+
+    /// A texture that defines the F0 color of the specular reflection, in
+    /// sRGB color space, sampled from the RGB channels.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub specular_color_texture: Option<texture::Info>,
+}
+
+/// A number in the inclusive range [0.0, 1.0] with a default value of 1.0.
+#[cfg(feature = "KHR_materials_specular")]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct SpecularFactor(pub f32);
+
+/// An RGB color with non-negative components and a default value of white.
+#[cfg(feature = "KHR_materials_specular")]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct SpecularColorFactor(pub [f32; 3]);
+
+#[cfg(feature = "KHR_materials_specular")]
+impl Default for SpecularFactor {
+    fn default() -> Self {
+        SpecularFactor(1.0)
+    }
+}
+
+#[cfg(feature = "KHR_materials_specular")]
+impl Validate for SpecularFactor {
+    fn validate_completely<P, R>(&self, _: &Root, path: P, report: &mut R)
+        where P: Fn() -> Path, R: FnMut(&Fn() -> Path, Error)
+    {
+        if self.0 < 0.0 || self.0 > 1.0 {
+            report(&path, Error::Invalid);
+        }
+    }
+}
+
+#[cfg(feature = "KHR_materials_specular")]
+impl Default for SpecularColorFactor {
+    fn default() -> Self {
+        SpecularColorFactor([1.0, 1.0, 1.0])
+    }
+}
+
+#[cfg(feature = "KHR_materials_specular")]
+impl Validate for SpecularColorFactor {
+    fn validate_completely<P, R>(&self, _: &Root, path: P, report: &mut R)
+        where P: Fn() -> Path, R: FnMut(&Fn() -> Path, Error)
+    {
+        if self.0.iter().any(|x| *x < 0.0) {
+            report(&path, Error::Invalid);
+        }
+    }
+}
+
+/// The `KHR_materials_transmission` extension of a material.
+#[cfg(feature = "KHR_materials_transmission")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct Transmission {
+    /// The base percentage of light that is transmitted through the surface.
+    #[serde(default)]
+    pub transmission_factor: TransmissionFactor,
+
+    /// A texture that defines the transmission percentage of the surface,
+    /// sampled from the R channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transmission_texture: Option<texture::Info>,
+}
+
+/// A number in the inclusive range [0.0, 1.0] with a default value of 0.0.
+#[cfg(feature = "KHR_materials_transmission")]
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+pub struct TransmissionFactor(pub f32);
+
+#[cfg(feature = "KHR_materials_transmission")]
+impl Validate for TransmissionFactor {
+    fn validate_completely<P, R>(&self, _: &Root, path: P, report: &mut R)
+        where P: Fn() -> Path, R: FnMut(&Fn() -> Path, Error)
+    {
+        if self.0 < 0.0 || self.0 > 1.0 {
+            report(&path, Error::Invalid);
+        }
+    }
+}
+
+/// The `KHR_materials_volume` extension of a material.
+#[cfg(feature = "KHR_materials_volume")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct Volume {
+    /// The thickness of the volume beneath the surface in the coordinate
+    /// space of the mesh.
+    #[serde(default)]
+    pub thickness_factor: ThicknessFactor,
+
+    /// A texture that defines the thickness, sampled from the G channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thickness_texture: Option<texture::Info>,
+
+    /// The average distance that light travels in the medium before
+    /// interacting with a particle. Infinite when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attenuation_distance: Option<AttenuationDistance>,
+
+    /// The color that white light turns into due to absorption when reaching
+    /// the attenuation distance.
+    #[serde(default)]
+    pub attenuation_color: AttenuationColor,
+}
+
+/// A non-negative number with a default value of 0.0.
+#[cfg(feature = "KHR_materials_volume")]
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+pub struct ThicknessFactor(pub f32);
+
+/// A positive number.
+#[cfg(feature = "KHR_materials_volume")]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct AttenuationDistance(pub f32);
+
+/// An RGB color with components in the inclusive range [0.0, 1.0] and a
+/// default value of white.
+#[cfg(feature = "KHR_materials_volume")]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct AttenuationColor(pub [f32; 3]);
+
+#[cfg(feature = "KHR_materials_volume")]
+impl Validate for ThicknessFactor {
+    fn validate_completely<P, R>(&self, _: &Root, path: P, report: &mut R)
+        where P: Fn() -> Path, R: FnMut(&Fn() -> Path, Error)
+    {
+        if self.0 < 0.0 {
+            report(&path, Error::Invalid);
+        }
+    }
+}
+
+#[cfg(feature = "KHR_materials_volume")]
+impl Validate for AttenuationDistance {
+    fn validate_completely<P, R>(&self, _: &Root, path: P, report: &mut R)
+        where P: Fn() -> Path, R: FnMut(&Fn() -> Path, Error)
+    {
+        if !(self.0 > 0.0) {
+            report(&path, Error::Invalid);
+        }
+    }
+}
+
+#[cfg(feature = "KHR_materials_volume")]
+impl Default for AttenuationColor {
+    fn default() -> Self {
+        AttenuationColor([1.0, 1.0, 1.0])
+    }
+}
+
+#[cfg(feature = "KHR_materials_volume")]
+impl Validate for AttenuationColor {
+    fn validate_completely<P, R>(&self, _: &Root, path: P, report: &mut R)
+        where P: Fn() -> Path, R: FnMut(&Fn() -> Path, Error)
+    {
+        for x in &self.0 {
+            if *x < 0.0 || *x > 1.0 {
+                report(&path, Error::Invalid);
+                // Only report once
+                break;
+            }
+        }
+    }
+}
@@ -37,7 +37,38 @@ pub mod texture;
 pub use self::root::Root;
 
 /// Names of glTF 2.0 extensions enabled by the user.
-pub const ENABLED_EXTENSIONS: &'static [&'static str] = &[];
+pub const ENABLED_EXTENSIONS: &'static [&'static str] = &[
+    #[cfg(feature = "EXT_lights_image_based")]
+    "EXT_lights_image_based",
+    #[cfg(feature = "KHR_materials_clearcoat")]
+    "KHR_materials_clearcoat",
+    #[cfg(feature = "KHR_materials_emissive_strength")]
+    "KHR_materials_emissive_strength",
+    #[cfg(feature = "KHR_materials_ior")]
+    "KHR_materials_ior",
+    #[cfg(feature = "KHR_materials_sheen")]
+    "KHR_materials_sheen",
+    #[cfg(feature = "KHR_materials_specular")]
+    "KHR_materials_specular",
+    #[cfg(feature = "KHR_materials_transmission")]
+    "KHR_materials_transmission",
+    #[cfg(feature = "KHR_materials_volume")]
+    "KHR_materials_volume",
+    "KHR_mesh_quantization",
+    #[cfg(feature = "KHR_texture_basisu")]
+    "KHR_texture_basisu",
+];
 
 /// Names of glTF 2.0 extensions supported by the library.
-pub const SUPPORTED_EXTENSIONS: &'static [&'static str] = &[];
+pub const SUPPORTED_EXTENSIONS: &'static [&'static str] = &[
+    "EXT_lights_image_based",
+    "KHR_materials_clearcoat",
+    "KHR_materials_emissive_strength",
+    "KHR_materials_ior",
+    "KHR_materials_sheen",
+    "KHR_materials_specular",
+    "KHR_materials_transmission",
+    "KHR_materials_volume",
+    "KHR_mesh_quantization",
+    "KHR_texture_basisu",
+];
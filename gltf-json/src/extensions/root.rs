@@ -1,3 +1,24 @@
+#[cfg(feature = "EXT_lights_image_based")]
+use extensions::scene::ImageBasedLight;
+
 /// The root object of a glTF 2.0 asset.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Root {}
+pub struct Root {
+    /// The image-based lights of the asset, referenced by scenes.
+    #[cfg(feature = "EXT_lights_image_based")]
+    #[serde(
+        default,
+        rename = "EXT_lights_image_based",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub lights_image_based: Option<LightsImageBased>,
+}
+
+/// The `EXT_lights_image_based` extension of the root object.
+#[cfg(feature = "EXT_lights_image_based")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+pub struct LightsImageBased {
+    /// An array of image-based lights.
+    #[serde(default)]
+    pub lights: Vec<ImageBasedLight>,
+}
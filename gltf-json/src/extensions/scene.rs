@@ -1,3 +1,10 @@
+#[cfg(feature = "extensions")]
+use serde_json::{Map, Value};
+#[cfg(feature = "EXT_lights_image_based")]
+use validation::{Error, Validate};
+#[cfg(feature = "EXT_lights_image_based")]
+use {image, scene, Index, Path, Root};
+
 /// A node in the node hierarchy.  When the node contains `skin`, all
 /// `mesh.primitives` must contain `JOINTS_0` and `WEIGHTS_0` attributes.
 /// A node can have either a `matrix` or any combination of
@@ -9,8 +16,96 @@
 /// animation.channel.target), only TRS properties may be present; `matrix` will not
 /// be present.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Node {}
+pub struct Node {
+    /// Unrecognized extensions, keyed by extension name.
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: Map<String, Value>,
+}
 
 /// The root `Node`s of a scene.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Scene {}
+pub struct Scene {
+    /// The image-based light illuminating the scene.
+    #[cfg(feature = "EXT_lights_image_based")]
+    #[serde(
+        default,
+        rename = "EXT_lights_image_based",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub lights_image_based: Option<LightImageBased>,
+}
+
+/// The `EXT_lights_image_based` extension of a scene.
+#[cfg(feature = "EXT_lights_image_based")]
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+pub struct LightImageBased {
+    /// The index of the image-based light used by the scene.
+    pub light: Index<ImageBasedLight>,
+}
+
+/// An environment light defined by prefiltered images.
+#[cfg(feature = "EXT_lights_image_based")]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImageBasedLight {
+    /// Optional user-defined name for this object.
+    #[cfg(feature = "names")]
+    pub name: Option<String>,
+
+    /// The light's unit quaternion rotation in the order (x, y, z, w), where
+    /// w is the scalar.
+    #[serde(default)]
+    pub rotation: scene::UnitQuaternion,
+
+    /// Brightness multiplier for the environment.
+    #[serde(default = "intensity_default")]
+    pub intensity: f32,
+
+    /// The first nine spherical harmonic coefficients of the irradiance
+    /// function, each given as an RGB triple.
+    pub irradiance_coefficients: [[f32; 3]; 9],
+
+    /// The width and height in pixels of the largest specular mip level.
+    pub specular_image_size: u32,
+
+    /// The specular cubemap for each mip level, starting from the largest.
+    ///
+    /// Each cubemap lists its faces in the order +X, -X, +Y, -Y, +Z, -Z.
+    pub specular_images: Vec<[Index<image::Image>; 6]>,
+}
+
+#[cfg(feature = "EXT_lights_image_based")]
+fn intensity_default() -> f32 {
+    1.0
+}
+
+#[cfg(feature = "EXT_lights_image_based")]
+impl Validate for ImageBasedLight {
+    fn validate_minimally<P, R>(&self, root: &Root, path: P, report: &mut R)
+        where P: Fn() -> Path, R: FnMut(&Fn() -> Path, Error)
+    {
+        for (i, cubemap) in self.specular_images.iter().enumerate() {
+            for (j, face) in cubemap.iter().enumerate() {
+                face.validate_minimally(
+                    root,
+                    || path().field("specularImages").index(i).index(j),
+                    report,
+                );
+            }
+        }
+    }
+
+    fn validate_completely<P, R>(&self, root: &Root, path: P, report: &mut R)
+        where P: Fn() -> Path, R: FnMut(&Fn() -> Path, Error)
+    {
+        self.rotation
+            .validate_completely(root, || path().field("rotation"), report);
+        if self.intensity < 0.0 {
+            report(&|| path().field("intensity"), Error::Invalid);
+        }
+        if self.specular_images.is_empty() {
+            report(&|| path().field("specularImages"), Error::Missing);
+        }
+    }
+}
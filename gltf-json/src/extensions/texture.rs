@@ -1,11 +1,38 @@
+#[cfg(feature = "extensions")]
+use serde_json::{Map, Value};
+#[cfg(feature = "KHR_texture_basisu")]
+use {image, Index};
+
 /// Texture sampler properties for filtering and wrapping modes.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
 pub struct Sampler {}
 
 /// A texture and its sampler.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Texture {}
+pub struct Texture {
+    /// Specifies a KTX2 image with Basis Universal supercompression.
+    #[cfg(feature = "KHR_texture_basisu")]
+    #[serde(
+        default,
+        rename = "KHR_texture_basisu",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub texture_basisu: Option<TextureBasisu>,
+
+    /// Unrecognized extensions, keyed by extension name.
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: Map<String, Value>,
+}
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
 /// Reference to a `Texture`.
 pub struct Info {}
+
+/// A texture that uses a KTX2 image with Basis Universal supercompression.
+#[cfg(feature = "KHR_texture_basisu")]
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+pub struct TextureBasisu {
+    /// The index of the KTX2 image used by the texture.
+    pub source: Index<image::Image>,
+}
@@ -4,6 +4,8 @@ use {buffer, extensions, Extras, Index, Root, Path};
 /// All valid MIME types.
 pub const VALID_MIME_TYPES: &'static [&'static str] = &[
     "image/jpeg",
+    #[cfg(feature = "KHR_texture_basisu")]
+    "image/ktx2",
     "image/png",
 ];
 
@@ -99,6 +99,7 @@ pub use serde_json::Value;
 /// so that one can deserialize data structures other than `Root` without
 /// being bound to a specific version of `serde_json`.
 pub mod deserialize {
+    pub use serde::de::DeserializeOwned;
     pub use serde_json::{from_reader, from_slice, from_str, from_value};
 }
 
@@ -108,5 +109,6 @@ pub mod deserialize {
 /// so that one can serialize data structures other than `Root` without
 /// being bound to a specific version of `serde_json`.
 pub mod serialize {
+    pub use serde::ser::Serialize;
     pub use serde_json::{to_string, to_string_pretty, to_value, to_vec, to_vec_pretty, to_writer, to_writer_pretty};
 }
@@ -2,6 +2,7 @@ use serde::{de, ser};
 use serde_json::from_value;
 use std::collections::HashMap;
 use std::fmt;
+use std::str::FromStr;
 use validation::{Checked, Error, Validate};
 use {accessor, extensions, material, Extras, Index};
 
@@ -148,6 +149,9 @@ pub struct Primitive {
                 .validate_minimally(root, || path().field("targets"), report);
 
             // Custom part
+            let quantized = root.extensions_required
+                .iter()
+                .any(|name| name == "KHR_mesh_quantization");
             let position_path = &|| path().field("attributes").key("POSITION");
             if let Some(pos_accessor_index) = self.attributes.get(&Checked::Valid(Semantic::Positions)) {
                 // spec: POSITION accessor **must** have `min` and `max` properties defined.
@@ -170,9 +174,106 @@ pub struct Primitive {
                 } else {
                     report(max_path, Error::Missing);
                 }
+
+                // spec: All attribute accessors for a given primitive **must**
+                // have the same `count`.
+                let mut mismatched: Vec<String> = self.attributes
+                    .iter()
+                    .filter_map(|(semantic, index)| match *semantic {
+                        Checked::Valid(ref semantic) => root.accessors
+                            .get(index.value())
+                            .filter(|accessor| accessor.count != pos_accessor.count)
+                            .map(|_| semantic.to_string()),
+                        Checked::Invalid => None,
+                    })
+                    .collect();
+                // Sorted so that errors are reported in a deterministic order.
+                mismatched.sort();
+                for semantic in &mismatched {
+                    report(&|| path().field("attributes").key(semantic), Error::Invalid);
+                }
+
+                // spec: Integer component types are only permitted for
+                // `POSITION`, `NORMAL`, `TANGENT`, and `TEXCOORD_n` when
+                // `KHR_mesh_quantization` is required.
+                //
+                // spec: Each attribute accessor **must** have one of the types
+                // and component types listed for its semantic.
+                let mut unpermitted: Vec<String> = self.attributes
+                    .iter()
+                    .filter_map(|(semantic, index)| match *semantic {
+                        Checked::Valid(ref semantic) => root.accessors
+                            .get(index.value())
+                            .filter(|accessor| match accessor.component_type {
+                                Checked::Valid(accessor::GenericComponentType(component)) => {
+                                    !semantic.permits(component, accessor.normalized, quantized)
+                                        || match accessor.type_ {
+                                            Checked::Valid(ty) => {
+                                                !semantic.permits_strictly(ty, component, accessor.normalized)
+                                            },
+                                            Checked::Invalid => false,
+                                        }
+                                },
+                                Checked::Invalid => false,
+                            })
+                            .map(|_| semantic.to_string()),
+                        Checked::Invalid => None,
+                    })
+                    .collect();
+                unpermitted.sort();
+                for semantic in &unpermitted {
+                    report(&|| path().field("attributes").key(semantic), Error::Invalid);
+                }
             } else {
                 report(position_path, Error::Missing);
             }
+
+            // spec: Morph target accessors **must** be `VEC3` and of float
+            // component type, unless integers are permitted by
+            // `KHR_mesh_quantization`.
+            for (i, target) in self.targets.iter().flatten().enumerate() {
+                let displacements = [
+                    ("POSITION", Semantic::Positions, &target.positions),
+                    ("NORMAL", Semantic::Normals, &target.normals),
+                    ("TANGENT", Semantic::Tangents, &target.tangents),
+                ];
+                for &(name, ref semantic, index) in &displacements {
+                    let permitted = index
+                        .as_ref()
+                        .and_then(|index| root.accessors.get(index.value()))
+                        .map_or(true, |accessor| match (&accessor.type_, &accessor.component_type) {
+                            (&Checked::Valid(ty), &Checked::Valid(accessor::GenericComponentType(component))) => {
+                                semantic.permits_displacement(ty, component, accessor.normalized, quantized)
+                            },
+                            _ => true,
+                        });
+                    if !permitted {
+                        report(&|| path().field("targets").index(i).field(name), Error::Invalid);
+                    }
+                }
+            }
+        }
+
+        fn validate_completely<P, R>(&self, root: &::Root, path: P, report: &mut R)
+        where
+            P: Fn() -> ::Path,
+            R: FnMut(&Fn() -> ::Path, ::validation::Error),
+        {
+            // Generated part
+            self.attributes
+                .validate_completely(root, || path().field("attributes"), report);
+            self.extensions
+                .validate_completely(root, || path().field("extensions"), report);
+            self.extras
+                .validate_completely(root, || path().field("extras"), report);
+            self.indices
+                .validate_completely(root, || path().field("indices"), report);
+            self.material
+                .validate_completely(root, || path().field("material"), report);
+            self.mode
+                .validate_completely(root, || path().field("mode"), report);
+            self.targets
+                .validate_completely(root, || path().field("targets"), report);
         }
     }
 
@@ -193,12 +294,10 @@ pub struct MorphTarget {
 }
 
 /// Vertex attribute semantic name.
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+///
+/// Semantics are ordered as declared, followed by set index or name.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum Semantic {
-    /// Extra attribute name.
-    #[cfg(feature = "extras")]
-    Extras(String),
-
     /// XYZ vertex positions.
     Positions,
 
@@ -220,6 +319,93 @@ pub enum Semantic {
 
     /// Joint weights.
     Weights(u32),
+
+    /// Extra attribute name.
+    #[cfg(feature = "extras")]
+    Extras(String),
+}
+
+impl Semantic {
+    /// Returns whether an accessor of the given component type may hold the
+    /// values of this attribute, where `quantized` specifies whether
+    /// `KHR_mesh_quantization` is required.
+    ///
+    /// Only the attributes affected by `KHR_mesh_quantization` are checked.
+    fn permits(&self, ty: accessor::ComponentType, normalized: bool, quantized: bool) -> bool {
+        use accessor::ComponentType::*;
+        match (self, ty) {
+            (_, F32) => true,
+            (&Semantic::Positions, I8)
+                | (&Semantic::Positions, U8)
+                | (&Semantic::Positions, I16)
+                | (&Semantic::Positions, U16) => quantized,
+            (&Semantic::Normals, I8)
+                | (&Semantic::Normals, I16)
+                | (&Semantic::Tangents, I8)
+                | (&Semantic::Tangents, I16) => quantized && normalized,
+            (&Semantic::TexCoords(_), U8) | (&Semantic::TexCoords(_), U16) => normalized || quantized,
+            (&Semantic::TexCoords(_), I8) | (&Semantic::TexCoords(_), I16) => quantized,
+            (&Semantic::Positions, _)
+                | (&Semantic::Normals, _)
+                | (&Semantic::Tangents, _)
+                | (&Semantic::TexCoords(_), _) => false,
+            _ => true,
+        }
+    }
+
+    /// Returns whether an accessor of the given type and component type may
+    /// hold the morph target displacements of this attribute, where
+    /// `quantized` specifies whether `KHR_mesh_quantization` is required.
+    fn permits_displacement(
+        &self,
+        ty: accessor::Type,
+        component: accessor::ComponentType,
+        normalized: bool,
+        quantized: bool,
+    ) -> bool {
+        use accessor::ComponentType::*;
+        ty == accessor::Type::Vec3 && match (self, component) {
+            (_, F32) => true,
+            (&Semantic::Positions, I8) | (&Semantic::Positions, I16) => quantized,
+            (&Semantic::Normals, I8)
+                | (&Semantic::Normals, I16)
+                | (&Semantic::Tangents, I8)
+                | (&Semantic::Tangents, I16) => quantized && normalized,
+            _ => false,
+        }
+    }
+
+    /// Returns whether an accessor of the given type and component type may
+    /// hold the values of this attribute according to the attribute table of
+    /// the specification.
+    ///
+    /// The component types of `POSITION`, `NORMAL`, `TANGENT`, and
+    /// `TEXCOORD_n` are not checked, as they depend on `KHR_mesh_quantization`
+    /// and are checked by `permits`.
+    fn permits_strictly(
+        &self,
+        ty: accessor::Type,
+        component: accessor::ComponentType,
+        normalized: bool,
+    ) -> bool {
+        use accessor::ComponentType::*;
+        use accessor::Type::*;
+        let normalized_or_float = match component {
+            F32 => true,
+            U8 | U16 => normalized,
+            _ => false,
+        };
+        match *self {
+            Semantic::Positions | Semantic::Normals => ty == Vec3,
+            Semantic::Tangents => ty == Vec4,
+            Semantic::TexCoords(_) => ty == Vec2,
+            Semantic::Colors(_) => (ty == Vec3 || ty == Vec4) && normalized_or_float,
+            Semantic::Joints(_) => ty == Vec4 && (component == U8 || component == U16) && !normalized,
+            Semantic::Weights(_) => ty == Vec4 && normalized_or_float,
+            #[cfg(feature = "extras")]
+            Semantic::Extras(_) => true,
+        }
+    }
 }
 
 impl Default for Mode {
@@ -241,6 +427,37 @@ impl Mode {
             Mode::TriangleFan => TRIANGLE_FAN,
         }
     }
+
+    /// Returns `true` if the mode renders triangles, i.e. `Triangles`,
+    /// `TriangleStrip`, or `TriangleFan`.
+    pub fn is_triangles(self) -> bool {
+        match self {
+            Mode::Triangles | Mode::TriangleStrip | Mode::TriangleFan => true,
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the mode renders lines, i.e. `Lines`, `LineLoop`,
+    /// or `LineStrip`.
+    pub fn is_lines(self) -> bool {
+        match self {
+            Mode::Lines | Mode::LineLoop | Mode::LineStrip => true,
+            _ => false,
+        }
+    }
+
+    /// Returns the number of points, lines, or triangles rendered from
+    /// `count` vertex indices.
+    pub fn primitive_count(self, count: usize) -> usize {
+        match self {
+            Mode::Points => count,
+            Mode::Lines => count / 2,
+            Mode::LineLoop => if count < 2 { 0 } else { count },
+            Mode::LineStrip => count.saturating_sub(1),
+            Mode::Triangles => count / 3,
+            Mode::TriangleStrip | Mode::TriangleFan => count.saturating_sub(2),
+        }
+    }
 }
 
 impl<'de> de::Deserialize<'de> for Checked<Mode> {
@@ -332,19 +549,33 @@ impl ser::Serialize for Semantic {
     }
 }
 
-impl ToString for Semantic {
-    fn to_string(&self) -> String {
+impl fmt::Display for Semantic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         use self::Semantic::*;
         match *self {
-            Positions => "POSITION".into(),
-            Normals => "NORMAL".into(),
-            Tangents => "TANGENT".into(),
-            Colors(set) => format!("COLOR_{}", set),
-            TexCoords(set) => format!("TEXCOORD_{}", set),
-            Joints(set) => format!("JOINTS_{}", set),
-            Weights(set) => format!("WEIGHTS_{}", set),
+            Positions => write!(f, "POSITION"),
+            Normals => write!(f, "NORMAL"),
+            Tangents => write!(f, "TANGENT"),
+            Colors(set) => write!(f, "COLOR_{}", set),
+            TexCoords(set) => write!(f, "TEXCOORD_{}", set),
+            Joints(set) => write!(f, "JOINTS_{}", set),
+            Weights(set) => write!(f, "WEIGHTS_{}", set),
             #[cfg(feature = "extras")]
-            Extras(ref name) => format!("_{}", name),
+            Extras(ref name) => write!(f, "_{}", name),
+        }
+    }
+}
+
+impl FromStr for Semantic {
+    type Err = Error;
+
+    /// Parses a semantic name such as `"POSITION"` or `"TEXCOORD_1"`.
+    ///
+    /// Returns `Error::Invalid` for unrecognized semantic names.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match Semantic::checked(s) {
+            Checked::Valid(semantic) => Ok(semantic),
+            Checked::Invalid => Err(Error::Invalid),
         }
     }
 }
@@ -379,3 +610,65 @@ impl<'de> de::Deserialize<'de> for Checked<Semantic> {
         deserializer.deserialize_str(Visitor)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Mode, Semantic};
+    use validation::Error;
+
+    #[test]
+    fn semantic_round_trip() {
+        let semantics = [
+            Semantic::Positions,
+            Semantic::Normals,
+            Semantic::Tangents,
+            Semantic::Colors(0),
+            Semantic::Colors(1),
+            Semantic::TexCoords(0),
+            Semantic::TexCoords(12),
+            Semantic::Joints(0),
+            Semantic::Joints(3),
+            Semantic::Weights(0),
+            Semantic::Weights(3),
+        ];
+        for semantic in semantics.iter() {
+            let name = semantic.to_string();
+            assert_eq!(Ok(semantic.clone()), name.parse::<Semantic>());
+        }
+    }
+
+    #[test]
+    fn semantic_from_str() {
+        assert_eq!(Ok(Semantic::Positions), "POSITION".parse());
+        assert_eq!(Ok(Semantic::TexCoords(1)), "TEXCOORD_1".parse());
+        assert_eq!(Err(Error::Invalid), "TEXCOORD_".parse::<Semantic>());
+        assert_eq!(Err(Error::Invalid), "COLOR_x".parse::<Semantic>());
+        assert_eq!(Err(Error::Invalid), "position".parse::<Semantic>());
+        assert_eq!(Err(Error::Invalid), "".parse::<Semantic>());
+    }
+
+    #[test]
+    fn mode_primitive_count() {
+        assert_eq!(7, Mode::Points.primitive_count(7));
+        assert_eq!(3, Mode::Lines.primitive_count(7));
+        assert_eq!(7, Mode::LineLoop.primitive_count(7));
+        assert_eq!(0, Mode::LineLoop.primitive_count(1));
+        assert_eq!(6, Mode::LineStrip.primitive_count(7));
+        assert_eq!(2, Mode::Triangles.primitive_count(7));
+        assert_eq!(5, Mode::TriangleStrip.primitive_count(7));
+        assert_eq!(5, Mode::TriangleFan.primitive_count(7));
+        assert_eq!(0, Mode::TriangleStrip.primitive_count(2));
+        assert_eq!(0, Mode::LineStrip.primitive_count(0));
+    }
+
+    #[test]
+    fn mode_is_triangles() {
+        assert!(Mode::Triangles.is_triangles());
+        assert!(Mode::TriangleStrip.is_triangles());
+        assert!(Mode::TriangleFan.is_triangles());
+        assert!(!Mode::LineLoop.is_triangles());
+        assert!(!Mode::Points.is_triangles());
+        assert!(Mode::LineStrip.is_lines());
+        assert!(!Mode::TriangleFan.is_lines());
+    }
+}
@@ -17,11 +17,10 @@ pub trait Get<T> {
 }
 
 /// Represents an offset into an array of type `T` owned by the root glTF object.
-#[derive(Clone, Copy)]
 pub struct Index<T>(u32, marker::PhantomData<T>);
 
 /// The root object of a glTF 2.0 asset.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct Root {
     /// An array of accessors.
     #[serde(default)]
@@ -161,11 +160,150 @@ impl Root {
     {
         serde_json::to_writer_pretty(writer, self)
     }
+
+    /// Reports the edges of the node hierarchy that close a cycle, visiting
+    /// each node once in a depth-first traversal.
+    fn validate_hierarchy<P, R>(&self, path: P, report: &mut R)
+        where P: Fn() -> Path, R: FnMut(&Fn() -> Path, validation::Error)
+    {
+        #[derive(Clone, Copy, PartialEq)]
+        enum Mark {
+            Unvisited,
+            InProgress,
+            Done,
+        }
+
+        let children = |index: usize| {
+            self.nodes[index].children.as_ref().map_or(&[][..], |x| &x[..])
+        };
+        let mut marks = vec![Mark::Unvisited; self.nodes.len()];
+        for start in 0..self.nodes.len() {
+            if marks[start] != Mark::Unvisited {
+                continue;
+            }
+            marks[start] = Mark::InProgress;
+            // Each entry is a node paired with the position of its next child.
+            let mut stack = vec![(start, 0)];
+            while let Some((parent, next)) = stack.pop() {
+                let child = match children(parent).get(next) {
+                    Some(child) => child.value(),
+                    None => {
+                        marks[parent] = Mark::Done;
+                        continue;
+                    },
+                };
+                stack.push((parent, next + 1));
+                match marks.get(child).cloned() {
+                    Some(Mark::Unvisited) => {
+                        marks[child] = Mark::InProgress;
+                        stack.push((child, 0));
+                    },
+                    Some(Mark::InProgress) => {
+                        // spec: The node hierarchy must be a set of disjoint
+                        // strict trees.
+                        let path = || path().field("nodes").index(parent).field("children");
+                        report(&path, validation::Error::Invalid);
+                    },
+                    // Out of range indices are reported by `Index`.
+                    Some(Mark::Done) | None => {},
+                }
+            }
+        }
+    }
+}
+
+impl Validate for Root {
+    fn validate_minimally<P, R>(&self, root: &Root, path: P, report: &mut R)
+        where P: Fn() -> Path, R: FnMut(&Fn() -> Path, validation::Error)
+    {
+        self.accessors
+            .validate_minimally(root, || path().field("accessors"), report);
+        self.animations
+            .validate_minimally(root, || path().field("animations"), report);
+        self.asset
+            .validate_minimally(root, || path().field("asset"), report);
+        self.buffers
+            .validate_minimally(root, || path().field("buffers"), report);
+        self.buffer_views
+            .validate_minimally(root, || path().field("bufferViews"), report);
+        self.scene
+            .validate_minimally(root, || path().field("scene"), report);
+        self.extensions
+            .validate_minimally(root, || path().field("extensions"), report);
+        self.extras
+            .validate_minimally(root, || path().field("extras"), report);
+        self.extensions_used
+            .validate_minimally(root, || path().field("extensionsUsed"), report);
+        self.extensions_required
+            .validate_minimally(root, || path().field("extensionsRequired"), report);
+        self.cameras
+            .validate_minimally(root, || path().field("cameras"), report);
+        self.images
+            .validate_minimally(root, || path().field("images"), report);
+        self.materials
+            .validate_minimally(root, || path().field("materials"), report);
+        self.meshes
+            .validate_minimally(root, || path().field("meshes"), report);
+        self.nodes
+            .validate_minimally(root, || path().field("nodes"), report);
+        self.samplers
+            .validate_minimally(root, || path().field("samplers"), report);
+        self.scenes
+            .validate_minimally(root, || path().field("scenes"), report);
+        self.skins
+            .validate_minimally(root, || path().field("skins"), report);
+        self.textures
+            .validate_minimally(root, || path().field("textures"), report);
+        self.validate_hierarchy(path, report);
+    }
+
+    fn validate_completely<P, R>(&self, root: &Root, path: P, report: &mut R)
+        where P: Fn() -> Path, R: FnMut(&Fn() -> Path, validation::Error)
+    {
+        self.accessors
+            .validate_completely(root, || path().field("accessors"), report);
+        self.animations
+            .validate_completely(root, || path().field("animations"), report);
+        self.asset
+            .validate_completely(root, || path().field("asset"), report);
+        self.buffers
+            .validate_completely(root, || path().field("buffers"), report);
+        self.buffer_views
+            .validate_completely(root, || path().field("bufferViews"), report);
+        self.scene
+            .validate_completely(root, || path().field("scene"), report);
+        self.extensions
+            .validate_completely(root, || path().field("extensions"), report);
+        self.extras
+            .validate_completely(root, || path().field("extras"), report);
+        self.extensions_used
+            .validate_completely(root, || path().field("extensionsUsed"), report);
+        self.extensions_required
+            .validate_completely(root, || path().field("extensionsRequired"), report);
+        self.cameras
+            .validate_completely(root, || path().field("cameras"), report);
+        self.images
+            .validate_completely(root, || path().field("images"), report);
+        self.materials
+            .validate_completely(root, || path().field("materials"), report);
+        self.meshes
+            .validate_completely(root, || path().field("meshes"), report);
+        self.nodes
+            .validate_completely(root, || path().field("nodes"), report);
+        self.samplers
+            .validate_completely(root, || path().field("samplers"), report);
+        self.scenes
+            .validate_completely(root, || path().field("scenes"), report);
+        self.skins
+            .validate_completely(root, || path().field("skins"), report);
+        self.textures
+            .validate_completely(root, || path().field("textures"), report);
+    }
 }
 
 impl<T> Index<T> {
     /// Creates a new `Index` representing an offset into an array containing `T`.
-    fn new(value: u32) -> Self {
+    pub fn new(value: u32) -> Self {
         Index(value, std::marker::PhantomData)
     }
 
@@ -205,6 +343,14 @@ impl<'de, T> serde::Deserialize<'de> for Index<T> {
     }
 }
 
+impl<T> Clone for Index<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Index<T> {}
+
 impl<T> fmt::Debug for Index<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.0)
@@ -252,3 +398,16 @@ impl_get!(texture::Sampler, samplers);
 impl_get!(Scene, scenes);
 impl_get!(Skin, skins);
 impl_get!(Texture, textures);
+
+#[cfg(feature = "EXT_lights_image_based")]
+impl Get<extensions::scene::ImageBasedLight> for Root {
+    fn get(
+        &self,
+        index: &Index<extensions::scene::ImageBasedLight>,
+    ) -> Option<&extensions::scene::ImageBasedLight> {
+        self.extensions
+            .lights_image_based
+            .as_ref()
+            .and_then(|extension| extension.lights.get(index.value()))
+    }
+}
@@ -11,7 +11,7 @@ use {camera, extensions, mesh, scene, skin, Extras, Index, Root, Path};
 /// identity. When a node is targeted for animation (referenced by an
 /// animation.channel.target), only TRS properties may be present; `matrix` will not
 /// be present.
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Node {
     /// The index of the camera referenced by this node.
     pub camera: Option<Index<camera::Camera>>,
@@ -62,6 +62,90 @@ fn node_scale_default() -> [f32; 3] {
     [1.0, 1.0, 1.0]
 }
 
+impl Node {
+    /// Returns `true` if any of the TRS properties differ from their defaults.
+    fn has_trs(&self) -> bool {
+        self.translation != [0.0, 0.0, 0.0]
+            || self.rotation.0 != [0.0, 0.0, 0.0, 1.0]
+            || self.scale != node_scale_default()
+    }
+
+}
+
+impl Validate for Node {
+    fn validate_minimally<P, R>(&self, root: &Root, path: P, report: &mut R)
+        where P: Fn() -> Path, R: FnMut(&Fn() -> Path, Error)
+    {
+        self.camera
+            .validate_minimally(root, || path().field("camera"), report);
+        self.children
+            .validate_minimally(root, || path().field("children"), report);
+        self.extensions
+            .validate_minimally(root, || path().field("extensions"), report);
+        self.extras
+            .validate_minimally(root, || path().field("extras"), report);
+        self.matrix
+            .validate_minimally(root, || path().field("matrix"), report);
+        self.mesh
+            .validate_minimally(root, || path().field("mesh"), report);
+        self.rotation
+            .validate_minimally(root, || path().field("rotation"), report);
+        self.scale
+            .validate_minimally(root, || path().field("scale"), report);
+        self.translation
+            .validate_minimally(root, || path().field("translation"), report);
+        self.skin
+            .validate_minimally(root, || path().field("skin"), report);
+        self.weights
+            .validate_minimally(root, || path().field("weights"), report);
+    }
+
+    fn validate_completely<P, R>(&self, root: &Root, path: P, report: &mut R)
+        where P: Fn() -> Path, R: FnMut(&Fn() -> Path, Error)
+    {
+        // spec: A node can have either a `matrix` or any combination of
+        // `translation`/`rotation`/`scale` (TRS) properties.
+        if self.matrix.is_some() && self.has_trs() {
+            report(&path, Error::Invalid);
+        }
+
+        // spec: The number of weights must match the number of morph targets
+        // of the referenced mesh.
+        if let (Some(weights), Some(mesh)) = (self.weights.as_ref(), self.mesh.as_ref()) {
+            let targets = root.meshes
+                .get(mesh.value())
+                .and_then(|mesh| mesh.primitives.first())
+                .map(|primitive| primitive.targets.as_ref().map_or(0, Vec::len));
+            if targets.map_or(false, |targets| targets != weights.len()) {
+                report(&|| path().field("weights"), Error::Invalid);
+            }
+        }
+
+        self.camera
+            .validate_completely(root, || path().field("camera"), report);
+        self.children
+            .validate_completely(root, || path().field("children"), report);
+        self.extensions
+            .validate_completely(root, || path().field("extensions"), report);
+        self.extras
+            .validate_completely(root, || path().field("extras"), report);
+        self.matrix
+            .validate_completely(root, || path().field("matrix"), report);
+        self.mesh
+            .validate_completely(root, || path().field("mesh"), report);
+        self.rotation
+            .validate_completely(root, || path().field("rotation"), report);
+        self.scale
+            .validate_completely(root, || path().field("scale"), report);
+        self.translation
+            .validate_completely(root, || path().field("translation"), report);
+        self.skin
+            .validate_completely(root, || path().field("skin"), report);
+        self.weights
+            .validate_completely(root, || path().field("weights"), report);
+    }
+}
+
 /// The root `Node`s of a scene.
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
 pub struct Scene {
@@ -1,7 +1,7 @@
 use serde::{de, ser};
 use std::fmt;
-use validation::Checked;
-use {extensions, image, Extras, Index};
+use validation::{Checked, Error, Validate};
+use {extensions, image, Extras, Index, Path, Root};
 
 /// Corresponds to `GL_NEAREST`.
 pub const NEAREST: u32 = 9728;
@@ -54,7 +54,7 @@ pub const VALID_WRAPPING_MODES: &'static [u32] = &[
 ];
 
 /// Magnification filter.
-#[derive(Clone, Copy, Debug, Deserialize)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
 pub enum MagFilter {
     /// Corresponds to `GL_NEAREST`.
     Nearest = 1,
@@ -64,6 +64,16 @@ pub enum MagFilter {
 }
 
 impl MagFilter {
+    /// Returns the filter corresponding to an OpenGL enum value, or `None`
+    /// if the value is not a valid magnification filter.
+    pub fn from_gl_enum(value: u32) -> Option<Self> {
+        match value {
+            NEAREST => Some(MagFilter::Nearest),
+            LINEAR => Some(MagFilter::Linear),
+            _ => None,
+        }
+    }
+
     /// OpenGL enum
     pub fn as_gl_enum(&self) -> u32 {
         match *self {
@@ -73,8 +83,19 @@ impl MagFilter {
     }
 }
 
+/// Texel filter, as used independently for minification and mipmap selection
+/// by APIs such as Vulkan.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Filter {
+    /// Selects the nearest texel or mipmap level.
+    Nearest,
+
+    /// Interpolates linearly between texels or mipmap levels.
+    Linear,
+}
+
 /// Minification filter.
-#[derive(Clone, Copy, Debug, Deserialize)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
 pub enum MinFilter {
     /// Corresponds to `GL_NEAREST`.
     Nearest = 1,
@@ -96,6 +117,20 @@ pub enum MinFilter {
 }
 
 impl MinFilter {
+    /// Returns the filter corresponding to an OpenGL enum value, or `None`
+    /// if the value is not a valid minification filter.
+    pub fn from_gl_enum(value: u32) -> Option<Self> {
+        match value {
+            NEAREST => Some(MinFilter::Nearest),
+            LINEAR => Some(MinFilter::Linear),
+            NEAREST_MIPMAP_NEAREST => Some(MinFilter::NearestMipmapNearest),
+            LINEAR_MIPMAP_NEAREST => Some(MinFilter::LinearMipmapNearest),
+            NEAREST_MIPMAP_LINEAR => Some(MinFilter::NearestMipmapLinear),
+            LINEAR_MIPMAP_LINEAR => Some(MinFilter::LinearMipmapLinear),
+            _ => None,
+        }
+    }
+
     /// Returns the corresponding OpenGL enum value.
     pub fn as_gl_enum(&self) -> u32 {
         match *self {
@@ -107,10 +142,39 @@ impl MinFilter {
             MinFilter::LinearMipmapLinear => LINEAR_MIPMAP_LINEAR,
         }
     }
+
+    /// Returns whether the texture should be sampled from mipmaps.
+    pub fn uses_mipmaps(&self) -> bool {
+        self.mipmap_filter().is_some()
+    }
+
+    /// Returns the filter applied to texels within a single mipmap level.
+    pub fn base_filter(&self) -> Filter {
+        match *self {
+            MinFilter::Nearest
+                | MinFilter::NearestMipmapNearest
+                | MinFilter::NearestMipmapLinear => Filter::Nearest,
+            MinFilter::Linear
+                | MinFilter::LinearMipmapNearest
+                | MinFilter::LinearMipmapLinear => Filter::Linear,
+        }
+    }
+
+    /// Returns the filter applied between mipmap levels, or `None` if
+    /// mipmaps are not used.
+    pub fn mipmap_filter(&self) -> Option<Filter> {
+        match *self {
+            MinFilter::Nearest | MinFilter::Linear => None,
+            MinFilter::NearestMipmapNearest
+                | MinFilter::LinearMipmapNearest => Some(Filter::Nearest),
+            MinFilter::NearestMipmapLinear
+                | MinFilter::LinearMipmapLinear => Some(Filter::Linear),
+        }
+    }
 }
 
 /// Texture co-ordinate wrapping mode.
-#[derive(Clone, Copy, Debug, Deserialize)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
 pub enum WrappingMode {
     /// Corresponds to `GL_CLAMP_TO_EDGE`.
     ClampToEdge = 1,
@@ -123,6 +187,17 @@ pub enum WrappingMode {
 }
 
 impl WrappingMode {
+    /// Returns the wrapping mode corresponding to an OpenGL enum value, or
+    /// `None` if the value is not a valid wrapping mode.
+    pub fn from_gl_enum(value: u32) -> Option<Self> {
+        match value {
+            CLAMP_TO_EDGE => Some(WrappingMode::ClampToEdge),
+            MIRRORED_REPEAT => Some(WrappingMode::MirroredRepeat),
+            REPEAT => Some(WrappingMode::Repeat),
+            _ => None,
+        }
+    }
+
     /// Returns the corresponding OpenGL enum value.
     pub fn as_gl_enum(&self) -> u32 {
         match *self {
@@ -167,7 +242,7 @@ pub struct Sampler {
 }
 
 /// A texture and its sampler.
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Texture {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
@@ -177,7 +252,11 @@ pub struct Texture {
     pub sampler: Option<Index<Sampler>>,
 
     /// The index of the image used by this texture.
-    pub source: Index<image::Image>,
+    ///
+    /// May be omitted when an extension, such as `KHR_texture_basisu`,
+    /// supplies the image instead.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<Index<image::Image>>,
 
     /// Extension specific data.
     #[serde(default)]
@@ -188,6 +267,51 @@ pub struct Texture {
     pub extras: Extras,
 }
 
+impl Texture {
+    /// Returns `true` if an extension supplies the image of this texture.
+    fn has_extension_source(&self) -> bool {
+        #[cfg(feature = "KHR_texture_basisu")]
+        {
+            if self.extensions.texture_basisu.is_some() {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+impl Validate for Texture {
+    fn validate_minimally<P, R>(&self, root: &Root, path: P, report: &mut R)
+        where P: Fn() -> Path, R: FnMut(&Fn() -> Path, Error)
+    {
+        if self.source.is_none() && !self.has_extension_source() {
+            report(&|| path().field("source"), Error::Missing);
+        }
+
+        self.sampler
+            .validate_minimally(root, || path().field("sampler"), report);
+        self.source
+            .validate_minimally(root, || path().field("source"), report);
+        self.extensions
+            .validate_minimally(root, || path().field("extensions"), report);
+        self.extras
+            .validate_minimally(root, || path().field("extras"), report);
+    }
+
+    fn validate_completely<P, R>(&self, root: &Root, path: P, report: &mut R)
+        where P: Fn() -> Path, R: FnMut(&Fn() -> Path, Error)
+    {
+        self.sampler
+            .validate_completely(root, || path().field("sampler"), report);
+        self.source
+            .validate_completely(root, || path().field("source"), report);
+        self.extensions
+            .validate_completely(root, || path().field("extensions"), report);
+        self.extras
+            .validate_completely(root, || path().field("extras"), report);
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
 /// Reference to a `Texture`.
 pub struct Info {
@@ -222,13 +346,7 @@ impl<'de> de::Deserialize<'de> for Checked<MagFilter> {
             fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
                 where E: de::Error
             {
-                use self::MagFilter::*;
-                use validation::Checked::*;
-                Ok(match value as u32 {
-                    NEAREST => Valid(Nearest),
-                    LINEAR => Valid(Linear),
-                    _ => Invalid,
-                })
+                Ok(MagFilter::from_gl_enum(value as u32).map_or(Checked::Invalid, Checked::Valid))
             }
         }
         deserializer.deserialize_u64(Visitor)
@@ -250,17 +368,7 @@ impl<'de> de::Deserialize<'de> for Checked<MinFilter> {
             fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
                 where E: de::Error
             {
-                use self::MinFilter::*;
-                use validation::Checked::*;
-                Ok(match value as u32 {
-                    NEAREST => Valid(Nearest),
-                    LINEAR => Valid(Linear),
-                    NEAREST_MIPMAP_NEAREST => Valid(NearestMipmapNearest),
-                    LINEAR_MIPMAP_NEAREST => Valid(LinearMipmapNearest),
-                    NEAREST_MIPMAP_LINEAR => Valid(NearestMipmapLinear),
-                    LINEAR_MIPMAP_LINEAR => Valid(LinearMipmapLinear),
-                    _ => Invalid,
-                })
+                Ok(MinFilter::from_gl_enum(value as u32).map_or(Checked::Invalid, Checked::Valid))
             }
         }
         deserializer.deserialize_u64(Visitor)
@@ -291,14 +399,7 @@ impl<'de> de::Deserialize<'de> for Checked<WrappingMode> {
             fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
                 where E: de::Error
             {
-                use self::WrappingMode::*;
-                use validation::Checked::*;
-                Ok(match value as u32 {
-                    CLAMP_TO_EDGE => Valid(ClampToEdge),
-                    MIRRORED_REPEAT => Valid(MirroredRepeat),
-                    REPEAT => Valid(Repeat),
-                    _ => Invalid,
-                })
+                Ok(WrappingMode::from_gl_enum(value as u32).map_or(Checked::Invalid, Checked::Valid))
             }
         }
         deserializer.deserialize_u64(Visitor)
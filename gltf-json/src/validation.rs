@@ -44,10 +44,15 @@ pub enum Error {
 
     /// Some required data has been omitted.
     Missing,
+
+    /// A feature that is not supported by the library was identified.
+    Unsupported,
 }
 
 /// Specifies a type that has been pre-validated during deserialization or otherwise.
-#[derive(Debug, Eq, Hash, PartialEq)]
+///
+/// `Valid` items are ordered before `Invalid` items.
+#[derive(Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum Checked<T> {
     /// The item is valid.
     Valid(T),
@@ -179,6 +184,7 @@ impl std::error::Error for Error {
             Error::IndexOutOfBounds => "Index out of bounds",
             Error::Invalid => "Invalid value",
             Error::Missing => "Missing data",
+            Error::Unsupported => "Unsupported feature",
         }
     }
 }
@@ -201,3 +207,4 @@ impl Validate for [f32; 16] {}
 impl Validate for () {}
 impl Validate for String {}
 impl Validate for serde_json::Value {}
+impl Validate for serde_json::Map<String, serde_json::Value> {}
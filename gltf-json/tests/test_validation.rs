@@ -24,3 +24,445 @@ fn test_accessor_bounds_validate_minimally() {
         [(Path("meshes[0].primitives[0].attributes[\"POSITION\"].min".into()), Error::Missing),
          (Path("meshes[0].primitives[0].attributes[\"POSITION\"].max".into()), Error::Invalid)]);
 }
+
+#[test]
+fn test_attribute_count_mismatch_validate_minimally() {
+    let json = gltf_json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 48 } ],
+        "bufferViews": [ { "buffer": 0, "byteLength": 48 } ],
+        "accessors": [
+            { "bufferView": 0, "componentType": 5126, "count": 4, "type": "VEC3",
+              "min": [0.0, 0.0, 0.0], "max": [1.0, 1.0, 1.0] },
+            { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3",
+              "min": [0.0, 0.0, 0.0], "max": [1.0, 1.0, 1.0] },
+            { "bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC2" }
+        ],
+        "meshes": [
+            {
+                "primitives": [
+                    { "attributes": { "POSITION": 0, "TEXCOORD_0": 2, "NORMAL": 1 } },
+                    { "attributes": { "POSITION": 1, "NORMAL": 1 } }
+                ]
+            }
+        ]
+    }"#).unwrap();
+    for _ in 0..4 {
+        let mut errs = vec![];
+        json.validate_minimally(
+            &json,
+            gltf_json::Path::new,
+            &mut |path, err| errs.push((path(), err)),
+        );
+        assert_eq!(errs, [
+            (Path("meshes[0].primitives[0].attributes[\"NORMAL\"]".into()), Error::Invalid),
+            (Path("meshes[0].primitives[0].attributes[\"TEXCOORD_0\"]".into()), Error::Invalid),
+        ]);
+    }
+}
+
+#[test]
+fn test_quantized_attributes_validate_minimally() {
+    let json = r#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 24 } ],
+        "bufferViews": [ { "buffer": 0, "byteLength": 24, "byteStride": 8 } ],
+        "accessors": [
+            { "bufferView": 0, "componentType": 5122, "count": 3, "type": "VEC3",
+              "min": [0, 0, 0], "max": [1, 1, 0] },
+            { "bufferView": 0, "byteOffset": 6, "componentType": 5120, "normalized": true,
+              "count": 3, "type": "VEC2" },
+            { "bufferView": 0, "componentType": 5120,
+              "count": 3, "type": "VEC3" }
+        ],
+        "meshes": [
+            {
+                "primitives": [
+                    { "attributes": { "POSITION": 0, "TEXCOORD_0": 1, "NORMAL": 2 } }
+                ]
+            }
+        ]
+    }"#;
+    let validate = |json: &gltf_json::Root| {
+        let mut errs = vec![];
+        json.validate_minimally(
+            json,
+            gltf_json::Path::new,
+            &mut |path, err| errs.push((path(), err)),
+        );
+        errs
+    };
+
+    let mut root = gltf_json::Root::from_str(json).unwrap();
+    assert_eq!(validate(&root), [
+        (Path("meshes[0].primitives[0].attributes[\"NORMAL\"]".into()), Error::Invalid),
+        (Path("meshes[0].primitives[0].attributes[\"POSITION\"]".into()), Error::Invalid),
+        (Path("meshes[0].primitives[0].attributes[\"TEXCOORD_0\"]".into()), Error::Invalid),
+    ]);
+
+    // Quantized normals must be normalized even with the extension.
+    root.extensions_required.push("KHR_mesh_quantization".to_string());
+    assert_eq!(validate(&root), [
+        (Path("meshes[0].primitives[0].attributes[\"NORMAL\"]".into()), Error::Invalid),
+    ]);
+
+    root.accessors[2].normalized = true;
+    assert_eq!(validate(&root), []);
+}
+
+#[test]
+fn test_quantized_morph_targets_validate_minimally() {
+    let json = r#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 24 } ],
+        "bufferViews": [ { "buffer": 0, "byteLength": 24, "byteStride": 8 } ],
+        "accessors": [
+            { "bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC3",
+              "min": [0.0, 0.0, 0.0], "max": [1.0, 1.0, 1.0] },
+            { "bufferView": 0, "componentType": 5122, "count": 2, "type": "VEC3" },
+            { "bufferView": 0, "componentType": 5120, "count": 2, "type": "VEC3" },
+            { "bufferView": 0, "componentType": 5121, "normalized": true, "count": 2, "type": "VEC3" }
+        ],
+        "meshes": [
+            {
+                "primitives": [
+                    {
+                        "attributes": { "POSITION": 0 },
+                        "targets": [ { "POSITION": 1, "NORMAL": 2, "TANGENT": 3 } ]
+                    }
+                ]
+            }
+        ]
+    }"#;
+    let validate = |json: &gltf_json::Root| {
+        let mut errs = vec![];
+        json.validate_minimally(
+            json,
+            gltf_json::Path::new,
+            &mut |path, err| errs.push((path(), err)),
+        );
+        errs
+    };
+
+    let mut root = gltf_json::Root::from_str(json).unwrap();
+    assert_eq!(validate(&root), [
+        (Path("meshes[0].primitives[0].targets[0].POSITION".into()), Error::Invalid),
+        (Path("meshes[0].primitives[0].targets[0].NORMAL".into()), Error::Invalid),
+        (Path("meshes[0].primitives[0].targets[0].TANGENT".into()), Error::Invalid),
+    ]);
+
+    // Quantized normal displacements must be normalized, and unsigned
+    // displacements are never permitted.
+    root.extensions_required.push("KHR_mesh_quantization".to_string());
+    assert_eq!(validate(&root), [
+        (Path("meshes[0].primitives[0].targets[0].NORMAL".into()), Error::Invalid),
+        (Path("meshes[0].primitives[0].targets[0].TANGENT".into()), Error::Invalid),
+    ]);
+
+    root.accessors[2].normalized = true;
+    root.accessors[3].component_type = root.accessors[2].component_type.clone();
+    assert_eq!(validate(&root), []);
+}
+
+#[test]
+fn test_attribute_types_validate_minimally() {
+    let json = gltf_json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 64 } ],
+        "bufferViews": [ { "buffer": 0, "byteLength": 64 } ],
+        "accessors": [
+            { "bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC2",
+              "min": [0.0, 0.0, 0.0], "max": [1.0, 1.0, 1.0] },
+            { "bufferView": 0, "componentType": 5123, "count": 2, "type": "VEC4" },
+            { "bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC4" },
+            { "bufferView": 0, "componentType": 5121, "normalized": true, "count": 2, "type": "VEC3" },
+            { "bufferView": 0, "componentType": 5121, "count": 2, "type": "VEC4" },
+            { "bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC3",
+              "min": [0.0, 0.0, 0.0], "max": [1.0, 1.0, 1.0] }
+        ],
+        "meshes": [
+            {
+                "primitives": [
+                    {
+                        "attributes": {
+                            "POSITION": 0,
+                            "JOINTS_0": 2,
+                            "WEIGHTS_0": 4,
+                            "COLOR_0": 3
+                        }
+                    },
+                    {
+                        "attributes": {
+                            "POSITION": 5,
+                            "JOINTS_0": 1,
+                            "WEIGHTS_0": 2,
+                            "COLOR_0": 3
+                        }
+                    }
+                ]
+            }
+        ]
+    }"#).unwrap();
+    let mut errs = vec![];
+    json.validate_minimally(
+        &json,
+        gltf_json::Path::new,
+        &mut |path, err| errs.push((path(), err)),
+    );
+    assert_eq!(errs, [
+        (Path("meshes[0].primitives[0].attributes[\"JOINTS_0\"]".into()), Error::Invalid),
+        (Path("meshes[0].primitives[0].attributes[\"POSITION\"]".into()), Error::Invalid),
+        (Path("meshes[0].primitives[0].attributes[\"WEIGHTS_0\"]".into()), Error::Invalid),
+    ]);
+}
+
+#[cfg(feature = "KHR_materials_clearcoat")]
+#[test]
+fn test_clearcoat_factor_range_validate_completely() {
+    let json = gltf_json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "materials": [
+            { "extensions": { "KHR_materials_clearcoat": { "clearcoatFactor": 1.0 } } },
+            { "extensions": { "KHR_materials_clearcoat": { "clearcoatRoughnessFactor": 1.5 } } }
+        ]
+    }"#).unwrap();
+    let mut errs = vec![];
+    json.validate_completely(
+        &json,
+        gltf_json::Path::new,
+        &mut |path, err| errs.push((path(), err)),
+    );
+    assert_eq!(errs,
+        [(Path("materials[1].extensions.KHR_materials_clearcoat.clearcoatRoughnessFactor".into()), Error::Invalid)]);
+}
+
+#[cfg(feature = "KHR_materials_emissive_strength")]
+#[test]
+fn test_negative_emissive_strength_validate_completely() {
+    let json = gltf_json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "materials": [
+            { "extensions": { "KHR_materials_emissive_strength": { "emissiveStrength": -1.0 } } }
+        ]
+    }"#).unwrap();
+    let mut errs = vec![];
+    json.validate_completely(
+        &json,
+        gltf_json::Path::new,
+        &mut |path, err| errs.push((path(), err)),
+    );
+    assert_eq!(errs,
+        [(Path("materials[0].extensions.KHR_materials_emissive_strength.emissiveStrength".into()), Error::Invalid)]);
+}
+
+#[cfg(feature = "KHR_materials_ior")]
+#[test]
+fn test_ior_range_validate_completely() {
+    let json = gltf_json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "materials": [
+            { "extensions": { "KHR_materials_ior": { "ior": 0.0 } } },
+            { "extensions": { "KHR_materials_ior": { "ior": 1.0 } } },
+            { "extensions": { "KHR_materials_ior": { "ior": 0.5 } } }
+        ]
+    }"#).unwrap();
+    let mut errs = vec![];
+    json.validate_completely(
+        &json,
+        gltf_json::Path::new,
+        &mut |path, err| errs.push((path(), err)),
+    );
+    assert_eq!(errs, [(Path("materials[2].extensions.KHR_materials_ior.ior".into()), Error::Invalid)]);
+}
+
+#[test]
+fn test_node_matrix_and_trs_validate_completely() {
+    let json = gltf_json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "nodes": [
+            { "matrix": [1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1] },
+            { "translation": [1, 2, 3], "scale": [2, 2, 2] },
+            {
+                "matrix": [1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1],
+                "translation": [1, 2, 3]
+            }
+        ]
+    }"#).unwrap();
+    let mut errs = vec![];
+    json.validate_completely(
+        &json,
+        gltf_json::Path::new,
+        &mut |path, err| errs.push((path(), err)),
+    );
+    assert_eq!(errs, [(Path("nodes[2]".into()), Error::Invalid)]);
+}
+
+#[cfg(feature = "KHR_materials_sheen")]
+#[test]
+fn test_sheen_factors_validate_completely() {
+    let json = gltf_json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "materials": [
+            { "extensions": { "KHR_materials_sheen": { "sheenColorFactor": [1.0, 0.5, 0.0] } } },
+            { "extensions": { "KHR_materials_sheen": { "sheenColorFactor": [1.5, 0.5, 0.0] } } },
+            { "extensions": { "KHR_materials_sheen": { "sheenRoughnessFactor": -0.5 } } }
+        ]
+    }"#).unwrap();
+    let mut errs = vec![];
+    json.validate_completely(
+        &json,
+        gltf_json::Path::new,
+        &mut |path, err| errs.push((path(), err)),
+    );
+    assert_eq!(errs, [
+        (Path("materials[1].extensions.KHR_materials_sheen.sheenColorFactor".into()), Error::Invalid),
+        (Path("materials[2].extensions.KHR_materials_sheen.sheenRoughnessFactor".into()), Error::Invalid),
+    ]);
+}
+
+#[cfg(feature = "KHR_materials_specular")]
+#[test]
+fn test_specular_factors_validate_completely() {
+    let json = gltf_json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "materials": [
+            { "extensions": { "KHR_materials_specular": { "specularFactor": 0.5, "specularColorFactor": [2.0, 1.0, 0.0] } } },
+            { "extensions": { "KHR_materials_specular": { "specularFactor": 1.5 } } },
+            { "extensions": { "KHR_materials_specular": { "specularColorFactor": [1.0, -0.5, 1.0] } } }
+        ]
+    }"#).unwrap();
+    let mut errs = vec![];
+    json.validate_completely(
+        &json,
+        gltf_json::Path::new,
+        &mut |path, err| errs.push((path(), err)),
+    );
+    assert_eq!(errs, [
+        (Path("materials[1].extensions.KHR_materials_specular.specularFactor".into()), Error::Invalid),
+        (Path("materials[2].extensions.KHR_materials_specular.specularColorFactor".into()), Error::Invalid),
+    ]);
+}
+
+#[cfg(feature = "KHR_materials_volume")]
+#[test]
+fn test_non_positive_attenuation_distance_validate_completely() {
+    let json = gltf_json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "materials": [
+            { "extensions": { "KHR_materials_volume": { "attenuationDistance": 1.0 } } },
+            { "extensions": { "KHR_materials_volume": { "attenuationDistance": 0.0 } } }
+        ]
+    }"#).unwrap();
+    let mut errs = vec![];
+    json.validate_completely(
+        &json,
+        gltf_json::Path::new,
+        &mut |path, err| errs.push((path(), err)),
+    );
+    assert_eq!(errs,
+        [(Path("materials[1].extensions.KHR_materials_volume.attenuationDistance".into()), Error::Invalid)]);
+}
+
+#[test]
+fn test_node_weights_length_validate_completely() {
+    let json = gltf_json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 36 } ],
+        "bufferViews": [ { "buffer": 0, "byteLength": 36 } ],
+        "accessors": [
+            { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3",
+              "min": [0.0, 0.0, 0.0], "max": [1.0, 1.0, 0.0] }
+        ],
+        "meshes": [
+            {
+                "primitives": [
+                    {
+                        "attributes": { "POSITION": 0 },
+                        "targets": [ { "POSITION": 0 }, { "POSITION": 0 } ]
+                    }
+                ],
+                "weights": [0.5, 0.5]
+            }
+        ],
+        "nodes": [
+            { "mesh": 0, "weights": [1.0, 0.0] },
+            { "mesh": 0, "weights": [1.0] },
+            { "mesh": 0 }
+        ]
+    }"#).unwrap();
+    let mut errs = vec![];
+    json.validate_completely(
+        &json,
+        gltf_json::Path::new,
+        &mut |path, err| errs.push((path(), err)),
+    );
+    assert_eq!(errs, [(Path("nodes[1].weights".into()), Error::Invalid)]);
+}
+
+#[test]
+fn test_node_cycle_validate_minimally() {
+    let json = gltf_json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "nodes": [
+            { "children": [1] },
+            { "children": [2] },
+            { "children": [1, 3] },
+            {},
+            { "children": [4] }
+        ]
+    }"#).unwrap();
+    let mut errs = vec![];
+    json.validate_minimally(
+        &json,
+        gltf_json::Path::new,
+        &mut |path, err| errs.push((path(), err)),
+    );
+    assert_eq!(errs, [
+        (Path("nodes[2].children".into()), Error::Invalid),
+        (Path("nodes[4].children".into()), Error::Invalid),
+    ]);
+}
+
+#[cfg(feature = "EXT_lights_image_based")]
+#[test]
+fn test_image_based_light_indices_validate_minimally() {
+    let json = gltf_json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "extensions": {
+            "EXT_lights_image_based": {
+                "lights": [
+                    {
+                        "irradianceCoefficients": [
+                            [0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0],
+                            [0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0],
+                            [0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0]
+                        ],
+                        "specularImageSize": 1,
+                        "specularImages": [ [0, 0, 0, 0, 0, 1] ]
+                    }
+                ]
+            }
+        },
+        "images": [ { "uri": "0.png" } ],
+        "scenes": [
+            { "nodes": [], "extensions": { "EXT_lights_image_based": { "light": 0 } } },
+            { "nodes": [], "extensions": { "EXT_lights_image_based": { "light": 1 } } }
+        ]
+    }"#).unwrap();
+    let mut errs = vec![];
+    json.validate_minimally(
+        &json,
+        gltf_json::Path::new,
+        &mut |path, err| errs.push((path(), err)),
+    );
+    assert_eq!(errs, [
+        (
+            Path("extensions.EXT_lights_image_based.lights[0].specularImages[0][5]".into()),
+            Error::IndexOutOfBounds,
+        ),
+        (
+            Path("scenes[1].extensions.EXT_lights_image_based.light".into()),
+            Error::IndexOutOfBounds,
+        ),
+    ]);
+}
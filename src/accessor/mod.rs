@@ -39,8 +39,10 @@
 //! for accessor in gltf.accessors() {
 //!     match (accessor.data_type(), accessor.dimensions()) {
 //!         (DataType::F32, Dimensions::Vec3) => {
-//!             let buffer_index = accessor.view().buffer().index();
-//!             let buffer_data = buffers[buffer_index].0.as_slice();
+//!             let buffer_data = match accessor.view() {
+//!                 Some(view) => buffers[view.buffer().index()].0.as_slice(),
+//!                 None => &[],
+//!             };
 //!             let iter = Iter::<[f32; 3]>::new(accessor, buffer_data);
 //!             for item in iter {
 //!                 println!("{:?}", item);
@@ -58,7 +60,15 @@
 
 use {buffer, json};
 
+#[cfg(feature = "utils")]
+use Buffer;
+#[cfg(feature = "import")]
+use Error;
+#[cfg(any(feature = "extras", feature = "import", feature = "utils"))]
+use Result;
 use Document;
+#[cfg(feature = "extras")]
+use extras_as;
 
 pub use json::accessor::ComponentType as DataType;
 pub use json::accessor::Type as Dimensions;
@@ -86,8 +96,8 @@ pub struct Accessor<'a> {
     /// The corresponding JSON struct.
     json: &'a json::accessor::Accessor,
 
-    /// The buffer view this accessor reads from.
-    view: buffer::View<'a>,
+    /// The buffer view this accessor reads from, if any.
+    view: Option<buffer::View<'a>>,
 }
 
 impl<'a> Accessor<'a> {
@@ -97,7 +107,7 @@ impl<'a> Accessor<'a> {
         index: usize,
         json: &'a json::accessor::Accessor,
     ) -> Self {
-        let view = document.views().nth(json.buffer_view.value()).unwrap();
+        let view = json.buffer_view.map(|view| document.views().nth(view.value()).unwrap());
         Self {
             document,
             index,
@@ -112,13 +122,19 @@ impl<'a> Accessor<'a> {
     }
 
     /// Returns the size of each component that this accessor describes.
+    ///
+    /// This includes the padding of the columns of matrices with 1-byte and
+    /// 2-byte components, see `Dimensions::element_size`.
     pub fn size(&self) -> usize {
-        self.data_type().size() * self.dimensions().multiplicity()
+        self.dimensions().element_size(self.data_type())
     }
 
     /// Returns the buffer view this accessor reads from.
-    pub fn view(&self) -> buffer::View<'a> {
-        self.document.views().nth(self.json.buffer_view.value()).unwrap()
+    ///
+    /// Returns `None` if the accessor has no buffer view, in which case its
+    /// elements are zero before sparse substitution is applied.
+    pub fn view(&self) -> Option<buffer::View<'a>> {
+        self.view.clone()
     }
 
     /// Returns the offset relative to the start of the parent buffer view in bytes.
@@ -126,8 +142,12 @@ impl<'a> Accessor<'a> {
         self.json.byte_offset as usize
     }
 
-    /// Returns the number of components within the buffer view - not to be confused
-    /// with the number of bytes in the buffer view.
+    /// Returns the number of elements referenced by the accessor, for example
+    /// the number of `[f32; 3]` vertex positions - not to be confused with the
+    /// number of components or bytes in the buffer view.
+    ///
+    /// Iterators over the accessor yield exactly this many items, so an
+    /// accessor with a count of zero yields an empty iterator.
     pub fn count(&self) -> usize {
         self.json.count as usize
     }
@@ -142,6 +162,17 @@ impl<'a> Accessor<'a> {
         &self.json.extras
     }
 
+    /// Deserializes the application specific data into a user type.
+    ///
+    /// Returns `None` if there is no application specific data.
+    #[cfg(feature = "extras")]
+    pub fn extras_as<T>(&self) -> Option<Result<T>>
+    where
+        T: json::deserialize::DeserializeOwned,
+    {
+        extras_as(&self.json.extras)
+    }
+
     /// Specifies if the attribute is a scalar, vector, or matrix.
     pub fn dimensions(&self) -> Dimensions {
         self.json.type_.unwrap()
@@ -157,6 +188,49 @@ impl<'a> Accessor<'a> {
         self.json.max.clone()
     }
 
+    /// Returns the minimum value of each component in this attribute as
+    /// `f32`, or `None` if absent or malformed.
+    pub fn min_values(&self) -> Option<Vec<f32>> {
+        self.bounds(self.json.min.as_ref(), json::Value::as_f64)
+            .map(|values| values.into_iter().map(|x| x as f32).collect())
+    }
+
+    /// Returns the maximum value of each component in this attribute as
+    /// `f32`, or `None` if absent or malformed.
+    pub fn max_values(&self) -> Option<Vec<f32>> {
+        self.bounds(self.json.max.as_ref(), json::Value::as_f64)
+            .map(|values| values.into_iter().map(|x| x as f32).collect())
+    }
+
+    /// Returns the minimum value of each component in this integer attribute,
+    /// or `None` if absent, malformed, or the data type is `F32`.
+    pub fn min_values_int(&self) -> Option<Vec<i64>> {
+        if self.data_type() == DataType::F32 {
+            return None;
+        }
+        self.bounds(self.json.min.as_ref(), as_integer)
+    }
+
+    /// Returns the maximum value of each component in this integer attribute,
+    /// or `None` if absent, malformed, or the data type is `F32`.
+    pub fn max_values_int(&self) -> Option<Vec<i64>> {
+        if self.data_type() == DataType::F32 {
+            return None;
+        }
+        self.bounds(self.json.max.as_ref(), as_integer)
+    }
+
+    /// Parses a `min` or `max` array with one element per component.
+    fn bounds<T, F>(&self, value: Option<&json::Value>, f: F) -> Option<Vec<T>>
+        where F: Fn(&json::Value) -> Option<T>
+    {
+        let array = value?.as_array()?;
+        if array.len() != self.dimensions().multiplicity() {
+            return None;
+        }
+        array.iter().map(f).collect()
+    }
+
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     pub fn name(&self) -> Option<&str> {
@@ -168,11 +242,79 @@ impl<'a> Accessor<'a> {
         self.json.normalized
     }
 
+    /// Returns an iterator that visits the items of this accessor as type `T`,
+    /// applying sparse substitution if present.
+    ///
+    /// Returns `Error::AccessorType` if the data type or dimensions of the
+    /// accessor do not match `T`, or `Error::MissingBufferData` if
+    /// `get_buffer_data` does not provide the data of a referenced buffer.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), Box<std::error::Error>> {
+    /// let (gltf, buffers, _) = gltf::import("examples/Box.gltf")?;
+    /// let accessor = gltf.accessors().nth(1).unwrap();
+    /// let iter = accessor.iter::<[f32; 3], _>(|buffer| Some(&buffers[buffer.index()]))?;
+    /// assert_eq!(iter.len(), accessor.count());
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #    let _ = run().expect("runtime error");
+    /// # }
+    /// ```
+    #[cfg(feature = "utils")]
+    pub fn iter<'s, T, F>(&self, get_buffer_data: F) -> Result<Iter<'s, T>>
+    where
+        T: Item,
+        F: Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        Iter::from_accessor(self.clone(), get_buffer_data)
+    }
+
+    /// Returns the bytes of this accessor from the imported buffer data, where
+    /// `data` is indexed by buffer index as returned by `gltf::import`.
+    ///
+    /// The slice begins at the first element and ends after the last, so for
+    /// interleaved views it includes the bytes of other attributes between
+    /// elements. Sparse substitution is not applied.
+    ///
+    /// Returns an error if `data` does not contain the buffer, or if the
+    /// accessor or its view exceed the available bytes. Returns an empty
+    /// slice if the accessor has no buffer view.
+    #[cfg(feature = "import")]
+    pub fn slice<'s>(&self, data: &'s [buffer::Data]) -> Result<&'s [u8]> {
+        let view = match self.view {
+            Some(ref view) => view,
+            None => return Ok(&[]),
+        };
+        let bytes = view.slice(data)?;
+        let size = self.size();
+        let stride = view.stride().unwrap_or(size);
+        let length = match self.count() {
+            0 => Some(0),
+            count => (count - 1).checked_mul(stride).and_then(|x| x.checked_add(size)),
+        };
+        let start = self.offset();
+        length
+            .and_then(|length| start.checked_add(length))
+            .and_then(|end| bytes.get(start .. end))
+            .ok_or(Error::AccessorBounds { accessor: self.index })
+    }
+
     /// Returns sparse storage of attributes that deviate from their initialization
     /// value.
-    pub fn sparse(&self) -> Option<sparse::Sparse> {
+    pub fn sparse(&self) -> Option<sparse::Sparse<'a>> {
         self.json.sparse.as_ref().map(|json| {
             sparse::Sparse::new(self.document, json)
         })
     }
 }
+
+/// Parses a JSON number as an integer, accepting floating point numbers
+/// without a fractional part.
+fn as_integer(value: &json::Value) -> Option<i64> {
+    value.as_i64().or_else(|| {
+        value.as_f64()
+            .filter(|x| x.fract() == 0.0)
+            .map(|x| x as i64)
+    })
+}
@@ -2,61 +2,137 @@ use std::mem;
 use byteorder::{LE, ByteOrder};
 use std::marker::PhantomData;
 
+use super::DataType;
+use super::sparse::IndexType;
+use {Buffer, Error, Result};
+
+#[doc(inline)]
+pub use Normalize;
+
 /// Represents items that can be read by an [`Accessor`].
 ///
 /// [`Accessor`]: struct.Accessor.html
 pub trait Item {
+    /// The data type of each component of this type.
+    const DATA_TYPE: DataType;
+
+    /// The number of components of this type.
+    const COMPONENTS: usize;
+
     /// Create an object of this type from a byte slice.
     fn from_slice(slice: &[u8]) -> Self;
 }
 
 /// Visits the items in an [`Accessor`].
 ///
+/// The number of items yielded is always equal to the accessor count, hence
+/// the iterator implements `ExactSizeIterator`. Consecutive items are read
+/// at the byte stride of the buffer view, hence padding between interleaved
+/// attributes is skipped. Accessors without a buffer view yield zeros.
+///
 /// [`Accessor`]: struct.Accessor.html
 #[derive(Copy, Clone, Debug)]
 pub struct Iter<'a, T> {
     stride: usize,
+    /// The bytes of the items, or an empty slice when the accessor has no
+    /// buffer view.
     data: &'a [u8],
+    index: usize,
+    count: usize,
+    normalized: bool,
+    sparse: Option<Sparse<'a>>,
     _phantom: PhantomData<T>,
 }
 
+/// The buffer data an accessor reads from, gathered before the item type is
+/// known.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct Source<'a> {
+    /// The buffer data of the accessor view, or an empty slice when the
+    /// accessor has no buffer view.
+    data: &'a [u8],
+
+    /// Sparse substitutions, if any.
+    sparse: Option<Sparse<'a>>,
+}
+
+/// Zero bytes from which the items of accessors without a buffer view are
+/// read. Large enough for any item type.
+static ZEROS: [u8; 64] = [0; 64];
+
+/// Sparse substitutions of an [`Iter`].
+///
+/// [`Iter`]: struct.Iter.html
+#[derive(Copy, Clone, Debug)]
+struct Sparse<'a> {
+    /// Tightly packed indices of the substituted items, in increasing order.
+    indices: &'a [u8],
+
+    /// The size of each index in bytes.
+    index_size: usize,
+
+    /// Tightly packed substitute items.
+    values: &'a [u8],
+}
+
 impl Item for i8 {
+    const DATA_TYPE: DataType = DataType::I8;
+    const COMPONENTS: usize = 1;
+
     fn from_slice(slice: &[u8]) -> Self {
         slice[0] as i8
     }
 }
 
 impl Item for i16 {
+    const DATA_TYPE: DataType = DataType::I16;
+    const COMPONENTS: usize = 1;
+
     fn from_slice(slice: &[u8]) -> Self {
         LE::read_i16(slice)
     }
 }
 
 impl Item for u8 {
+    const DATA_TYPE: DataType = DataType::U8;
+    const COMPONENTS: usize = 1;
+
     fn from_slice(slice: &[u8]) -> Self {
         slice[0]
     }
 }
 
 impl Item for u16 {
+    const DATA_TYPE: DataType = DataType::U16;
+    const COMPONENTS: usize = 1;
+
     fn from_slice(slice: &[u8]) -> Self {
         LE::read_u16(slice)
     }
 }
 
 impl Item for u32 {
+    const DATA_TYPE: DataType = DataType::U32;
+    const COMPONENTS: usize = 1;
+
     fn from_slice(slice: &[u8]) -> Self {
         LE::read_u32(slice)
     }
 }
 
 impl Item for f32 {
+    const DATA_TYPE: DataType = DataType::F32;
+    const COMPONENTS: usize = 1;
+
     fn from_slice(slice: &[u8]) -> Self {
         LE::read_f32(slice)
     }
 }
 
 impl<T: Item> Item for [T; 2] {
+    const DATA_TYPE: DataType = T::DATA_TYPE;
+    const COMPONENTS: usize = 2 * T::COMPONENTS;
+
     fn from_slice(slice: &[u8]) -> Self {
         assert!(slice.len() >= 2 * mem::size_of::<T>());
         [T::from_slice(slice),
@@ -65,6 +141,9 @@ impl<T: Item> Item for [T; 2] {
 }
 
 impl<T: Item> Item for [T; 3] {
+    const DATA_TYPE: DataType = T::DATA_TYPE;
+    const COMPONENTS: usize = 3 * T::COMPONENTS;
+
     fn from_slice(slice: &[u8]) -> Self {
         assert!(slice.len() >= 3 * mem::size_of::<T>());
         [T::from_slice(slice),
@@ -74,6 +153,9 @@ impl<T: Item> Item for [T; 3] {
 }
 
 impl<T: Item> Item for [T; 4] {
+    const DATA_TYPE: DataType = T::DATA_TYPE;
+    const COMPONENTS: usize = 4 * T::COMPONENTS;
+
     fn from_slice(slice: &[u8]) -> Self {
         assert!(slice.len() >= 4 * mem::size_of::<T>());
         [T::from_slice(slice),
@@ -83,21 +165,211 @@ impl<T: Item> Item for [T; 4] {
     }
 }
 
+impl<'a> Source<'a> {
+    /// Gathers the buffer data of an accessor, including that of its sparse
+    /// substitutions.
+    ///
+    /// Returns `Error::MissingBufferData` if `get_buffer_data` does not
+    /// provide the data of a referenced buffer.
+    pub(crate) fn new<'d, F>(accessor: &super::Accessor<'d>, get_buffer_data: F) -> Result<Self>
+    where
+        F: Fn(Buffer<'d>) -> Option<&'a [u8]>,
+    {
+        let buffer_data = |buffer: Buffer<'d>| {
+            let index = buffer.index();
+            get_buffer_data(buffer).ok_or(Error::MissingBufferData { buffer: index })
+        };
+
+        let sparse = match accessor.sparse() {
+            Some(sparse) => {
+                let count = sparse.count() as usize;
+                let indices = sparse.indices();
+                let index_size = match indices.index_type() {
+                    IndexType::U8 => 1,
+                    IndexType::U16 => 2,
+                    IndexType::U32 => 4,
+                };
+                let view = indices.view();
+                let start = view.offset() + indices.offset() as usize;
+                let end = start + count * index_size;
+                let indices = &buffer_data(view.buffer())?[start .. end];
+
+                let values = sparse.values();
+                let view = values.view();
+                let start = view.offset() + values.offset() as usize;
+                let end = start + count * accessor.size();
+                let values = &buffer_data(view.buffer())?[start .. end];
+
+                Some(Sparse { indices, index_size, values })
+            },
+            None => None,
+        };
+
+        let data = match accessor.view() {
+            Some(view) => buffer_data(view.buffer())?,
+            None => &[],
+        };
+        Ok(Source { data, sparse })
+    }
+}
+
+/// Returns the byte stride of the items of an accessor and the bytes from the
+/// first item to the end of the last, where items are `size` bytes long, or
+/// an empty slice when the accessor has no buffer view.
+fn view_data<'a>(
+    accessor: &super::Accessor,
+    buffer_data: &'a [u8],
+    size: usize,
+) -> (usize, &'a [u8]) {
+    let count = accessor.count();
+    match accessor.view() {
+        Some(view) => {
+            let stride = view.stride().unwrap_or(size);
+            debug_assert!(stride >= size);
+            let start = view.offset() + accessor.offset();
+            let end = if count > 0 {
+                start + stride * (count - 1) + size
+            } else {
+                start
+            };
+            (stride, &buffer_data[start .. end])
+        },
+        None => (size, &[][..]),
+    }
+}
+
+/// Copies the items of an accessor into `dst` without interpreting them,
+/// applying sparse substitution if present.
+///
+/// Item `i` is written to `dst[offset + i * stride ..]`.
+pub(crate) fn copy_items(
+    accessor: &super::Accessor,
+    source: Source,
+    dst: &mut [u8],
+    offset: usize,
+    stride: usize,
+) {
+    let size = accessor.size();
+    let (src_stride, data) = view_data(accessor, source.data, size);
+    let mut sparse = source.sparse;
+    for index in 0 .. accessor.count() {
+        let item = if data.is_empty() {
+            &ZEROS[.. size]
+        } else {
+            &data[index * src_stride ..][.. size]
+        };
+        let item = sparse
+            .as_mut()
+            .and_then(|sparse| sparse.substitute_bytes(index, size))
+            .unwrap_or(item);
+        dst[offset + index * stride ..][.. size].copy_from_slice(item);
+    }
+}
+
 impl<'a, T> Iter<'a, T> {
     /// Constructor.
+    ///
+    /// `buffer_data` is the data of the buffer of the accessor view. It is
+    /// ignored if the accessor has no buffer view. Sparse substitution is not
+    /// applied.
     pub fn new(
         accessor: super::Accessor,
         buffer_data: &'a [u8],
     ) -> Iter<'a, T> {
         debug_assert_eq!(mem::size_of::<T>(), accessor.size());
         debug_assert!(mem::size_of::<T>() > 0);
-        let view = accessor.view();
-        let stride = view.stride().unwrap_or(mem::size_of::<T>());
-        debug_assert!(stride >= mem::size_of::<T>());
-        let start = view.offset() + accessor.offset();
-        let end = start + stride * (accessor.count() - 1) + mem::size_of::<T>();
-        let data = &buffer_data[start .. end];
-        Iter { stride, data, _phantom: PhantomData }
+        let count = accessor.count();
+        let normalized = accessor.normalized();
+        let (stride, data) = view_data(&accessor, buffer_data, mem::size_of::<T>());
+        Iter { stride, data, index: 0, count, normalized, sparse: None, _phantom: PhantomData }
+    }
+
+    /// Constructs an iterator from previously gathered buffer data, applying
+    /// sparse substitution if present.
+    pub(crate) fn from_source(accessor: super::Accessor, source: Source<'a>) -> Iter<'a, T> {
+        let mut iter = Iter::new(accessor, source.data);
+        iter.sparse = source.sparse;
+        iter
+    }
+
+    /// Returns `true` if the items are integers that represent values in the
+    /// range `[0.0, 1.0]` (unsigned) or `[-1.0, 1.0]` (signed).
+    pub fn normalized(&self) -> bool {
+        self.normalized
+    }
+}
+
+impl<'a, T: Item> Iter<'a, T> {
+    /// Constructs an iterator over the items of an accessor, applying sparse
+    /// substitution if present.
+    ///
+    /// Returns an error if the accessor data type or dimensions do not match
+    /// `T`, or if `get_buffer_data` does not provide the required buffer data.
+    pub(crate) fn from_accessor<'d, F>(
+        accessor: super::Accessor<'d>,
+        get_buffer_data: F,
+    ) -> Result<Iter<'a, T>>
+    where
+        F: Fn(Buffer<'d>) -> Option<&'a [u8]>,
+    {
+        let matches = accessor.data_type() == T::DATA_TYPE
+            && accessor.dimensions().multiplicity() == T::COMPONENTS
+            && accessor.size() == mem::size_of::<T>();
+        if !matches {
+            return Err(Error::AccessorType { accessor: accessor.index() });
+        }
+        let source = Source::new(&accessor, get_buffer_data)?;
+        Ok(Iter::from_source(accessor, source))
+    }
+
+    /// Reads the item at `index`, applying sparse substitution.
+    fn read(&mut self, index: usize) -> T {
+        let item = if self.data.is_empty() {
+            T::from_slice(&ZEROS[.. mem::size_of::<T>()])
+        } else {
+            T::from_slice(&self.data[index * self.stride ..])
+        };
+        match self.sparse.as_mut() {
+            Some(sparse) => sparse.substitute(index).unwrap_or(item),
+            None => item,
+        }
+    }
+}
+
+impl<'a> Sparse<'a> {
+    /// Returns the index of the next substituted item.
+    fn peek_index(&self) -> Option<usize> {
+        if self.indices.len() < self.index_size {
+            return None;
+        }
+        Some(match self.index_size {
+            1 => self.indices[0] as usize,
+            2 => LE::read_u16(self.indices) as usize,
+            _ => LE::read_u32(self.indices) as usize,
+        })
+    }
+
+    /// Returns the substitute for the item at `index`, if any, discarding the
+    /// substitutes for all items before `index`.
+    fn substitute<T: Item>(&mut self, index: usize) -> Option<T> {
+        self.substitute_bytes(index, mem::size_of::<T>()).map(T::from_slice)
+    }
+
+    /// Returns the bytes of the substitute for the item at `index`, if any,
+    /// where items are `size` bytes long.
+    fn substitute_bytes(&mut self, index: usize, size: usize) -> Option<&'a [u8]> {
+        while let Some(next) = self.peek_index() {
+            if next > index {
+                break;
+            }
+            let value = &self.values[.. size];
+            self.indices = &self.indices[self.index_size ..];
+            self.values = &self.values[size ..];
+            if next == index {
+                return Some(value);
+            }
+        }
+        None
     }
 }
 
@@ -106,42 +378,24 @@ impl<'a, T: Item> Iterator for Iter<'a, T> {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let stride = if self.data.len() >= self.stride {
-            Some(self.stride)
-        } else if self.data.len() >= mem::size_of::<T>() {
-            Some(mem::size_of::<T>())
-        } else {
-            None
-        };
-        if let Some(stride) = stride {
-            let (val, data) = self.data.split_at(stride);
-            let val = T::from_slice(val);
-            self.data = data;
-            Some(val)
+        if self.index < self.count {
+            let index = self.index;
+            self.index += 1;
+            Some(self.read(index))
         } else {
             None
         }
     }
 
     fn nth(&mut self, nth: usize) -> Option<Self::Item> {
-        if let Some(val_data) = self.data.get(nth * self.stride ..) {
-            if val_data.len() >= mem::size_of::<T>() {
-                let val = T::from_slice(val_data);
-                self.data = &val_data[self.stride.min(val_data.len()) ..];
-                Some(val)
-            } else {
-                None
-            }
-        } else {
-            None
-        }
+        self.index = self.index.saturating_add(nth).min(self.count);
+        self.next()
     }
 
-    fn last(self) -> Option<Self::Item> {
-        if self.data.len() >= mem::size_of::<T>() {
-            self.data
-                .get((self.data.len() - 1) / self.stride * self.stride ..)
-                .map(T::from_slice)
+    fn last(mut self) -> Option<Self::Item> {
+        if self.index < self.count {
+            let index = self.count - 1;
+            Some(self.read(index))
         } else {
             None
         }
@@ -152,8 +406,7 @@ impl<'a, T: Item> Iterator for Iter<'a, T> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let hint = self.data.len() / self.stride
-            + (self.data.len() % self.stride >= mem::size_of::<T>()) as usize;
+        let hint = self.count - self.index;
         (hint, Some(hint))
     }
 }
@@ -92,6 +92,20 @@ impl<'a> Animation<'a> {
         }
     }
 
+    /// Returns the length of the animation in seconds, that is the latest
+    /// keyframe time of any of its samplers.
+    ///
+    /// Keyframe times are taken from the `max` property of each sampler input
+    /// accessor, which the specification requires, so no buffer data is read.
+    /// Inputs without a valid `max` are ignored. Returns `0.0` if the
+    /// animation has no samplers.
+    pub fn duration(&self) -> f32 {
+        self.samplers()
+            .filter_map(|sampler| sampler.input().max())
+            .filter_map(|max| json::deserialize::from_value::<[f32; 1]>(max).ok())
+            .fold(0.0, |duration, [time]| duration.max(time))
+    }
+
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     pub fn name(&self) -> Option<&str> {
@@ -179,7 +193,7 @@ impl<'a> Target<'a> {
     }
 
     /// Returns the target node.
-    pub fn node(&self) -> scene::Node {
+    pub fn node(&self) -> scene::Node<'a> {
         self.anim.document.nodes().nth(self.json.node.value()).unwrap()
     }
 
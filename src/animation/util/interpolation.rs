@@ -0,0 +1,161 @@
+use animation::Interpolation;
+
+/// Evaluates a morph target weight sampler at `time`.
+///
+/// `inputs` are the keyframe times in ascending order and `outputs` the
+/// keyframe output values as read from the sampler, with the weights of every
+/// morph target stored consecutively for each keyframe. For `CubicSpline`
+/// samplers each keyframe stores the in-tangents, the values and the
+/// out-tangents in that order, and for `CatmullRomSpline` samplers the outputs
+/// begin and end with an extra control point.
+///
+/// Times before the first keyframe and after the last keyframe are clamped,
+/// and a NaN time is clamped to the first keyframe. Returns one weight per morph target, or an empty `Vec` if there are no
+/// keyframes.
+pub fn morph_target_weights(
+    interpolation: Interpolation,
+    inputs: &[f32],
+    outputs: &[f32],
+    time: f32,
+) -> Vec<f32> {
+    let keyframes = inputs.len();
+    if keyframes == 0 {
+        return vec![];
+    }
+    let stride = match interpolation {
+        Interpolation::Linear | Interpolation::Step => outputs.len() / keyframes,
+        Interpolation::CubicSpline => outputs.len() / (3 * keyframes),
+        Interpolation::CatmullRomSpline => outputs.len() / (keyframes + 2),
+    };
+    let values = |key: usize| -> &[f32] {
+        let start = match interpolation {
+            Interpolation::Linear | Interpolation::Step => key * stride,
+            Interpolation::CubicSpline => (3 * key + 1) * stride,
+            Interpolation::CatmullRomSpline => (key + 1) * stride,
+        };
+        &outputs[start .. start + stride]
+    };
+
+    if !(time > inputs[0]) {
+        return values(0).to_vec();
+    }
+    // Malformed inputs that are not in ascending order or contain NaN may
+    // have no later keyframe.
+    let next = match inputs.iter().position(|&t| t > time) {
+        Some(next) => next,
+        None => return values(keyframes - 1).to_vec(),
+    };
+    let previous = next - 1;
+    let delta = inputs[next] - inputs[previous];
+    let s = (time - inputs[previous]) / delta;
+
+    match interpolation {
+        Interpolation::Step => values(previous).to_vec(),
+        Interpolation::Linear => {
+            values(previous)
+                .iter()
+                .zip(values(next))
+                .map(|(a, b)| a + s * (b - a))
+                .collect()
+        },
+        Interpolation::CubicSpline => {
+            // Tangents are scaled by the keyframe delta time, as the spline
+            // is parameterised over `[0, 1]` between keyframes.
+            let out_tangents = &outputs[3 * previous * stride + 2 * stride ..][.. stride];
+            let in_tangents = &outputs[3 * next * stride ..][.. stride];
+            (0 .. stride)
+                .map(|i| {
+                    hermite(
+                        values(previous)[i],
+                        delta * out_tangents[i],
+                        values(next)[i],
+                        delta * in_tangents[i],
+                        s,
+                    )
+                })
+                .collect()
+        },
+        Interpolation::CatmullRomSpline => {
+            // Control point `key + 1` belongs to keyframe `key`, so the
+            // neighbouring points of the segment always exist.
+            let point = |key: usize| &outputs[key * stride .. (key + 1) * stride];
+            let (p0, p1, p2, p3) = (point(previous), point(previous + 1), point(next + 1), point(next + 2));
+            (0 .. stride)
+                .map(|i| {
+                    let m1 = 0.5 * (p2[i] - p0[i]);
+                    let m2 = 0.5 * (p3[i] - p1[i]);
+                    hermite(p1[i], m1, p2[i], m2, s)
+                })
+                .collect()
+        },
+    }
+}
+
+/// Evaluates the cubic Hermite spline from `p0` with tangent `m0` to `p1`
+/// with tangent `m1` at `s` in `[0, 1]`.
+fn hermite(p0: f32, m0: f32, p1: f32, m1: f32, s: f32) -> f32 {
+    let s2 = s * s;
+    let s3 = s2 * s;
+    (2.0 * s3 - 3.0 * s2 + 1.0) * p0
+        + (s3 - 2.0 * s2 + s) * m0
+        + (-2.0 * s3 + 3.0 * s2) * p1
+        + (s3 - s2) * m1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::morph_target_weights;
+    use animation::Interpolation;
+
+    #[test]
+    fn cubic_spline_weights() {
+        let inputs = [1.0, 3.0];
+        let outputs = [
+            // in-tangents, values and out-tangents of the first keyframe.
+            9.0, 9.0,   0.0, 1.0,   2.0, 0.0,
+            // in-tangents, values and out-tangents of the second keyframe.
+            0.0, 0.5,   1.0, 0.0,   9.0, 9.0,
+        ];
+        let weights = |time| morph_target_weights(Interpolation::CubicSpline, &inputs, &outputs, time);
+
+        assert_eq!(weights(0.0), [0.0, 1.0]);
+        assert_eq!(weights(1.0), [0.0, 1.0]);
+        // With `s = 0.5` and a delta time of 2, the first weight is
+        // `0.5 * 1.0 + 0.125 * 2.0 * 2.0` and the second weight is
+        // `0.5 * 1.0 - 0.125 * 2.0 * 0.5`.
+        assert_eq!(weights(2.0), [1.0, 0.375]);
+        assert_eq!(weights(3.0), [1.0, 0.0]);
+        assert_eq!(weights(4.0), [1.0, 0.0]);
+    }
+
+    #[test]
+    fn linear_and_step_weights() {
+        let inputs = [0.0, 1.0, 2.0];
+        let outputs = [0.0, 1.0, 1.0, 0.0, 0.5, 0.5];
+        let linear = morph_target_weights(Interpolation::Linear, &inputs, &outputs, 1.5);
+        assert_eq!(linear, [0.75, 0.25]);
+        let step = morph_target_weights(Interpolation::Step, &inputs, &outputs, 1.5);
+        assert_eq!(step, [1.0, 0.0]);
+        assert!(morph_target_weights(Interpolation::Linear, &[], &[], 0.0).is_empty());
+    }
+
+    #[test]
+    fn nan_time_weights() {
+        let inputs = [0.0, 1.0, 2.0];
+        let outputs = [0.0, 1.0, 1.0, 0.0, 0.5, 0.5];
+        let weights = morph_target_weights(Interpolation::Linear, &inputs, &outputs, ::std::f32::NAN);
+        assert_eq!(weights, [0.0, 1.0]);
+
+        let inputs = [0.0, ::std::f32::NAN, 2.0];
+        let weights = morph_target_weights(Interpolation::Linear, &inputs, &outputs, 3.0);
+        assert_eq!(weights, [0.5, 0.5]);
+    }
+
+    #[test]
+    fn catmull_rom_spline_weights() {
+        let inputs = [0.0, 1.0];
+        let outputs = [-1.0, 0.0, 1.0, 2.0];
+        let weights = morph_target_weights(Interpolation::CatmullRomSpline, &inputs, &outputs, 0.5);
+        assert_eq!(weights, [0.5]);
+    }
+}
@@ -4,7 +4,11 @@ pub mod rotations;
 /// Casting iterator adapters for morph target weights.
 pub mod morph_target_weights;
 
+/// Evaluation of animation samplers between keyframes.
+pub mod interpolation;
+
 use accessor;
+use accessor::util::Source;
 
 use animation::Channel;
 use Buffer;
@@ -82,6 +86,10 @@ impl<'a> Rotations<'a> {
 
     /// Reinterpret rotations as u16.  Lossy if underlying iterator yields i16,
     /// u16 or f32.
+    ///
+    /// Negative components are clamped to zero, which does not preserve the
+    /// rotation. Use `into_i8`, `into_i16`, or `into_f32` instead.
+    #[deprecated(note = "clamps negative quaternion components to zero")]
     pub fn into_u8(self) -> rotations::CastingIter<'a, rotations::U8> {
         rotations::CastingIter::new(self)
     }
@@ -93,6 +101,10 @@ impl<'a> Rotations<'a> {
     }
 
     /// Reinterpret rotations as u16.  Lossy if underlying iterator yields f32.
+    ///
+    /// Negative components are clamped to zero, which does not preserve the
+    /// rotation. Use `into_i8`, `into_i16`, or `into_f32` instead.
+    #[deprecated(note = "clamps negative quaternion components to zero")]
     pub fn into_u16(self) -> rotations::CastingIter<'a, rotations::U16> {
         rotations::CastingIter::new(self)
     }
@@ -141,12 +153,9 @@ where F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
 {
     /// Visits the input samples of a channel.
     pub fn read_inputs(&self) -> Option<ReadInputs<'s>> {
-        let buffer = self.channel.sampler().input().view().buffer();
-        if let Some(slice) = (self.get_buffer_data)(buffer) {
-            Some(accessor::Iter::new(self.channel.sampler().input(), slice))
-        } else {
-            None
-        }
+        let input = self.channel.sampler().input();
+        let source = Source::new(&input, &self.get_buffer_data).ok()?;
+        Some(accessor::Iter::from_source(input, source))
     }
 
     /// Visits the output samples of a channel.
@@ -155,25 +164,25 @@ where F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
         use animation::Property;
 
         let output = self.channel.sampler().output();
-        if let Some(slice) = (self.get_buffer_data)(output.view().buffer()) {
+        if let Ok(source) = Source::new(&output, &self.get_buffer_data) {
             Some(
                 match self.channel.target().property() {
-                    Property::Translation => ReadOutputs::Translations(Iter::new(output, slice)),
+                    Property::Translation => ReadOutputs::Translations(Iter::from_source(output, source)),
                     Property::Rotation => ReadOutputs::Rotations(match output.data_type() {
-                        DataType::I8 => Rotations::I8(Iter::new(output, slice)),
-                        DataType::U8 => Rotations::U8(Iter::new(output, slice)),
-                        DataType::I16 => Rotations::I16(Iter::new(output, slice)),
-                        DataType::U16 => Rotations::U16(Iter::new(output, slice)),
-                        DataType::F32 => Rotations::F32(Iter::new(output, slice)),
+                        DataType::I8 => Rotations::I8(Iter::from_source(output, source)),
+                        DataType::U8 => Rotations::U8(Iter::from_source(output, source)),
+                        DataType::I16 => Rotations::I16(Iter::from_source(output, source)),
+                        DataType::U16 => Rotations::U16(Iter::from_source(output, source)),
+                        DataType::F32 => Rotations::F32(Iter::from_source(output, source)),
                         _ => unreachable!()
                     }),
-                    Property::Scale => ReadOutputs::Scales(Iter::new(output, slice)),
+                    Property::Scale => ReadOutputs::Scales(Iter::from_source(output, source)),
                     Property::MorphTargetWeights => ReadOutputs::MorphTargetWeights(match output.data_type() {
-                        DataType::I8 => MorphTargetWeights::I8(Iter::new(output, slice)),
-                        DataType::U8 => MorphTargetWeights::U8(Iter::new(output, slice)),
-                        DataType::I16 => MorphTargetWeights::I16(Iter::new(output, slice)),
-                        DataType::U16 => MorphTargetWeights::U16(Iter::new(output, slice)),
-                        DataType::F32 => MorphTargetWeights::F32(Iter::new(output, slice)),
+                        DataType::I8 => MorphTargetWeights::I8(Iter::from_source(output, source)),
+                        DataType::U8 => MorphTargetWeights::U8(Iter::from_source(output, source)),
+                        DataType::I16 => MorphTargetWeights::I16(Iter::from_source(output, source)),
+                        DataType::U16 => MorphTargetWeights::U16(Iter::from_source(output, source)),
+                        DataType::F32 => MorphTargetWeights::F32(Iter::from_source(output, source)),
                         _ => unreachable!()
                     }),
                 }
@@ -182,4 +191,20 @@ where F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
             None
         }
     }
+
+    /// Evaluates the morph target weights of a channel at `time`, returning
+    /// one weight per morph target.
+    ///
+    /// Returns `None` if the channel does not target morph target weights or
+    /// the buffer data of the sampler is not available. See
+    /// `interpolation::morph_target_weights` for details.
+    pub fn sample_morph_target_weights(&self, time: f32) -> Option<Vec<f32>> {
+        let inputs: Vec<f32> = self.read_inputs()?.collect();
+        let outputs: Vec<f32> = match self.read_outputs()? {
+            ReadOutputs::MorphTargetWeights(weights) => weights.into_f32().collect(),
+            _ => return None,
+        };
+        let interpolation = self.channel.sampler().interpolation();
+        Some(interpolation::morph_target_weights(interpolation, &inputs, &outputs, time))
+    }
 }
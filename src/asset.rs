@@ -0,0 +1,72 @@
+use json;
+
+/// The newest glTF version supported by this crate, as `(major, minor)`.
+pub const SUPPORTED_VERSION: (u32, u32) = (2, 0);
+
+/// Metadata about the glTF asset.
+#[derive(Clone, Debug)]
+pub struct Asset<'a> {
+    /// The corresponding JSON struct.
+    json: &'a json::Asset,
+}
+
+impl<'a> Asset<'a> {
+    /// Constructs an `Asset`.
+    pub(crate) fn new(json: &'a json::Asset) -> Self {
+        Self {
+            json: json,
+        }
+    }
+
+    /// A copyright message suitable for display to credit the content creator.
+    pub fn copyright(&self) -> Option<&'a str> {
+        self.json.copyright.as_ref().map(String::as_str)
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+
+    /// Tool that generated this glTF model.
+    pub fn generator(&self) -> Option<&'a str> {
+        self.json.generator.as_ref().map(String::as_str)
+    }
+
+    /// The minimum glTF version that this asset targets.
+    pub fn min_version(&self) -> Option<&'a str> {
+        self.json.min_version.as_ref().map(String::as_str)
+    }
+
+    /// The glTF version of this asset, for example `"2.0"`.
+    pub fn version(&self) -> &'a str {
+        &self.json.version
+    }
+
+    /// Returns `true` if this crate is able to load the asset.
+    ///
+    /// When `minVersion` is provided, the asset is supported if `minVersion`
+    /// is no newer than `SUPPORTED_VERSION`. Otherwise, the major version of
+    /// the asset must match that of `SUPPORTED_VERSION`. Malformed version
+    /// strings are never supported.
+    pub fn is_supported(&self) -> bool {
+        let (major, minor) = SUPPORTED_VERSION;
+        match self.min_version() {
+            Some(min_version) => parse_version(min_version)
+                .map_or(false, |min| min.0 == major && min.1 <= minor),
+            None => parse_version(self.version())
+                .map_or(false, |version| version.0 == major),
+        }
+    }
+}
+
+/// Parses a version string of the form `<major>.<minor>`.
+fn parse_version(version: &str) -> Option<(u32, u32)> {
+    let mut parts = version.splitn(2, '.');
+    let major = parts.next().and_then(|x| x.parse().ok());
+    let minor = parts.next().and_then(|x| x.parse().ok());
+    match (major, minor) {
+        (Some(major), Some(minor)) => Some((major, minor)),
+        _ => None,
+    }
+}
@@ -11,7 +11,8 @@ pub enum Error {
     Version(u32),
     /// Magic says that file is not glTF.
     Magic([u8; 4]),
-    /// Length specified in GLB header exceeeds that of slice.
+    /// Length specified in GLB header is shorter than the header itself or
+    /// exceeeds that of slice.
     Length {
         /// length specified in GLB header.
         length: u32,
@@ -44,6 +45,18 @@ pub struct Glb<'a> {
     pub bin: Option<Cow<'a, [u8]>>,
 }
 
+/// The contents of a .glb file with the BIN section left unread.
+#[derive(Debug)]
+pub struct GlbStream<R> {
+    /// The header section of the `.glb` file.
+    pub header: Header,
+    /// The JSON section of the `.glb` file.
+    pub json: Vec<u8>,
+    /// The optional BIN section of the `.glb` file, bounded to the length of
+    /// the chunk.
+    pub bin: Option<io::Take<R>>,
+}
+
 /// The header section of a .glb file.
 #[derive(Copy, Clone, Debug)]
 #[repr(C)]
@@ -63,6 +76,8 @@ pub enum ChunkType {
     Json,
     /// `BIN` chunk.
     Bin,
+    /// Chunk of unknown type, which is skipped when reading.
+    Unknown([u8; 4]),
 }
 
 /// Chunk header with no data read yet.
@@ -95,6 +110,28 @@ impl Header {
     }
 
     fn size_of() -> usize { 12 }
+
+    /// Returns the length of the contents following the header, as specified
+    /// by the header.
+    fn contents_length(&self) -> Result<usize, Error> {
+        (self.length as usize)
+            .checked_sub(Header::size_of())
+            .ok_or(Error::Length {
+                length: self.length,
+                length_read: Header::size_of(),
+            })
+    }
+}
+
+/// Reads exactly `length` bytes from `reader` without allocating more than
+/// has actually been read, as `length` is untrusted.
+fn read_exact_to_vec<R: io::Read>(reader: R, length: usize) -> Result<Vec<u8>, Error> {
+    let mut data = Vec::new();
+    io::Read::read_to_end(&mut reader.take(length as u64), &mut data).map_err(Error::Io)?;
+    if data.len() < length {
+        return Err(Error::Io(io::ErrorKind::UnexpectedEof.into()));
+    }
+    Ok(data)
 }
 
 impl ChunkHeader {
@@ -110,6 +147,34 @@ impl ChunkHeader {
         }?;
         Ok(Self { length, ty })
     }
+
+    fn size_of() -> usize { 8 }
+}
+
+/// Reads the header of the next chunk, skipping over any chunks of unknown
+/// type.
+///
+/// Returns `Ok(None)` if the end of data is reached.
+fn next_known_chunk_header(data: &mut &[u8]) -> Result<Option<ChunkHeader>, Error> {
+    while data.len() > 0 {
+        let length = (&data[..]).read_u32::<LittleEndian>().map_err(Error::Io)?;
+        match ChunkHeader::from_reader(&mut *data) {
+            Ok(header) => return Ok(Some(header)),
+            Err(Error::UnknownChunkType(ty)) => {
+                // Unknown chunks must be ignored by the client.
+                if length as usize > data.len() {
+                    return Err(Error::ChunkLength {
+                        ty: ChunkType::Unknown(ty),
+                        length,
+                        length_read: data.len(),
+                    });
+                }
+                *data = &data[length as usize..];
+            },
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(None)
 }
 
 fn align_to_multiple_of_four(n: &mut usize) {
@@ -117,6 +182,24 @@ fn align_to_multiple_of_four(n: &mut usize) {
 }
 
 impl<'a> Glb<'a> {
+    /// Returns the parsed GLB header.
+    pub fn header(&self) -> Header {
+        self.header
+    }
+
+    /// Returns the exact bytes of the JSON chunk, excluding the chunk header.
+    ///
+    /// Any padding at the end of the chunk is included.
+    pub fn json(&self) -> &[u8] {
+        &self.json
+    }
+
+    /// Returns the exact bytes of the BIN chunk, excluding the chunk header,
+    /// or `None` if there is no BIN chunk.
+    pub fn bin(&self) -> Option<&[u8]> {
+        self.bin.as_ref().map(|bin| &bin[..])
+    }
+
     /// Writes binary glTF to a writer.
     pub fn to_writer<W>(&self, mut writer: W) -> Result<(), ::Error>
         where W: io::Write
@@ -125,10 +208,10 @@ impl<'a> Glb<'a> {
         {
             let magic = b"glTF";
             let version = 2;
-            let mut length = mem::size_of::<Header>() + mem::size_of::<ChunkHeader>() + self.json.len();
+            let mut length = mem::size_of::<Header>() + ChunkHeader::size_of() + self.json.len();
             align_to_multiple_of_four(&mut length);
             if let Some(bin) = self.bin.as_ref() {
-                length += mem::size_of::<ChunkHeader>() + bin.len();
+                length += ChunkHeader::size_of() + bin.len();
                 align_to_multiple_of_four(&mut length);
             }
 
@@ -171,10 +254,10 @@ impl<'a> Glb<'a> {
 
     /// Writes binary glTF to a byte vector.
     pub fn to_vec(&self) -> Result<Vec<u8>, ::Error> {
-        let mut length = mem::size_of::<Header>() + mem::size_of::<ChunkHeader>() + self.json.len();
+        let mut length = mem::size_of::<Header>() + ChunkHeader::size_of() + self.json.len();
         align_to_multiple_of_four(&mut length);
         if let Some(bin) = self.bin.as_ref() {
-            length += mem::size_of::<ChunkHeader>() + bin.len();
+            length += ChunkHeader::size_of() + bin.len();
             align_to_multiple_of_four(&mut length);
         }
 
@@ -188,12 +271,14 @@ impl<'a> Glb<'a> {
     /// * Mandatory GLB header.
     /// * Mandatory JSON chunk.
     /// * Optional BIN chunk.
+    ///
+    /// Chunks of unknown type are skipped.
     pub fn from_slice(mut data: &'a [u8]) -> Result<Self, ::Error> {
-        let header = Header::from_reader(&mut data)
+        let (header, contents_length) = Header::from_reader(&mut data)
             .and_then(|header| {
-                let contents_length = header.length as usize - Header::size_of();
+                let contents_length = header.contents_length()?;
                 if contents_length <= data.len() {
-                    Ok(header)
+                    Ok((header, contents_length))
                 } else {
                     Err(Error::Length {
                         length: contents_length as u32,
@@ -203,7 +288,7 @@ impl<'a> Glb<'a> {
             })
             .map_err(::Error::Binary)?;
         match header.version {
-            2 => Self::from_v2(data)
+            2 => Self::from_v2(&data[..contents_length])
                 .map(|(json, bin)| Glb { header, json: json.into(), bin: bin.map(Into::into) })
                 .map_err(::Error::Binary),
             x => Err(::Error::Binary(Error::Version(x)))
@@ -217,25 +302,99 @@ impl<'a> Glb<'a> {
         let header = Header::from_reader(&mut reader).map_err(::Error::Binary)?;
         match header.version {
             2 => {
-                let glb_len = header.length - Header::size_of() as u32;
-                let mut buf = vec![0; glb_len as usize];
-                if let Err(e) = reader.read_exact(&mut buf).map_err(Error::Io) {
-                    Err(::Error::Binary(e))
-                } else {
-                    Self::from_v2(&buf)
-                        .map(|(json, bin)| Glb {
-                            header,
-                            json: json.to_vec().into(),
-                            bin: bin.map(<[u8]>::to_vec).map(Into::into),
-                        })
-                        .map_err(::Error::Binary)
-                }
+                let buf = header.contents_length()
+                    .and_then(|glb_len| read_exact_to_vec(&mut reader, glb_len))
+                    .map_err(::Error::Binary)?;
+                Self::from_v2(&buf)
+                    .map(|(json, bin)| Glb {
+                        header,
+                        json: json.to_vec().into(),
+                        bin: bin.map(<[u8]>::to_vec).map(Into::into),
+                    })
+                    .map_err(::Error::Binary)
             }
             x => Err(::Error::Binary(Error::Version(x)))
         }
     }
 
-    fn from_v2(mut data: &'a [u8]) -> Result<(&'a [u8], Option<&'a [u8]>), Error> {
+    /// Reads the GLB header and JSON chunk, leaving the BIN chunk unread.
+    ///
+    /// The BIN chunk, if present, is returned as a reader bounded to the chunk
+    /// length, so that large binary payloads may be streamed instead of being
+    /// loaded into memory. Chunks of unknown type preceding the BIN chunk are
+    /// skipped and any data following the BIN chunk is not inspected.
+    pub fn from_reader_streaming<R: io::Read>(mut reader: R) -> Result<GlbStream<R>, ::Error> {
+        let header = Header::from_reader(&mut reader).map_err(::Error::Binary)?;
+        match header.version {
+            2 => {
+                let (json, bin) = header.contents_length()
+                    .and_then(|glb_len| Self::from_v2_streaming(reader, glb_len))
+                    .map_err(::Error::Binary)?;
+                Ok(GlbStream { header, json, bin })
+            },
+            x => Err(::Error::Binary(Error::Version(x)))
+        }
+    }
+
+    fn from_v2_streaming<R: io::Read>(
+        mut reader: R,
+        mut remaining: usize,
+    ) -> Result<(Vec<u8>, Option<io::Take<R>>), Error> {
+        let json_h = ChunkHeader::from_reader(&mut reader)?;
+        if let ChunkType::Bin = json_h.ty {
+            return Err(Error::ChunkType(json_h.ty));
+        }
+        remaining = remaining.saturating_sub(ChunkHeader::size_of());
+        if json_h.length as usize > remaining {
+            return Err(Error::ChunkLength {
+                ty: json_h.ty,
+                length: json_h.length,
+                length_read: remaining,
+            });
+        }
+        let json = read_exact_to_vec(&mut reader, json_h.length as usize)?;
+        remaining -= json.len();
+
+        while remaining > 0 {
+            let mut chunk_h = [0; 8];
+            reader.read_exact(&mut chunk_h).map_err(Error::Io)?;
+            remaining = remaining.saturating_sub(chunk_h.len());
+            let length = (&chunk_h[..]).read_u32::<LittleEndian>().map_err(Error::Io)?;
+            match ChunkHeader::from_reader(&chunk_h[..]) {
+                Ok(ChunkHeader { ty: ChunkType::Bin, length }) => {
+                    if length as usize > remaining {
+                        return Err(Error::ChunkLength {
+                            ty: ChunkType::Bin,
+                            length,
+                            length_read: remaining,
+                        });
+                    }
+                    return Ok((json, Some(reader.take(length as u64))));
+                },
+                Ok(ChunkHeader { ty, .. }) => return Err(Error::ChunkType(ty)),
+                Err(Error::UnknownChunkType(ty)) => {
+                    // Unknown chunks must be ignored by the client.
+                    if length as usize > remaining {
+                        return Err(Error::ChunkLength {
+                            ty: ChunkType::Unknown(ty),
+                            length,
+                            length_read: remaining,
+                        });
+                    }
+                    let mut chunk = <&mut R as io::Read>::take(&mut reader, length as u64);
+                    let skipped = io::copy(&mut chunk, &mut io::sink()).map_err(Error::Io)?;
+                    if skipped < length as u64 {
+                        return Err(Error::Io(io::ErrorKind::UnexpectedEof.into()));
+                    }
+                    remaining -= length as usize;
+                },
+                Err(err) => return Err(err),
+            }
+        }
+        Ok((json, None))
+    }
+
+    fn from_v2(mut data: &[u8]) -> Result<(&[u8], Option<&[u8]>), Error> {
         let (json, mut data) = ChunkHeader::from_reader(&mut data)
             .and_then(|json_h| if let ChunkType::Json = json_h.ty {
                 Ok(json_h)
@@ -255,29 +414,25 @@ impl<'a> Glb<'a> {
             // data.len().
             .map(|json_h| data.split_at(json_h.length as usize))?;
 
-        let bin = if data.len() > 0 {
-            ChunkHeader::from_reader(&mut data)
-                .and_then(|bin_h| if let ChunkType::Bin = bin_h.ty {
-                    Ok(bin_h)
-                } else {
-                    Err(Error::ChunkType(bin_h.ty))
-                })
-                .and_then(|bin_h| if bin_h.length as usize <= data.len() {
-                    Ok(bin_h)
-                } else {
-                    Err(Error::ChunkLength {
-                        ty: bin_h.ty,
-                        length: bin_h.length,
-                        length_read: data.len(),
-                    })
-                })
-                // We have verified that bin_h.length is no greater than that
-                // of data.len().
-                .map(|bin_h| data.split_at(bin_h.length as usize))
-                .map(|(x, _)| Some(x))?
-        } else {
-            None
-        };
+        let mut bin = None;
+        while let Some(chunk_h) = next_known_chunk_header(&mut data)? {
+            match chunk_h.ty {
+                ChunkType::Bin if bin.is_none() => {},
+                ty => return Err(Error::ChunkType(ty)),
+            }
+            if chunk_h.length as usize > data.len() {
+                return Err(Error::ChunkLength {
+                    ty: chunk_h.ty,
+                    length: chunk_h.length,
+                    length_read: data.len(),
+                });
+            }
+            // We have verified that chunk_h.length is no greater than that
+            // of data.len().
+            let (contents, rest) = data.split_at(chunk_h.length as usize);
+            bin = Some(contents);
+            data = rest;
+        }
         Ok((json, bin))
     }
 }
@@ -299,10 +454,12 @@ impl ::std::error::Error for Error {
              Error::ChunkLength { ty, .. } => match ty {
                  ChunkType::Json => "JSON chunk length exceeds that of slice",
                  ChunkType::Bin => "BIN\\0 chunk length exceeds that of slice",
+                 ChunkType::Unknown(_) => "unknown chunk length exceeds that of slice",
              },
              Error::ChunkType(ty) => match ty {
                  ChunkType::Json => "was not expecting JSON chunk",
                  ChunkType::Bin => "was not expecting BIN\\0 chunk",
+                 ChunkType::Unknown(_) => "was not expecting unknown chunk",
              },
              Error::UnknownChunkType(_) => "unknown chunk type",
         }
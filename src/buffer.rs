@@ -5,6 +5,9 @@ use std::ops;
 
 use {Document};
 
+#[cfg(feature = "import")]
+use {Error, Result};
+
 pub use json::buffer::Target;
 
 /// A buffer points to binary data representing geometry, animations, or skins.
@@ -42,7 +45,8 @@ pub enum Source<'a> {
     /// Buffer data is contained in the `BIN` section of binary glTF.
     Bin,
 
-    /// Buffer data is contained in an external data source.
+    /// Buffer data is contained in an external data source, which may be a
+    /// base 64 encoded data URI.
     Uri(&'a str),
 }
 
@@ -152,6 +156,22 @@ impl<'a> View<'a> {
         self.json.name.as_ref().map(String::as_str)
     }
 
+    /// Returns the bytes of this view from the imported buffer data, where
+    /// `data` is indexed by buffer index as returned by `gltf::import`.
+    ///
+    /// Returns `Error::MissingBufferData` if `data` does not contain the
+    /// parent buffer, or `Error::BufferViewBounds` if the view extends
+    /// beyond the end of the buffer data.
+    #[cfg(feature = "import")]
+    pub fn slice<'s>(&self, data: &'s [Data]) -> Result<&'s [u8]> {
+        let buffer = self.json.buffer.value();
+        let data = data.get(buffer).ok_or(Error::MissingBufferData { buffer })?;
+        let start = self.offset();
+        start.checked_add(self.length())
+            .and_then(|end| data.get(start .. end))
+            .ok_or(Error::BufferViewBounds { view: self.index })
+    }
+
     /// Optional target the buffer should be bound to.
     pub fn target(&self) -> Option<Target> {
         self.json.target.map(|target| target.unwrap())
@@ -0,0 +1,252 @@
+use json;
+use std::collections::HashMap;
+
+use json::accessor::{ComponentType, GenericComponentType, Type};
+use json::buffer::Target;
+use json::mesh::{Mode, Semantic};
+use json::validation::Checked;
+use json::Index;
+use {Document, Result};
+
+/// Assembles a glTF document programmatically.
+///
+/// Each `add_*` method appends an object to the document and returns its
+/// index, which may then be passed to later calls to refer to the object.
+///
+/// # Examples
+///
+/// ```rust
+/// use gltf::build::{DocumentBuilder, Primitive};
+/// use gltf::json::accessor::{ComponentType, Type};
+/// use gltf::json::mesh::Semantic;
+///
+/// let mut builder = DocumentBuilder::new();
+/// let buffer = builder.add_buffer(36, None);
+/// let view = builder.add_view(buffer, 0, 36, None, None);
+/// let positions = builder.add_accessor(view, 0, 3, ComponentType::F32, Type::Vec3);
+/// builder.set_bounds(positions, &[0.0, 0.0, 0.0], &[1.0, 1.0, 0.0]);
+/// let mesh = builder.add_mesh(vec![
+///     Primitive::new().attribute(Semantic::Positions, positions),
+/// ]);
+/// let node = builder.add_node(Some(mesh), &[]);
+/// let scene = builder.add_scene(&[node]);
+/// builder.set_default_scene(scene);
+/// let document = builder.build().unwrap();
+/// assert_eq!(document.meshes().len(), 1);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct DocumentBuilder {
+    /// The JSON being assembled.
+    root: json::Root,
+}
+
+/// A mesh primitive to be added with `DocumentBuilder::add_mesh`.
+#[derive(Clone, Debug)]
+pub struct Primitive {
+    /// The primitive being assembled.
+    json: json::mesh::Primitive,
+}
+
+impl DocumentBuilder {
+    /// Constructs an empty builder.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Appends a buffer of `byte_length` bytes.
+    ///
+    /// When `uri` is `None`, the buffer refers to the binary chunk of a
+    /// binary glTF.
+    pub fn add_buffer(&mut self, byte_length: u32, uri: Option<&str>) -> Index<json::Buffer> {
+        let index = Index::new(self.root.buffers.len() as u32);
+        self.root.buffers.push(json::Buffer {
+            byte_length,
+            #[cfg(feature = "names")]
+            name: None,
+            uri: uri.map(String::from),
+            extensions: Default::default(),
+            extras: Default::default(),
+        });
+        index
+    }
+
+    /// Appends a view into `buffer`.
+    pub fn add_view(
+        &mut self,
+        buffer: Index<json::Buffer>,
+        byte_offset: u32,
+        byte_length: u32,
+        byte_stride: Option<u32>,
+        target: Option<Target>,
+    ) -> Index<json::buffer::View> {
+        let index = Index::new(self.root.buffer_views.len() as u32);
+        self.root.buffer_views.push(json::buffer::View {
+            buffer,
+            byte_length,
+            byte_offset,
+            byte_stride: byte_stride.map(json::buffer::ByteStride),
+            #[cfg(feature = "names")]
+            name: None,
+            target: target.map(Checked::Valid),
+            extensions: Default::default(),
+            extras: Default::default(),
+        });
+        index
+    }
+
+    /// Appends an accessor of `count` elements into `view`.
+    pub fn add_accessor(
+        &mut self,
+        view: Index<json::buffer::View>,
+        byte_offset: u32,
+        count: u32,
+        component_type: ComponentType,
+        type_: Type,
+    ) -> Index<json::Accessor> {
+        let index = Index::new(self.root.accessors.len() as u32);
+        self.root.accessors.push(json::Accessor {
+            buffer_view: Some(view),
+            byte_offset,
+            count,
+            component_type: Checked::Valid(GenericComponentType(component_type)),
+            extensions: Default::default(),
+            extras: Default::default(),
+            type_: Checked::Valid(type_),
+            min: None,
+            max: None,
+            #[cfg(feature = "names")]
+            name: None,
+            normalized: false,
+            sparse: None,
+        });
+        index
+    }
+
+    /// Sets the minimum and maximum value of each component of an accessor.
+    ///
+    /// Bounds are required for accessors of the `POSITION` attribute.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `accessor` was not returned by this builder.
+    pub fn set_bounds(&mut self, accessor: Index<json::Accessor>, min: &[f32], max: &[f32]) {
+        let accessor = &mut self.root.accessors[accessor.value()];
+        accessor.min = Some(json::Value::from(min.to_vec()));
+        accessor.max = Some(json::Value::from(max.to_vec()));
+    }
+
+    /// Appends a mesh composed of the given primitives.
+    pub fn add_mesh(&mut self, primitives: Vec<Primitive>) -> Index<json::Mesh> {
+        let index = Index::new(self.root.meshes.len() as u32);
+        self.root.meshes.push(json::Mesh {
+            extensions: Default::default(),
+            extras: Default::default(),
+            #[cfg(feature = "names")]
+            name: None,
+            primitives: primitives.into_iter().map(|primitive| primitive.json).collect(),
+            weights: None,
+        });
+        index
+    }
+
+    /// Appends a node with an identity transform.
+    pub fn add_node(
+        &mut self,
+        mesh: Option<Index<json::Mesh>>,
+        children: &[Index<json::Node>],
+    ) -> Index<json::Node> {
+        let index = Index::new(self.root.nodes.len() as u32);
+        self.root.nodes.push(json::Node {
+            camera: None,
+            children: if children.is_empty() { None } else { Some(children.to_vec()) },
+            extensions: Default::default(),
+            extras: Default::default(),
+            matrix: None,
+            mesh,
+            #[cfg(feature = "names")]
+            name: None,
+            rotation: Default::default(),
+            scale: [1.0, 1.0, 1.0],
+            translation: [0.0, 0.0, 0.0],
+            skin: None,
+            weights: None,
+        });
+        index
+    }
+
+    /// Appends a scene with the given root nodes.
+    pub fn add_scene(&mut self, nodes: &[Index<json::Node>]) -> Index<json::Scene> {
+        let index = Index::new(self.root.scenes.len() as u32);
+        self.root.scenes.push(json::Scene {
+            extensions: Default::default(),
+            extras: Default::default(),
+            #[cfg(feature = "names")]
+            name: None,
+            nodes: nodes.to_vec(),
+        });
+        index
+    }
+
+    /// Sets the scene to be displayed when the asset is loaded.
+    pub fn set_default_scene(&mut self, scene: Index<json::Scene>) {
+        self.root.scene = Some(scene);
+    }
+
+    /// Provides mutable access to the JSON being assembled, for setting any
+    /// property not covered by the builder methods.
+    pub fn json_mut(&mut self) -> &mut json::Root {
+        &mut self.root
+    }
+
+    /// Validates the assembled JSON and returns the resulting `Document`.
+    pub fn build(self) -> Result<Document> {
+        Document::from_json(self.root)
+    }
+}
+
+impl Primitive {
+    /// Constructs a primitive of triangles with no attributes.
+    pub fn new() -> Self {
+        Primitive {
+            json: json::mesh::Primitive {
+                attributes: HashMap::new(),
+                extensions: Default::default(),
+                extras: Default::default(),
+                indices: None,
+                material: None,
+                mode: Default::default(),
+                targets: None,
+            },
+        }
+    }
+
+    /// Sets the accessor containing the values of a vertex attribute.
+    pub fn attribute(mut self, semantic: Semantic, accessor: Index<json::Accessor>) -> Self {
+        self.json.attributes.insert(Checked::Valid(semantic), accessor);
+        self
+    }
+
+    /// Sets the accessor containing the vertex indices.
+    pub fn indices(mut self, accessor: Index<json::Accessor>) -> Self {
+        self.json.indices = Some(accessor);
+        self
+    }
+
+    /// Sets the material to apply to the primitive when rendering.
+    pub fn material(mut self, material: Index<json::Material>) -> Self {
+        self.json.material = Some(material);
+        self
+    }
+
+    /// Sets the type of primitives to render.
+    pub fn mode(mut self, mode: Mode) -> Self {
+        self.json.mode = Checked::Valid(mode);
+        self
+    }
+}
+
+impl Default for Primitive {
+    fn default() -> Self {
+        Primitive::new()
+    }
+}
@@ -1,4 +1,8 @@
+use cgmath;
+use cgmath::prelude::*;
 use {json, Document};
+#[cfg(feature = "extensions")]
+use extension_value;
 
 /// A camera's projection.
 #[derive(Clone, Debug)]
@@ -79,10 +83,63 @@ impl<'a> Camera<'a> {
         }
     } 
 
+    /// Returns the world space corners of the view frustum of the camera.
+    ///
+    /// `view_matrix` transforms world space to camera space, and is typically
+    /// the inverse of the global transform of the camera node. The camera
+    /// looks down `-Z` with `+Y` up. The corners of the near plane are
+    /// returned first, then those of the far plane, each in the order
+    /// bottom left, bottom right, top right, and top left.
+    ///
+    /// `aspect_ratio` is used for perspective cameras without an aspect ratio,
+    /// and is usually that of the viewport. `max_zfar` is used as the far
+    /// distance of perspective cameras with an infinite far plane.
+    ///
+    /// Returns `None` if `view_matrix` is not invertible.
+    pub fn frustum_corners(
+        &self,
+        view_matrix: [[f32; 4]; 4],
+        aspect_ratio: f32,
+        max_zfar: f32,
+    ) -> Option<[[f32; 3]; 8]> {
+        // The half extents of the near and far planes, and their distances.
+        let (near, far) = match self.projection() {
+            Projection::Orthographic(ortho) => {
+                let extent = [ortho.xmag(), ortho.ymag()];
+                ((extent, ortho.znear()), (extent, ortho.zfar()))
+            },
+            Projection::Perspective(persp) => {
+                let aspect_ratio = persp.aspect_ratio().unwrap_or(aspect_ratio);
+                let tan = (0.5 * persp.yfov()).tan();
+                let extent = |distance: f32| [aspect_ratio * tan * distance, tan * distance];
+                let zfar = persp.zfar().unwrap_or(max_zfar);
+                ((extent(persp.znear()), persp.znear()), (extent(zfar), zfar))
+            },
+        };
+        let camera_to_world = cgmath::Matrix4::from(view_matrix).invert()?;
+        let mut corners = [[0.0; 3]; 8];
+        for (i, corner) in corners.iter_mut().enumerate() {
+            let ([x, y], distance) = if i < 4 { near } else { far };
+            let (sx, sy) = [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)][i % 4];
+            let point = cgmath::Point3::new(sx * x, sy * y, -distance);
+            *corner = camera_to_world.transform_point(point).into();
+        }
+        Some(corners)
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &json::Extras {
         &self.json.extras
     }
+
+    /// Queries the JSON of an extension by name.
+    ///
+    /// Extensions that are parsed into typed fields are serialized back to
+    /// JSON. Returns `None` if the extension is not present.
+    #[cfg(feature = "extensions")]
+    pub fn extension_value(&self, name: &str) -> Option<json::Value> {
+        extension_value(&self.json.extensions, name)
+    }
 }
 
 impl<'a> Orthographic<'a> {
@@ -0,0 +1,347 @@
+use json;
+use json::serialize::to_value;
+use json::Value;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hasher;
+
+use Document;
+
+/// Transform components are rounded to this many decimal places, so that a
+/// matrix and its decomposition compare equal.
+const TRANSFORM_PRECISION: f32 = 1e5;
+
+/// Returns whether two documents have equal resolved content.
+///
+/// `buffers` provides the buffer data of each document, indexed by buffer
+/// index, in which case the data of accessors and images is compared too.
+pub(crate) fn semantically_eq(
+    a: &Document,
+    b: &Document,
+    buffers: Option<(&[&[u8]], &[&[u8]])>,
+) -> bool {
+    let (a_buffers, b_buffers) = match buffers {
+        Some((a, b)) => (Some(a), Some(b)),
+        None => (None, None),
+    };
+    Resolver::new(a, a_buffers).resolve() == Resolver::new(b, b_buffers).resolve()
+}
+
+/// Converts a document into a tree in which every index is replaced by the
+/// content of the object it refers to.
+struct Resolver<'a> {
+    /// The document being resolved.
+    document: &'a Document,
+
+    /// The JSON of `document`.
+    root: &'a json::Root,
+
+    /// The JSON of `document` as a `Value`.
+    value: Value,
+
+    /// The buffer data of the document, if provided.
+    buffers: Option<&'a [&'a [u8]]>,
+}
+
+/// Returns the index stored in a property, if any.
+fn property_index(object: &Value, key: &str) -> Option<usize> {
+    object.get(key).and_then(Value::as_u64).map(|index| index as usize)
+}
+
+/// Removes properties from a JSON object.
+fn remove(object: &mut Value, keys: &[&str]) {
+    if let Value::Object(ref mut map) = *object {
+        for key in keys {
+            map.remove(*key);
+        }
+    }
+}
+
+/// Sorts values whose order is not meaningful.
+fn sorted(mut values: Vec<Value>) -> Value {
+    values.sort_by_key(|value| value.to_string());
+    Value::Array(values)
+}
+
+impl<'a> Resolver<'a> {
+    fn new(document: &'a Document, buffers: Option<&'a [&'a [u8]]>) -> Self {
+        Resolver {
+            document,
+            root: &document.0,
+            value: to_value(&document.0).unwrap_or(Value::Null),
+            buffers,
+        }
+    }
+
+    /// Returns a copy of the JSON of an object in a top-level array.
+    fn object(&self, array: &str, index: usize) -> Value {
+        self.value[array][index].clone()
+    }
+
+    fn resolve(&self) -> Value {
+        let root = self.root;
+        let mut is_child = vec![false; root.nodes.len()];
+        for node in &root.nodes {
+            for child in node.children.iter().flat_map(|children| children.iter()) {
+                if let Some(is_child) = is_child.get_mut(child.value()) {
+                    *is_child = true;
+                }
+            }
+        }
+        let mut extensions_used = root.extensions_used.clone();
+        extensions_used.sort();
+        let mut extensions_required = root.extensions_required.clone();
+        extensions_required.sort();
+
+        let mut value = Value::Null;
+        value["extensionsUsed"] = Value::from(extensions_used);
+        value["extensionsRequired"] = Value::from(extensions_required);
+        value["scene"] = root.scene.map_or(Value::Null, |scene| self.scene(scene.value()));
+        value["scenes"] = sorted((0 .. root.scenes.len()).map(|i| self.scene(i)).collect());
+        value["nodes"] = sorted(
+            (0 .. root.nodes.len())
+                .filter(|&i| !is_child[i])
+                .map(|i| self.node(i))
+                .collect(),
+        );
+        value["animations"] = sorted((0 .. root.animations.len()).map(|i| self.animation(i)).collect());
+        value
+    }
+
+    /// Hashes byte ranges of a buffer, or returns `Null` if the buffer data
+    /// is not provided or too short.
+    fn hash<I>(&self, buffer: usize, ranges: I) -> Value
+    where
+        I: IntoIterator<Item = (usize, usize)>,
+    {
+        let data = match self.buffers.and_then(|buffers| buffers.get(buffer)) {
+            Some(data) => data,
+            None => return Value::Null,
+        };
+        let mut hasher = DefaultHasher::new();
+        for (start, length) in ranges {
+            match data.get(start .. start + length) {
+                Some(bytes) => hasher.write(bytes),
+                None => return Value::Null,
+            }
+        }
+        Value::from(hasher.finish())
+    }
+
+    /// Hashes `count` elements of `size` bytes from a buffer view.
+    fn view_hash(&self, view: usize, offset: usize, count: usize, size: usize) -> Value {
+        let view = &self.root.buffer_views[view];
+        let stride = view.byte_stride.map_or(size, |stride| stride.0 as usize);
+        let start = view.byte_offset as usize + offset;
+        let ranges = (0 .. count).map(|i| (start + i * stride, size));
+        self.hash(view.buffer.value(), ranges)
+    }
+
+    fn accessor(&self, index: usize) -> Value {
+        let json = &self.root.accessors[index];
+        let mut value = self.object("accessors", index);
+        remove(&mut value, &["bufferView", "byteOffset"]);
+        if let Some(ref sparse) = json.sparse {
+            remove(&mut value["sparse"]["indices"], &["bufferView", "byteOffset"]);
+            remove(&mut value["sparse"]["values"], &["bufferView", "byteOffset"]);
+            if self.buffers.is_some() {
+                let count = sparse.count as usize;
+                let index_size = sparse.indices.component_type.as_ref().unwrap().0.size();
+                value["sparse"]["indices"]["data"] = self.view_hash(
+                    sparse.indices.buffer_view.value(),
+                    sparse.indices.byte_offset as usize,
+                    count,
+                    index_size,
+                );
+                value["sparse"]["values"]["data"] = self.view_hash(
+                    sparse.values.buffer_view.value(),
+                    sparse.values.byte_offset as usize,
+                    count,
+                    self.document.accessors().nth(index).unwrap().size(),
+                );
+            }
+        }
+        if let (Some(view), true) = (json.buffer_view, self.buffers.is_some()) {
+            let accessor = self.document.accessors().nth(index).unwrap();
+            value["data"] = self.view_hash(
+                view.value(),
+                accessor.offset(),
+                accessor.count(),
+                accessor.size(),
+            );
+        }
+        value
+    }
+
+    /// Replaces every accessor index in an attribute map with the accessor.
+    fn attributes(&self, attributes: &mut Value) {
+        if let Value::Object(ref mut map) = *attributes {
+            for value in map.values_mut() {
+                if let Some(index) = value.as_u64() {
+                    *value = self.accessor(index as usize);
+                }
+            }
+        }
+    }
+
+    fn animation(&self, index: usize) -> Value {
+        let json = &self.root.animations[index];
+        let mut value = self.object("animations", index);
+        let samplers = value["samplers"].take();
+        remove(&mut value, &["samplers"]);
+        let channels = json.channels
+            .iter()
+            .zip(value["channels"].as_array().cloned().unwrap_or_default())
+            .map(|(channel, mut channel_value)| {
+                let sampler_index = channel.sampler.value();
+                let sampler = &json.samplers[sampler_index];
+                let mut sampler_value = samplers[sampler_index].clone();
+                sampler_value["input"] = self.accessor(sampler.input.value());
+                sampler_value["output"] = self.accessor(sampler.output.value());
+                channel_value["sampler"] = sampler_value;
+                channel_value["target"]["node"] = self.joint(channel.target.node.value());
+                channel_value
+            })
+            .collect();
+        value["channels"] = sorted(channels);
+        value
+    }
+
+    fn image(&self, index: usize) -> Value {
+        let mut value = self.object("images", index);
+        if let Some(view) = self.root.images[index].buffer_view {
+            remove(&mut value, &["bufferView"]);
+            if self.buffers.is_some() {
+                let view = &self.root.buffer_views[view.value()];
+                value["data"] = self.hash(
+                    view.buffer.value(),
+                    Some((view.byte_offset as usize, view.byte_length as usize)),
+                );
+            }
+        }
+        value
+    }
+
+    /// Resolves a node referred to by a skin or animation, without its
+    /// contents or descendants.
+    fn joint(&self, index: usize) -> Value {
+        let mut value = Value::Null;
+        value["name"] = self.value["nodes"][index]["name"].clone();
+        value["transform"] = self.transform(index);
+        value
+    }
+
+    fn material(&self, index: usize) -> Value {
+        let mut value = self.object("materials", index);
+        self.textures(&mut value);
+        value
+    }
+
+    fn mesh(&self, index: usize) -> Value {
+        let mut value = self.object("meshes", index);
+        if let Some(primitives) = value["primitives"].as_array_mut() {
+            for primitive in primitives.iter_mut() {
+                self.attributes(&mut primitive["attributes"]);
+                if let Some(targets) = primitive["targets"].as_array_mut() {
+                    for target in targets.iter_mut() {
+                        self.attributes(target);
+                    }
+                }
+                if let Some(indices) = property_index(primitive, "indices") {
+                    primitive["indices"] = self.accessor(indices);
+                }
+                if let Some(material) = property_index(primitive, "material") {
+                    primitive["material"] = self.material(material);
+                }
+            }
+        }
+        value
+    }
+
+    fn node(&self, index: usize) -> Value {
+        let mut value = self.object("nodes", index);
+        remove(&mut value, &["children", "matrix", "rotation", "scale", "translation"]);
+        value["transform"] = self.transform(index);
+        if let Some(camera) = property_index(&value, "camera") {
+            value["camera"] = self.object("cameras", camera);
+        }
+        if let Some(mesh) = property_index(&value, "mesh") {
+            value["mesh"] = self.mesh(mesh);
+        }
+        if let Some(skin) = property_index(&value, "skin") {
+            value["skin"] = self.skin(skin);
+        }
+        let node = self.document.nodes().nth(index).unwrap();
+        value["children"] = sorted(node.children().map(|child| self.node(child.index())).collect());
+        value
+    }
+
+    fn scene(&self, index: usize) -> Value {
+        let json = &self.root.scenes[index];
+        let mut value = self.object("scenes", index);
+        value["nodes"] = sorted(json.nodes.iter().map(|node| self.node(node.value())).collect());
+        value
+    }
+
+    fn skin(&self, index: usize) -> Value {
+        let json = &self.root.skins[index];
+        let mut value = self.object("skins", index);
+        value["joints"] = Value::Array(json.joints.iter().map(|joint| self.joint(joint.value())).collect());
+        if let Some(skeleton) = json.skeleton {
+            value["skeleton"] = self.joint(skeleton.value());
+        }
+        if let Some(matrices) = json.inverse_bind_matrices {
+            value["inverseBindMatrices"] = self.accessor(matrices.value());
+        }
+        value
+    }
+
+    fn texture(&self, index: usize) -> Value {
+        let json = &self.root.textures[index];
+        let mut value = self.object("textures", index);
+        value["sampler"] = json.sampler.map_or(Value::Null, |sampler| self.object("samplers", sampler.value()));
+        if let Some(source) = json.source {
+            value["source"] = self.image(source.value());
+        }
+        #[cfg(feature = "KHR_texture_basisu")]
+        {
+            if let Some(ref basisu) = json.extensions.texture_basisu {
+                value["extensions"]["KHR_texture_basisu"]["source"] = self.image(basisu.source.value());
+            }
+        }
+        value
+    }
+
+    /// Replaces the texture index of every texture reference, that is every
+    /// object whose property name ends in `Texture`, with the texture.
+    fn textures(&self, value: &mut Value) {
+        match *value {
+            Value::Object(ref mut map) => {
+                for (key, value) in map.iter_mut() {
+                    if !key.ends_with("Texture") {
+                        self.textures(value);
+                    } else if let Some(texture) = property_index(value, "index") {
+                        value["index"] = self.texture(texture);
+                    }
+                }
+            },
+            Value::Array(ref mut values) => {
+                for value in values.iter_mut() {
+                    self.textures(value);
+                }
+            },
+            _ => {},
+        }
+    }
+
+    /// Returns the local transform of a node as a rounded column-major matrix.
+    fn transform(&self, index: usize) -> Value {
+        let node = self.document.nodes().nth(index).unwrap();
+        let values: Vec<f32> = node.transform()
+            .matrix()
+            .iter()
+            .flat_map(|column| column.iter())
+            .map(|&x| (x * TRANSFORM_PRECISION).round() / TRANSFORM_PRECISION + 0.0)
+            .collect();
+        Value::from(values)
+    }
+}
@@ -1,8 +1,12 @@
 use {buffer, json};
 use Document;
 
+#[cfg(feature = "import")]
+use byteorder::{ByteOrder, NativeEndian};
 #[cfg(feature = "import")]
 use image_crate::DynamicImage;
+#[cfg(all(feature = "import", feature = "KHR_texture_basisu"))]
+use {Error, Result};
 
 /// Format of image pixel data.
 #[cfg(feature = "import")]
@@ -19,6 +23,103 @@ pub enum Format {
 
     /// Red, green, blue, alpha.
     R8G8B8A8,
+
+    /// Red only, 16 bits per channel.
+    R16,
+
+    /// Red, green, 16 bits per channel.
+    R16G16,
+
+    /// Red, green, blue, 16 bits per channel.
+    R16G16B16,
+
+    /// Red, green, blue, alpha, 16 bits per channel.
+    R16G16B16A16,
+
+    /// Block-compressed data that must be transcoded before use.
+    ///
+    /// The pixel data is the complete KTX2 container, to be passed to a
+    /// Basis Universal transcoder, which can produce a format supported by
+    /// the GPU. The container is not transcoded by this crate.
+    ///
+    /// Only produced when the `KHR_texture_basisu` feature is enabled.
+    Compressed(CompressedFormat),
+}
+
+/// Supercompressed texture formats of KTX2 images.
+#[cfg(feature = "import")]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum CompressedFormat {
+    /// ETC1S data with BasisLZ supercompression.
+    Etc1s,
+
+    /// UASTC data, optionally with Zstandard supercompression.
+    Uastc,
+}
+
+#[cfg(feature = "import")]
+impl Format {
+    /// Returns the number of channels per pixel.
+    ///
+    /// Compressed formats have four channels once transcoded.
+    pub fn channels(self) -> u8 {
+        match self {
+            Format::R8 | Format::R16 => 1,
+            Format::R8G8 | Format::R16G16 => 2,
+            Format::R8G8B8 | Format::R16G16B16 => 3,
+            Format::R8G8B8A8 | Format::R16G16B16A16 => 4,
+            Format::Compressed(_) => 4,
+        }
+    }
+
+    /// Returns the number of bits per channel.
+    ///
+    /// Returns `0` for compressed formats, whose channel depth is only known
+    /// once transcoded.
+    pub fn bits_per_channel(self) -> u8 {
+        match self {
+            Format::R8 | Format::R8G8 | Format::R8G8B8 | Format::R8G8B8A8 => 8,
+            Format::R16 | Format::R16G16 | Format::R16G16B16 | Format::R16G16B16A16 => 16,
+            Format::Compressed(_) => 0,
+        }
+    }
+
+    /// Returns the number of bytes per pixel.
+    ///
+    /// Returns `0` for compressed formats, which have no fixed pixel size.
+    pub fn bytes_per_pixel(self) -> usize {
+        self.channels() as usize * self.bits_per_channel() as usize / 8
+    }
+
+    /// Returns `true` if the pixel data must be transcoded before use.
+    pub fn is_compressed(self) -> bool {
+        match self {
+            Format::Compressed(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the last channel is alpha.
+    ///
+    /// Always `false` for compressed formats, whose channels are only known
+    /// once transcoded.
+    pub fn has_alpha(self) -> bool {
+        match self {
+            Format::R8G8B8A8 | Format::R16G16B16A16 => true,
+            _ => false,
+        }
+    }
+}
+
+/// The color space of the color channels of an image, as determined by how
+/// materials use it.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ColorSpace {
+    /// Linear data, such as normal, metallic-roughness, and occlusion maps.
+    Linear,
+
+    /// sRGB encoded color, such as base color and emissive textures.
+    Srgb,
 }
 
 /// Describes an image data source.
@@ -38,7 +139,8 @@ pub enum Source<'a> {
         /// The URI of the external data source.
         uri: &'a str,
 
-        /// The image data MIME type, if provided.
+        /// The image data MIME type, if provided either by the image or by
+        /// the media type of a data URI.
         mime_type: Option<&'a str>,
     },
 }
@@ -60,16 +162,20 @@ pub struct Image<'a> {
 #[cfg(feature = "import")]
 #[derive(Clone, Debug)]
 pub struct Data {
-    /// The image pixel data (8 bits per channel).
+    /// The image pixel data, tightly packed in row-major order.
+    ///
+    /// Contains `width * height * format.bytes_per_pixel()` bytes. Channels
+    /// of 16-bit formats are stored in native byte order. For compressed
+    /// formats this is the complete KTX2 container instead.
     pub pixels: Vec<u8>,
 
     /// The image pixel data format.
     pub format: Format,
 
-    /// The image height in pixels.
+    /// The image width in pixels.
     pub width: u32,
 
-    /// The image width in pixels.
+    /// The image height in pixels.
     pub height: u32,
 }
 
@@ -112,7 +218,10 @@ impl<'a> Image<'a> {
             Source::View { view, mime_type }
         } else {
             let uri = self.json.uri.as_ref().unwrap();
-            let mime_type = self.json.mime_type.as_ref().map(|x| x.0.as_str());
+            let mime_type = self.json.mime_type
+                .as_ref()
+                .map(|x| x.0.as_str())
+                .or_else(|| data_uri_mime_type(uri));
             Source::Uri { uri, mime_type }
         }
     }
@@ -139,4 +248,272 @@ impl Data {
         let pixels = image.raw_pixels();
         Data { format, width, height, pixels }
     }
+
+    /// Reads a KTX2 container holding Basis Universal data, which is kept as
+    /// is with a compressed format.
+    ///
+    /// Returns `Error::UnsupportedImageEncoding` if the data is not a KTX2
+    /// container or holds neither ETC1S nor UASTC data.
+    #[cfg(feature = "KHR_texture_basisu")]
+    pub(crate) fn from_ktx2(data: &[u8]) -> Result<Self> {
+        use byteorder::LE;
+
+        // The KTX2 file identifier, `«KTX 20»\r\n\x1A\n`.
+        const IDENTIFIER: [u8; 12] = [0xAB, 0x4B, 0x54, 0x58, 0x20, 0x32, 0x30, 0xBB, 0x0D, 0x0A, 0x1A, 0x0A];
+        // Supercompression scheme of ETC1S data.
+        const BASIS_LZ: u32 = 1;
+        // Data format descriptor color model of UASTC data.
+        const KHR_DF_MODEL_UASTC: u8 = 166;
+
+        if data.len() < 80 || data[.. 12] != IDENTIFIER {
+            return Err(Error::UnsupportedImageEncoding);
+        }
+        let width = LE::read_u32(&data[20 .. 24]);
+        let height = LE::read_u32(&data[24 .. 28]);
+        let supercompression = LE::read_u32(&data[44 .. 48]);
+        let dfd_offset = LE::read_u32(&data[48 .. 52]) as usize;
+        // The color model is the first byte of the third word of the basic
+        // descriptor block, which follows the total size of the descriptor.
+        let color_model = data.get(dfd_offset + 12).cloned();
+        let format = if supercompression == BASIS_LZ {
+            CompressedFormat::Etc1s
+        } else if color_model == Some(KHR_DF_MODEL_UASTC) {
+            CompressedFormat::Uastc
+        } else {
+            return Err(Error::UnsupportedImageEncoding);
+        };
+        Ok(Data { pixels: data.to_vec(), format: Format::Compressed(format), width, height })
+    }
+
+    /// Converts the pixel data from sRGB to linear color space.
+    ///
+    /// The alpha channel, if present, is left unchanged. Compressed pixel data
+    /// is left unchanged too, as it must be converted once transcoded.
+    pub fn srgb_to_linear(&mut self) {
+        self.map_color_channels(srgb_to_linear);
+    }
+
+    /// Converts the pixel data from linear to sRGB color space.
+    ///
+    /// The alpha channel, if present, is left unchanged. Compressed pixel data
+    /// is left unchanged too, as it must be converted once transcoded.
+    pub fn linear_to_srgb(&mut self) {
+        self.map_color_channels(linear_to_srgb);
+    }
+
+    /// Converts the pixel data to `Format::R8G8B8A8`, as commonly required
+    /// for uploading to the GPU.
+    ///
+    /// One and two channel formats are treated as grayscale and grayscale
+    /// with alpha respectively, since those are what they are decoded from,
+    /// so the first channel is replicated to red, green, and blue. Missing
+    /// alpha is filled with `255`. Channels of 16-bit formats are rounded to
+    /// the nearest 8-bit value.
+    ///
+    /// If `srgb_to_linear` is `true`, the color channels are converted from
+    /// sRGB to linear color space, before any reduction in precision. Pass
+    /// `false` to keep sRGB encoded data, such as base color textures, for
+    /// upload to an sRGB texture format, and for non-color data such as
+    /// normal maps.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the format is compressed.
+    pub fn into_rgba8(mut self, srgb_to_linear: bool) -> Self {
+        assert!(!self.format.is_compressed(), "compressed image data must be transcoded");
+        if srgb_to_linear {
+            self.srgb_to_linear();
+        }
+        let channels = self.format.channels() as usize;
+        let values: Vec<u8> = if self.format.bits_per_channel() == 8 {
+            self.pixels
+        } else {
+            self.pixels
+                .chunks(2)
+                .map(|x| ((NativeEndian::read_u16(x) as u32 * 255 + 32767) / 65535) as u8)
+                .collect()
+        };
+        if channels == 4 {
+            return Data { pixels: values, format: Format::R8G8B8A8, ..self };
+        }
+        let mut pixels = Vec::with_capacity(values.len() / channels * 4);
+        for x in values.chunks(channels) {
+            let rgba = match channels {
+                3 => [x[0], x[1], x[2], 255],
+                2 => [x[0], x[0], x[0], x[1]],
+                _ => [x[0], x[0], x[0], 255],
+            };
+            pixels.extend_from_slice(&rgba);
+        }
+        Data { pixels, format: Format::R8G8B8A8, ..self }
+    }
+
+    /// Applies `f` to every channel except alpha, with channel values in the
+    /// range `[0.0, 1.0]`.
+    fn map_color_channels<F: Fn(f32) -> f32>(&mut self, f: F) {
+        if self.format.is_compressed() {
+            return;
+        }
+        let channels = self.format.channels() as usize;
+        let color_channels = if self.format.has_alpha() { channels - 1 } else { channels };
+        if self.format.bits_per_channel() == 8 {
+            let mut table = [0u8; 256];
+            for (x, y) in table.iter_mut().enumerate() {
+                *y = (f(x as f32 / 255.0) * 255.0).round().max(0.0).min(255.0) as u8;
+            }
+            for pixel in self.pixels.chunks_mut(channels) {
+                for x in pixel.iter_mut().take(color_channels) {
+                    *x = table[*x as usize];
+                }
+            }
+        } else {
+            for pixel in self.pixels.chunks_mut(2 * channels) {
+                for x in pixel.chunks_mut(2).take(color_channels) {
+                    let y = f(NativeEndian::read_u16(x) as f32 / 65535.0);
+                    let y = (y * 65535.0).round().max(0.0).min(65535.0) as u16;
+                    NativeEndian::write_u16(x, y);
+                }
+            }
+        }
+    }
+}
+
+/// Returns the media type of a URI of the form
+/// `data:[<media type>][;<parameter>]*,<data>`, if present.
+fn data_uri_mime_type(uri: &str) -> Option<&str> {
+    if !uri.starts_with("data:") {
+        return None;
+    }
+    let header = uri["data:".len()..].split(',').next().unwrap();
+    let media_type = header.split(';').next().unwrap();
+    if media_type.is_empty() {
+        None
+    } else {
+        Some(media_type)
+    }
+}
+
+/// Converts a single sRGB encoded channel value to linear color space.
+#[cfg(any(feature = "import", feature = "utils"))]
+pub(crate) fn srgb_to_linear(x: f32) -> f32 {
+    if x <= 0.04045 {
+        x / 12.92
+    } else {
+        ((x + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Converts a single linear channel value to sRGB encoding.
+#[cfg(feature = "import")]
+fn linear_to_srgb(x: f32) -> f32 {
+    if x <= 0.0031308 {
+        x * 12.92
+    } else {
+        1.055 * x.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+#[cfg(all(test, feature = "import"))]
+mod tests {
+    use image_crate::DynamicImage;
+    use super::{CompressedFormat, Data, Format};
+
+    fn rgba(pixels: Vec<u8>) -> Data {
+        let width = pixels.len() as u32 / 4;
+        Data { pixels, format: Format::R8G8B8A8, width, height: 1 }
+    }
+
+    #[test]
+    fn srgb_to_linear_midtone() {
+        let mut image = rgba(vec![128, 0, 255, 128]);
+        image.srgb_to_linear();
+        // ((128 / 255 + 0.055) / 1.055) ^ 2.4 * 255 = 55.0
+        assert_eq!(image.pixels, [55, 0, 255, 128]);
+    }
+
+    #[test]
+    fn linear_to_srgb_midtone() {
+        let mut image = rgba(vec![55, 0, 255, 55]);
+        image.linear_to_srgb();
+        // (1.055 * (55 / 255) ^ (1 / 2.4) - 0.055) * 255 = 128.0
+        assert_eq!(image.pixels, [128, 0, 255, 55]);
+    }
+
+    #[test]
+    fn format_bytes_per_pixel() {
+        let formats = [
+            (Format::R8, 1, 1),
+            (Format::R8G8, 2, 2),
+            (Format::R8G8B8, 3, 3),
+            (Format::R8G8B8A8, 4, 4),
+            (Format::R16, 1, 2),
+            (Format::R16G16, 2, 4),
+            (Format::R16G16B16, 3, 6),
+            (Format::R16G16B16A16, 4, 8),
+        ];
+        for &(format, channels, bytes_per_pixel) in &formats {
+            assert_eq!(format.channels(), channels);
+            assert_eq!(format.bytes_per_pixel(), bytes_per_pixel);
+        }
+
+        let compressed = Format::Compressed(CompressedFormat::Uastc);
+        assert_eq!(compressed.bits_per_channel(), 0);
+        assert_eq!(compressed.bytes_per_pixel(), 0);
+    }
+
+    #[test]
+    fn decoded_pixels_length() {
+        let images = [
+            DynamicImage::new_luma8(5, 3),
+            DynamicImage::new_luma_a8(5, 3),
+            DynamicImage::new_rgb8(5, 3),
+            DynamicImage::new_rgba8(5, 3),
+        ];
+        for image in images.iter() {
+            let data = Data::new(image.clone());
+            assert_eq!((data.width, data.height), (5, 3));
+            let expected = data.width as usize * data.height as usize * data.format.bytes_per_pixel();
+            assert_eq!(data.pixels.len(), expected);
+        }
+    }
+
+    #[test]
+    fn into_rgba8_fills_missing_channels() {
+        use byteorder::{ByteOrder, NativeEndian};
+
+        let image = |pixels: Vec<u8>, format| Data { pixels, format, width: 1, height: 1 };
+        let expected = [
+            (image(vec![10], Format::R8), [10, 10, 10, 255]),
+            (image(vec![10, 20], Format::R8G8), [10, 10, 10, 20]),
+            (image(vec![10, 20, 30], Format::R8G8B8), [10, 20, 30, 255]),
+            (image(vec![10, 20, 30, 40], Format::R8G8B8A8), [10, 20, 30, 40]),
+        ];
+        for &(ref data, pixels) in &expected {
+            let rgba = data.clone().into_rgba8(false);
+            assert_eq!(rgba.format, Format::R8G8B8A8);
+            assert_eq!(rgba.pixels, pixels);
+        }
+
+        let mut pixels = vec![0; 6];
+        NativeEndian::write_u16_into(&[65535, 32896, 128], &mut pixels);
+        let rgba = image(pixels, Format::R16G16B16).into_rgba8(false);
+        assert_eq!(rgba.pixels, [255, 128, 0, 255]);
+
+        let rgba = image(vec![128, 255], Format::R8G8).into_rgba8(true);
+        assert_eq!(rgba.pixels, [55, 55, 55, 255]);
+    }
+
+    #[test]
+    fn srgb_to_linear_16_bit() {
+        use byteorder::{ByteOrder, NativeEndian};
+
+        let mut pixels = vec![0; 8];
+        NativeEndian::write_u16_into(&[32896, 0, 65535, 32896], &mut pixels);
+        let mut image = Data { pixels, format: Format::R16G16B16A16, width: 1, height: 1 };
+        image.srgb_to_linear();
+        let mut channels = [0; 4];
+        NativeEndian::read_u16_into(&image.pixels, &mut channels);
+        // ((32896 / 65535 + 0.055) / 1.055) ^ 2.4 * 65535 = 14146.4
+        assert_eq!(channels, [14146, 0, 65535, 32896]);
+    }
 }
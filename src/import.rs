@@ -11,11 +11,45 @@ use {Document, Error, Gltf, Result};
 /// Return type of `import`.
 type Import = (Document, Vec<buffer::Data>, Vec<image::Data>);
 
+/// A stage of an import, as reported by `import_with_progress`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ImportProgress {
+    /// The glTF file is being read and parsed.
+    ParsingJson,
+
+    /// The data of a buffer is being loaded.
+    LoadingBuffer {
+        /// The index of the buffer.
+        index: usize,
+
+        /// The number of buffers.
+        total: usize,
+    },
+
+    /// An image is being loaded and decoded.
+    DecodingImage {
+        /// The index of the image.
+        index: usize,
+
+        /// The number of images.
+        total: usize,
+    },
+}
+
 /// Represents the set of URI schemes the importer supports.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 enum Scheme<'a> {
-    /// `data:[<media type>];base64,<data>`.
-    Data(Option<&'a str>, &'a str),
+    /// `data:[<media type>][;<parameter>...][;base64],<data>`.
+    Data {
+        /// The media type without parameters, or `None` if omitted.
+        mime_type: Option<&'a str>,
+
+        /// Whether `data` is base 64 encoded rather than percent-encoded.
+        base64: bool,
+
+        /// The encoded data.
+        data: &'a str,
+    },
 
     /// `file:[//]<absolute file path>`.
     ///
@@ -33,15 +67,7 @@ impl<'a> Scheme<'a> {
     fn parse<'s>(uri: &'s str) -> Scheme<'s> {
         if uri.contains(":") {
             if uri.starts_with("data:") {
-                let match0 = &uri["data:".len()..].split(";base64,").nth(0);
-                let match1 = &uri["data:".len()..].split(";base64,").nth(1);
-                if match1.is_some() {
-                    Scheme::Data(Some(match0.unwrap()), match1.unwrap())
-                } else if match0.is_some() {
-                    Scheme::Data(None, match0.unwrap())
-                } else {
-                    Scheme::Unsupported
-                }
+                Scheme::parse_data(&uri["data:".len()..])
             } else if uri.starts_with("file://") {
                 Scheme::File(&uri["file://".len()..])
             } else if uri.starts_with("file:") {
@@ -54,14 +80,67 @@ impl<'a> Scheme<'a> {
         }
     }
 
+    /// Parses the part of a data URI following `data:`.
+    fn parse_data<'s>(uri: &'s str) -> Scheme<'s> {
+        let comma = match uri.find(',') {
+            Some(comma) => comma,
+            None => return Scheme::Unsupported,
+        };
+        let (header, data) = (&uri[.. comma], &uri[comma + 1 ..]);
+        let mut parameters = header.split(';');
+        let mime_type = parameters
+            .next()
+            .map(str::trim)
+            .filter(|mime_type| !mime_type.is_empty() && !mime_type.contains('='));
+        let base64 = parameters
+            .last()
+            .map_or(false, |parameter| parameter.trim().eq_ignore_ascii_case("base64"));
+        Scheme::Data { mime_type, base64, data }
+    }
+
     fn read(base: &Path, uri: &str) -> Result<Vec<u8>> {
         match Scheme::parse(uri) {
-            Scheme::Data(_, base64) => base64::decode(&base64).map_err(Error::Base64),
+            Scheme::Data { base64, data, .. } => decode_data(base64, data),
             Scheme::File(path) => read_to_end(path),
             Scheme::Relative => read_to_end(base.join(uri)),
-            Scheme::Unsupported => Err(Error::UnsupportedScheme),
+            Scheme::Unsupported => Err(Error::UnsupportedScheme { uri: uri.to_string() }),
+        }
+    }
+}
+
+/// Decodes the data of a data URI.
+///
+/// Base 64 data may itself be percent-encoded, and whitespace within it is
+/// ignored.
+fn decode_data(base64: bool, data: &str) -> Result<Vec<u8>> {
+    let bytes = percent_decode(data);
+    if base64 {
+        let encoded: Vec<u8> = bytes.into_iter().filter(|byte| !byte.is_ascii_whitespace()).collect();
+        base64::decode(&encoded).map_err(Error::Base64)
+    } else {
+        Ok(bytes)
+    }
+}
+
+/// Replaces each `%XX` escape by the byte it represents. Malformed escapes are
+/// kept as is.
+fn percent_decode(data: &str) -> Vec<u8> {
+    let bytes = data.as_bytes();
+    let hex = |byte: u8| (byte as char).to_digit(16).map(|digit| digit as u8);
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            if let (Some(high), Some(low)) = (hex(bytes[i + 1]), hex(bytes[i + 2])) {
+                decoded.push(high << 4 | low);
+                i += 3;
+                continue;
+            }
         }
+        decoded.push(bytes[i]);
+        i += 1;
     }
+    decoded
 }
 
 fn read_to_end<P>(path: P) -> Result<Vec<u8>>
@@ -76,91 +155,166 @@ where P: AsRef<Path>
     Ok(data)
 }
 
-/// Import the buffer data referenced by a glTF document.
-pub fn import_buffer_data(
+fn import_buffer(
+    buffer: buffer::Buffer,
+    base: &Path,
+    blob: Option<Vec<u8>>,
+) -> Result<buffer::Data> {
+    // spec: The `BIN` chunk may be padded by up to three bytes beyond the
+    // length of the buffer.
+    let (data, padding) = match buffer.source() {
+        buffer::Source::Uri(uri) => (Scheme::read(base, uri), 0),
+        buffer::Source::Bin => (blob.ok_or(Error::MissingBlob { buffer: buffer.index() }), 3),
+    };
+    let mut data = data?;
+    if data.len() < buffer.length() || data.len() > buffer.length() + padding {
+        return Err(
+            Error::BufferLength {
+                buffer: buffer.index(),
+                expected: buffer.length(),
+                actual: data.len(),
+            }
+        );
+    }
+    while data.len() % 4 != 0 {
+        data.push(0);
+    }
+    Ok(buffer::Data(data))
+}
+
+fn import_image(
+    image: image::Image,
+    base: &Path,
+    buffer_data: &[buffer::Data],
+) -> Result<image::Data> {
+    match image.source() {
+        image::Source::Uri { uri, mime_type } => {
+            match Scheme::parse(uri) {
+                Scheme::Data { mime_type: Some(annoying_case), base64, data }
+                    if annoying_case.starts_with("image/") =>
+                {
+                    let encoded_image = decode_data(base64, data)?;
+                    return decode_image(&encoded_image, annoying_case);
+                },
+                Scheme::Unsupported => return Err(Error::UnsupportedScheme { uri: uri.to_string() }),
+                _ => {},
+            }
+            let encoded_image = Scheme::read(base, uri)?;
+            let encoded_format = match mime_type {
+                Some(mime_type) => mime_type,
+                None => match uri.rsplit(".").next() {
+                    Some("png") => "image/png",
+                    Some("jpg") | Some("jpeg") => "image/jpeg",
+                    Some("ktx2") => "image/ktx2",
+                    _ => return Err(Error::UnsupportedImageEncoding),
+                },
+            };
+            decode_image(&encoded_image, encoded_format)
+        },
+        image::Source::View { view, mime_type } => {
+            let parent_buffer_data = &buffer_data[view.buffer().index()].0;
+            let begin = view.offset();
+            let end = begin + view.length();
+            let encoded_image = &parent_buffer_data[begin..end];
+            decode_image(encoded_image, mime_type)
+        },
+    }
+}
+
+/// Decodes an image with the given MIME type.
+///
+/// KTX2 images are supported with the `KHR_texture_basisu` feature, and are
+/// kept compressed.
+fn decode_image(encoded_image: &[u8], mime_type: &str) -> Result<image::Data> {
+    let encoded_format = match mime_type {
+        "image/png" => Png,
+        "image/jpeg" => Jpeg,
+        #[cfg(feature = "KHR_texture_basisu")]
+        "image/ktx2" => return image::Data::from_ktx2(encoded_image),
+        _ => return Err(Error::UnsupportedImageEncoding),
+    };
+    let decoded_image = image_crate::load_from_memory_with_format(encoded_image, encoded_format)?;
+    Ok(image::Data::new(decoded_image))
+}
+
+/// Applies `f` to every item, in parallel if the `rayon` feature is enabled.
+///
+/// The output order always matches the input order.
+#[cfg(feature = "rayon")]
+fn map_ordered<T, U, F>(items: Vec<T>, f: F) -> Result<Vec<U>>
+where
+    T: Send,
+    U: Send,
+    F: Fn(T) -> Result<U> + Send + Sync,
+{
+    use rayon::prelude::*;
+    items.into_par_iter().map(f).collect()
+}
+
+/// Applies `f` to every item, in parallel if the `rayon` feature is enabled.
+///
+/// The output order always matches the input order.
+#[cfg(not(feature = "rayon"))]
+fn map_ordered<T, U, F>(items: Vec<T>, f: F) -> Result<Vec<U>>
+where
+    F: Fn(T) -> Result<U>,
+{
+    items.into_iter().map(f).collect()
+}
+
+/// Imports the buffer data referenced by a glTF document.
+///
+/// This is the first stage of `import`, for callers that parse documents and
+/// manage buffer data separately, for example to reuse buffers already in
+/// memory. URIs are resolved relative to `base`, and `blob` provides the `BIN`
+/// chunk of binary glTF. The returned data is indexed by buffer index.
+///
+/// ```
+/// # extern crate gltf;
+/// # fn run() -> Result<(), gltf::Error> {
+/// # use std::path::Path;
+/// let gltf = gltf::Gltf::open("examples/Box.gltf")?;
+/// let buffers = gltf::import_buffers(&gltf, Path::new("examples"), None)?;
+/// let images = gltf::import_images(&gltf, Path::new("examples"), &buffers)?;
+/// # assert_eq!(buffers.len(), 1);
+/// # assert!(images.is_empty());
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// #     run().expect("test failure");
+/// # }
+/// ```
+pub fn import_buffers(
     document: &Document,
     base: &Path,
     mut blob: Option<Vec<u8>>,
 ) -> Result<Vec<buffer::Data>> {
-    let mut buffers = Vec::new();
-    for buffer in document.buffers() {
-        let mut data = match buffer.source() {
-            buffer::Source::Uri(uri) => Scheme::read(base, uri),
-            buffer::Source::Bin => blob.take().ok_or(Error::MissingBlob),
-        }?;
-        if data.len() < buffer.length() {
-            return Err(
-                Error::BufferLength {
-                    buffer: buffer.index(),
-                    expected: buffer.length(),
-                    actual: data.len(),
-                }
-            );
-        }
-        while data.len() % 4 != 0 {
-            data.push(0);
-        }
-        buffers.push(buffer::Data(data));
-    }
-    Ok(buffers)
+    let buffers = document
+        .buffers()
+        .map(|buffer| {
+            let blob = match buffer.source() {
+                buffer::Source::Bin => blob.take(),
+                buffer::Source::Uri(_) => None,
+            };
+            (buffer, blob)
+        })
+        .collect();
+    map_ordered(buffers, |(buffer, blob)| import_buffer(buffer, base, blob))
 }
 
-/// Import the image data referenced by a glTF document.
-pub fn import_image_data(
+/// Imports and decodes the image data referenced by a glTF document.
+///
+/// This is the second stage of `import`. URIs are resolved relative to
+/// `base`, and `buffer_data` must be the buffer data of the document, as
+/// returned by `import_buffers`, from which images stored in buffer views are
+/// read. The returned data is indexed by image index.
+pub fn import_images(
     document: &Document,
     base: &Path,
     buffer_data: &[buffer::Data],
 ) -> Result<Vec<image::Data>> {
-    let mut images = Vec::new();
-    for image in document.images() {
-        match image.source() {
-            image::Source::Uri { uri, mime_type } => {
-                match Scheme::parse(uri) {
-                    Scheme::Data(Some(annoying_case), base64) => {
-                        let format = match annoying_case.as_ref() {
-                            "image/png" => Png,
-                            "image/jpeg" => Jpeg,
-                            _ => return Err(Error::UnsupportedImageEncoding),
-                        };
-                        let encoded_image = base64::decode(&base64).map_err(Error::Base64)?;
-                        let decoded_image = image_crate::load_from_memory_with_format(&encoded_image, format)?;
-                        images.push(image::Data::new(decoded_image));
-                        continue;
-                    },
-                    Scheme::Unsupported => return Err(Error::UnsupportedScheme),
-                    _ => {},
-                }
-                let encoded_image = Scheme::read(base, uri)?;
-                let encoded_format =  match mime_type {
-                    Some("image/png") => Png,
-                    Some("image/jpeg") => Jpeg,
-                    Some(_) => return Err(Error::UnsupportedImageEncoding),
-                    None => match uri.rsplit(".").next() {
-                        Some("png") => Png,
-                        Some("jpg") | Some("jpeg") => Jpeg,
-                        _ => return Err(Error::UnsupportedImageEncoding),
-                    },
-                };
-                let decoded_image = image_crate::load_from_memory_with_format(&encoded_image, encoded_format)?;
-                images.push(image::Data::new(decoded_image));
-            },
-            image::Source::View { view, mime_type } => {
-                let parent_buffer_data = &buffer_data[view.buffer().index()].0;
-                let begin = view.offset();
-                let end = begin + view.length();
-                let encoded_image = &parent_buffer_data[begin..end];
-                let encoded_format = match mime_type {
-                    "image/png" => Png,
-                    "image/jpeg" => Jpeg,
-                    _ => return Err(Error::UnsupportedImageEncoding)
-                };
-                let decoded_image = image_crate::load_from_memory_with_format(encoded_image, encoded_format)?;
-                images.push(image::Data::new(decoded_image));
-            },
-        }
-    }
-
-    Ok(images)
+    let images = document.images().collect();
+    map_ordered(images, |image| import_image(image, base, buffer_data))
 }
 
 fn import_impl(path: &Path) -> Result<Import> {
@@ -168,12 +322,39 @@ fn import_impl(path: &Path) -> Result<Import> {
     let file = fs::File::open(path).map_err(Error::Io)?;
     let reader = io::BufReader::new(file);
     let Gltf { document, blob } = Gltf::from_reader(reader)?;
-    let buffer_data = import_buffer_data(&document, base, blob)?;
-    let image_data = import_image_data(&document, base, &buffer_data)?;
+    let buffer_data = import_buffers(&document, base, blob)?;
+    let image_data = import_images(&document, base, &buffer_data)?;
     let import = (document, buffer_data, image_data);
     Ok(import)
 }
 
+fn import_with_progress_impl(path: &Path, progress: &mut dyn FnMut(ImportProgress)) -> Result<Import> {
+    let base = path.parent().unwrap_or(Path::new("./"));
+    progress(ImportProgress::ParsingJson);
+    let file = fs::File::open(path).map_err(Error::Io)?;
+    let reader = io::BufReader::new(file);
+    let Gltf { document, mut blob } = Gltf::from_reader(reader)?;
+
+    let total = document.buffers().len();
+    let mut buffer_data = Vec::with_capacity(total);
+    for (index, buffer) in document.buffers().enumerate() {
+        progress(ImportProgress::LoadingBuffer { index, total });
+        let blob = match buffer.source() {
+            buffer::Source::Bin => blob.take(),
+            buffer::Source::Uri(_) => None,
+        };
+        buffer_data.push(import_buffer(buffer, base, blob)?);
+    }
+
+    let total = document.images().len();
+    let mut image_data = Vec::with_capacity(total);
+    for (index, image) in document.images().enumerate() {
+        progress(ImportProgress::DecodingImage { index, total });
+        image_data.push(import_image(image, base, &buffer_data)?);
+    }
+    Ok((document, buffer_data, image_data))
+}
+
 /// Import some glTF 2.0 from the file system.
 ///
 /// ```
@@ -193,3 +374,279 @@ pub fn import<P>(path: P) -> Result<Import>
 {
     import_impl(path.as_ref())
 }
+
+/// Like `import`, but calls `progress` as each stage of the import begins,
+/// for example to display a progress bar.
+///
+/// Buffers and images are loaded one at a time in index order, even when the
+/// `rayon` feature is enabled.
+///
+/// ```
+/// # extern crate gltf;
+/// # fn run() -> Result<(), gltf::Error> {
+/// # let path = "examples/Box.gltf";
+/// # #[allow(unused)]
+/// let (document, buffers, images) = gltf::import_with_progress(path, &mut |progress| {
+///     println!("{:?}", progress);
+/// })?;
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// #     run().expect("test failure");
+/// # }
+/// ```
+pub fn import_with_progress<P>(path: P, progress: &mut dyn FnMut(ImportProgress)) -> Result<Import>
+    where P: AsRef<Path>
+{
+    import_with_progress_impl(path.as_ref(), progress)
+}
+
+#[cfg(test)]
+mod tests {
+    use image_crate::{png, ColorType};
+    use std::path::Path;
+    use {Error, Gltf};
+
+    fn encode_png(width: u32, height: u32) -> Vec<u8> {
+        let pixels = vec![255; (width * height * 4) as usize];
+        let mut data = Vec::new();
+        png::PNGEncoder::new(&mut data)
+            .encode(&pixels, width, height, ColorType::RGBA(8))
+            .unwrap();
+        data
+    }
+
+    #[test]
+    fn replace_image() {
+        let json = br#"{
+            "asset": { "version": "2.0" },
+            "images": [ { "uri": "missing.jpg" } ],
+            "textures": [ { "source": 0 } ]
+        }"#;
+        let mut gltf = Gltf::from_slice(json).unwrap();
+        let data = encode_png(3, 5);
+        gltf.document.replace_image(0, &data, "image/png");
+        let texture = gltf.textures().nth(0).unwrap();
+        match texture.source().source() {
+            ::image::Source::Uri { uri, mime_type } => {
+                assert!(uri.starts_with("data:image/png;base64,"));
+                assert_eq!(Some("image/png"), mime_type);
+            },
+            _ => panic!("expected data URI"),
+        }
+
+        let images = super::import_images(&gltf.document, Path::new("."), &[]).unwrap();
+        assert_eq!((3, 5), (images[0].width, images[0].height));
+    }
+
+    /// Encodes the header and basic data format descriptor of a KTX2 image.
+    fn encode_ktx2(supercompression: u32, color_model: u8) -> Vec<u8> {
+        use byteorder::{ByteOrder, LE};
+
+        let mut data = vec![0; 148];
+        data[.. 12].copy_from_slice(&[0xAB, 0x4B, 0x54, 0x58, 0x20, 0x32, 0x30, 0xBB, 0x0D, 0x0A, 0x1A, 0x0A]);
+        // Width, height, face count, level count, and supercompression scheme.
+        LE::write_u32_into(&[4, 2, 0, 0, 1, 1, supercompression], &mut data[20 .. 48]);
+        // Offset and length of the data format descriptor, which follows the
+        // header and the index of the only level.
+        LE::write_u32_into(&[104, 44], &mut data[48 .. 56]);
+        data[116] = color_model;
+        data
+    }
+
+    fn import_ktx2(data: &[u8]) -> ::Result<::image::Data> {
+        let json = br#"{
+            "asset": { "version": "2.0" },
+            "images": [ { "uri": "missing.png" } ]
+        }"#;
+        let mut gltf = Gltf::from_slice(json).unwrap();
+        gltf.document.replace_image(0, data, "image/ktx2");
+        super::import_images(&gltf.document, Path::new("."), &[]).map(|mut images| images.remove(0))
+    }
+
+    #[cfg(feature = "KHR_texture_basisu")]
+    #[test]
+    fn import_ktx2_images() {
+        use image::{CompressedFormat, Format};
+
+        let etc1s = import_ktx2(&encode_ktx2(1, 163)).unwrap();
+        assert_eq!(etc1s.format, Format::Compressed(CompressedFormat::Etc1s));
+        assert_eq!((etc1s.width, etc1s.height), (4, 2));
+        assert_eq!(etc1s.pixels, encode_ktx2(1, 163));
+
+        let uastc = import_ktx2(&encode_ktx2(2, 166)).unwrap();
+        assert_eq!(uastc.format, Format::Compressed(CompressedFormat::Uastc));
+        assert_eq!(uastc.format.bytes_per_pixel(), 0);
+
+        match import_ktx2(&encode_ktx2(0, 1)) {
+            Err(Error::UnsupportedImageEncoding) => {},
+            other => panic!("unexpected result: {:?}", other.map(|data| data.format)),
+        }
+        match import_ktx2(&encode_png(1, 1)) {
+            Err(Error::UnsupportedImageEncoding) => {},
+            other => panic!("unexpected result: {:?}", other.map(|data| data.format)),
+        }
+    }
+
+    #[cfg(not(feature = "KHR_texture_basisu"))]
+    #[test]
+    fn import_ktx2_images_unsupported() {
+        match import_ktx2(&encode_ktx2(1, 163)) {
+            Err(Error::UnsupportedImageEncoding) => {},
+            other => panic!("unexpected result: {:?}", other.map(|data| data.format)),
+        }
+    }
+
+    #[test]
+    fn import_errors_carry_context() {
+        let json = br#"{
+            "asset": { "version": "2.0" },
+            "buffers": [
+                { "byteLength": 4, "uri": "data:application/octet-stream;base64,AAAA" },
+                { "byteLength": 4 },
+                { "byteLength": 4, "uri": "http://example.com/buffer.bin" }
+            ]
+        }"#;
+        let gltf = Gltf::from_slice(json).unwrap();
+        let error = |index: usize| {
+            let buffer = gltf.buffers().nth(index).unwrap();
+            super::import_buffer(buffer, Path::new("."), None).unwrap_err()
+        };
+        match error(1) {
+            Error::MissingBlob { buffer: 1 } => {},
+            other => panic!("unexpected error: {:?}", other),
+        }
+        assert_eq!(error(1).to_string(), "missing BIN section of binary glTF (buffer 1)");
+        match error(2) {
+            Error::UnsupportedScheme { ref uri } if uri == "http://example.com/buffer.bin" => {},
+            other => panic!("unexpected error: {:?}", other),
+        }
+        assert_eq!(
+            error(2).to_string(),
+            "unsupported URI scheme (URI \"http://example.com/buffer.bin\")",
+        );
+    }
+
+    #[test]
+    fn import_buffers_checks_length() {
+        let json = br#"{
+            "asset": { "version": "2.0" },
+            "buffers": [
+                { "byteLength": 3, "uri": "data:application/octet-stream;base64,AAAA" },
+                { "byteLength": 2, "uri": "data:application/octet-stream;base64,AAAA" },
+                { "byteLength": 4, "uri": "data:application/octet-stream;base64,AAAA" },
+                { "byteLength": 5 }
+            ]
+        }"#;
+        let gltf = Gltf::from_slice(json).unwrap();
+        let length = |index: usize, blob: Option<Vec<u8>>| {
+            let buffer = gltf.buffers().nth(index).unwrap();
+            match super::import_buffer(buffer, Path::new("."), blob) {
+                Ok(data) => Ok(data.len()),
+                Err(Error::BufferLength { expected, actual, .. }) => Err((expected, actual)),
+                Err(error) => panic!("unexpected error: {:?}", error),
+            }
+        };
+        assert_eq!(length(0, None), Ok(4));
+        assert_eq!(length(1, None), Err((2, 3)));
+        assert_eq!(length(2, None), Err((4, 3)));
+        assert_eq!(length(3, Some(vec![0; 8])), Ok(8));
+        assert_eq!(length(3, Some(vec![0; 9])), Err((5, 9)));
+    }
+
+    #[test]
+    fn import_images_preserves_order() {
+        let json = br#"{
+            "asset": { "version": "2.0" },
+            "images": [
+                { "uri": "missing.png" },
+                { "uri": "missing.png" },
+                { "uri": "missing.png" },
+                { "uri": "missing.png" }
+            ]
+        }"#;
+        let mut gltf = Gltf::from_slice(json).unwrap();
+        let sizes = [(16, 1), (1, 2), (8, 8), (3, 4)];
+        for (index, &(width, height)) in sizes.iter().enumerate() {
+            let data = encode_png(width, height);
+            gltf.document.replace_image(index, &data, "image/png");
+        }
+        let images = super::import_images(&gltf.document, Path::new("."), &[]).unwrap();
+        let actual: Vec<_> = images.iter().map(|x| (x.width, x.height)).collect();
+        assert_eq!(&sizes[..], &actual[..]);
+    }
+
+    #[test]
+    fn import_reports_progress() {
+        use super::ImportProgress::*;
+
+        let json = br#"{
+            "asset": { "version": "2.0" },
+            "buffers": [ { "byteLength": 1, "uri": "data:application/octet-stream;base64,AA==" } ],
+            "images": [ { "uri": "missing.png" }, { "uri": "missing.png" } ]
+        }"#;
+        let mut gltf = Gltf::from_slice(json).unwrap();
+        for index in 0 .. 2 {
+            gltf.document.replace_image(index, &encode_png(1, 1), "image/png");
+        }
+        let path = ::std::env::temp_dir().join("gltf_import_reports_progress.gltf");
+        ::std::fs::write(&path, gltf.pretty_json_string().unwrap()).unwrap();
+
+        let mut stages = Vec::new();
+        let result = super::import_with_progress(&path, &mut |stage| stages.push(stage));
+        let _ = ::std::fs::remove_file(&path);
+        let (_, buffers, images) = result.unwrap();
+        assert_eq!((buffers.len(), images.len()), (1, 2));
+        assert_eq!(stages, [
+            ParsingJson,
+            LoadingBuffer { index: 0, total: 1 },
+            DecodingImage { index: 0, total: 2 },
+            DecodingImage { index: 1, total: 2 },
+        ]);
+    }
+
+    #[test]
+    fn parse_data_uris() {
+        use super::Scheme;
+
+        assert_eq!(
+            Scheme::parse("data:application/octet-stream;base64,AAEC"),
+            Scheme::Data { mime_type: Some("application/octet-stream"), base64: true, data: "AAEC" },
+        );
+        assert_eq!(
+            Scheme::parse("data:;base64,AAEC"),
+            Scheme::Data { mime_type: None, base64: true, data: "AAEC" },
+        );
+        assert_eq!(
+            Scheme::parse("data:,Hello%20World"),
+            Scheme::Data { mime_type: None, base64: false, data: "Hello%20World" },
+        );
+        assert_eq!(
+            Scheme::parse("data:text/plain;charset=utf-8,Hi"),
+            Scheme::Data { mime_type: Some("text/plain"), base64: false, data: "Hi" },
+        );
+        assert_eq!(
+            Scheme::parse("data:charset=utf-8;base64,"),
+            Scheme::Data { mime_type: None, base64: true, data: "" },
+        );
+        assert_eq!(Scheme::parse("data:application/gltf-buffer;base64"), Scheme::Unsupported);
+    }
+
+    #[test]
+    fn read_data_uris() {
+        use super::Scheme;
+
+        let base = Path::new(".");
+        let read = |uri| Scheme::read(base, uri).unwrap();
+        assert_eq!(read("data:application/gltf-buffer;base64,AAEC"), vec![0, 1, 2]);
+        assert_eq!(read("data:;base64,AA%3D%3D"), vec![0]);
+        assert_eq!(read("data:;base64,AA EC\n"), vec![0, 1, 2]);
+        assert_eq!(read("data:,Hello%20World"), b"Hello World".to_vec());
+        assert_eq!(read("data:,100%"), b"100%".to_vec());
+        assert_eq!(read("data:text/plain;charset=utf-8,%e2%9c%93"), "\u{2713}".as_bytes().to_vec());
+        match Scheme::read(base, "data:no-comma") {
+            Err(Error::UnsupportedScheme { .. }) => {},
+            other => panic!("expected unsupported scheme, got {:?}", other),
+        }
+    }
+}
@@ -74,6 +74,8 @@ extern crate cgmath;
 extern crate image as image_crate;
 #[macro_use]
 extern crate lazy_static;
+#[cfg(feature = "rayon")]
+extern crate rayon;
 
 /// Contains (de)serializable data structures that match the glTF JSON text.
 pub extern crate gltf_json as json;
@@ -84,15 +86,24 @@ pub mod accessor;
 /// Animations, their channels, targets, and samplers.
 pub mod animation;
 
+/// Metadata about the glTF asset.
+pub mod asset;
+
 /// Primitives for working with binary glTF.
 pub mod binary;
 
 /// Buffers and buffer views.
 pub mod buffer;
 
+/// Programmatic construction of glTF documents.
+pub mod build;
+
 /// Cameras and their projections.
 pub mod camera;
 
+/// Comparison of the resolved content of glTF documents.
+mod compare;
+
 /// Images that may be used by textures.
 pub mod image;
 
@@ -103,9 +114,16 @@ mod import;
 /// Iterators for walking the glTF node hierarchy.
 pub mod iter;
 
+/// Image-based environment lights.
+#[cfg(feature = "EXT_lights_image_based")]
+pub mod light;
+
 /// Material properties of primitives.
 pub mod material;
 
+/// Merging of glTF documents.
+mod merge;
+
 /// Meshes and their primitives.
 pub mod mesh;
 
@@ -115,6 +133,15 @@ pub mod scene;
 /// Mesh skinning primitives.
 pub mod skin;
 
+/// Repair of invalid references in glTF documents.
+mod repair;
+
+/// Removal of objects from glTF documents.
+mod remove;
+
+/// Removal of extensions from glTF documents.
+mod strip;
+
 /// Textures and their samplers.
 pub mod texture;
 
@@ -123,6 +150,8 @@ pub use self::animation::Animation;
 #[doc(inline)]
 pub use self::accessor::Accessor;
 #[doc(inline)]
+pub use self::asset::Asset;
+#[doc(inline)]
 pub use self::buffer::Buffer;
 #[doc(inline)]
 pub use self::camera::Camera;
@@ -130,31 +159,97 @@ pub use self::camera::Camera;
 pub use self::image::Image;
 #[cfg(feature = "import")]
 #[doc(inline)]
-pub use self::import::import;
+pub use self::import::{import, import_buffers, import_images, import_with_progress, ImportProgress};
 #[doc(inline)]
 pub use self::material::Material;
 #[doc(inline)]
 pub use self::mesh::{Attribute, Mesh, Primitive, Semantic};
 #[doc(inline)]
-pub use self::scene::{Node, Scene};
+pub use self::scene::{Node, Parents, Scene};
 #[doc(inline)]
 pub use self::skin::Skin;
 #[doc(inline)]
 pub use self::texture::Texture;
 
+#[cfg(feature = "names")]
+use std::collections::HashMap;
+use std::collections::HashSet;
 use std::path::Path;
-use std::{fs, io, ops, result};
-
-pub(crate) trait Normalize<T> {
+use std::convert::TryFrom;
+use std::{fs, io, ops, result, str};
+
+/// Converts the components of normalized accessor data between types.
+///
+/// This follows the glTF definition of normalized integers, which represent
+/// values in `[0.0, 1.0]` when unsigned and `[-1.0, 1.0]` when signed, and is
+/// how the crate reads colors, weights, and quantized attributes. Conversion
+/// is component-wise and does not scale vectors to unit length. Conversion
+/// to an integer type rounds to the nearest value, so integer types convert
+/// to each other as if through `f32`.
+///
+/// Conversion to an unsigned type clamps negative values to zero, so data
+/// with negative components, such as quaternions, should only be converted
+/// to signed types or `f32`.
+///
+/// ```
+/// use gltf::Normalize;
+///
+/// let color: [u8; 3] = [65535u16, 32768, 0].normalize();
+/// assert_eq!(color, [255, 128, 0]);
+/// let rotation: [f32; 4] = [0i16, 0, -32767, 32767].normalize();
+/// assert_eq!(rotation, [0.0, 0.0, -1.0, 1.0]);
+/// ```
+pub trait Normalize<T> {
+    /// Converts `self` to `T`.
     fn normalize(self) -> T;
 }
 
 /// Result type for convenience.
 pub type Result<T> = result::Result<T, Error>;
 
+/// Deserializes application specific data into a user type, or returns
+/// `None` if there is no data.
+#[cfg(feature = "extras")]
+pub(crate) fn extras_as<T>(extras: &json::Extras) -> Option<Result<T>>
+where
+    T: json::deserialize::DeserializeOwned,
+{
+    extras
+        .as_ref()
+        .map(|value| json::deserialize::from_value(value.clone()).map_err(Error::Deserialize))
+}
+
+/// Looks up an extension by name in the serialized form of `extensions`,
+/// which includes the extensions that are parsed into typed fields.
+#[cfg(feature = "extensions")]
+pub(crate) fn extension_value<T>(extensions: &T, name: &str) -> Option<json::Value>
+where
+    T: json::serialize::Serialize,
+{
+    match json::serialize::to_value(extensions) {
+        Ok(json::Value::Object(mut map)) => map.remove(name),
+        _ => None,
+    }
+}
+
 /// Represents a runtime error.
 #[derive(Debug)]
 pub enum Error {
+    /// The bytes of an accessor extend beyond the end of its buffer view.
+    #[cfg(feature = "import")]
+    AccessorBounds {
+        /// The index of the offending accessor.
+        accessor: usize,
+    },
+
+    /// The data type or dimensions of an accessor do not match the requested
+    /// item type.
+    #[cfg(feature = "utils")]
+    AccessorType {
+        /// The index of the offending accessor.
+        accessor: usize,
+    },
+
     /// Base 64 decoding error.
     #[cfg(feature = "import")]
     Base64(base64::DecodeError),
@@ -175,6 +270,13 @@ pub enum Error {
         actual: usize,
     },
 
+    /// A buffer view extends beyond the end of its buffer.
+    #[cfg(feature = "import")]
+    BufferViewBounds {
+        /// The index of the offending buffer view.
+        view: usize,
+    },
+
     /// JSON deserialization error.
     Deserialize(json::Error),
 
@@ -187,7 +289,46 @@ pub enum Error {
     
     /// The `BIN` chunk of binary glTF is referenced but does not exist.
     #[cfg(feature = "import")]
-    MissingBlob,
+    MissingBlob {
+        /// The index of the buffer referring to the `BIN` chunk.
+        buffer: usize,
+    },
+
+    /// Buffer data required to read an accessor was not provided.
+    #[cfg(any(feature = "import", feature = "utils"))]
+    MissingBufferData {
+        /// The index of the buffer whose data is missing.
+        buffer: usize,
+    },
+
+    /// A node is listed as a child by more than one node.
+    MultipleParents {
+        /// The index of the offending node.
+        node: usize,
+    },
+
+    /// An object cannot be removed because it is still referenced by another
+    /// object that cannot do without it.
+    Referenced {
+        /// The path of the offending reference.
+        path: json::Path,
+    },
+
+    /// An extension cannot be removed because the document requires it.
+    RequiredExtension {
+        /// The name of the extension.
+        name: String,
+    },
+
+    /// The joint weights of a vertex do not sum to `1.0`.
+    #[cfg(feature = "utils")]
+    UnnormalizedWeights {
+        /// The index of the offending vertex.
+        vertex: usize,
+
+        /// The sum of the weights of the vertex.
+        sum: f32,
+    },
 
     /// Unsupported image encoding.
     #[cfg(feature = "import")]
@@ -195,12 +336,60 @@ pub enum Error {
 
     /// Unsupported URI scheme.
     #[cfg(feature = "import")]
-    UnsupportedScheme,
+    UnsupportedScheme {
+        /// The offending URI.
+        uri: String,
+    },
 
     /// glTF validation error.
     Validation(Vec<(json::Path, json::validation::Error)>),
 }
 
+/// Categorized validation problems of a glTF asset.
+#[derive(Clone, Debug, Default)]
+pub struct Diagnostics {
+    /// Problems that make the asset unsafe to use with this library.
+    pub errors: Vec<(json::Path, json::validation::Error)>,
+
+    /// Violations of the glTF 2.0 specification that the library tolerates,
+    /// extensions required by the asset that are not enabled, and references
+    /// removed by lenient loading.
+    pub warnings: Vec<(json::Path, json::validation::Error)>,
+
+    /// Extensions used by the asset that are not enabled, which are ignored.
+    pub infos: Vec<(json::Path, json::validation::Error)>,
+}
+
+/// Configures which validation problems `Document::validate_with` treats as
+/// errors.
+///
+/// Problems found by the minimal validation checks make the asset unsafe to
+/// use with this library and are always errors. The default configuration
+/// treats no other problem as an error, matching `Gltf::from_slice`.
+#[derive(Clone, Copy, Debug)]
+pub struct ValidationConfig {
+    /// Whether extensions required by the asset that are not enabled are
+    /// reported as warnings rather than errors.
+    ///
+    /// The default value is `true`.
+    pub allow_unknown_extensions: bool,
+
+    /// Returns whether a violation of the glTF 2.0 specification, as found
+    /// by the complete validation checks, is an error rather than a warning.
+    ///
+    /// The default predicate returns `false`.
+    pub is_error: fn(&json::Path, json::validation::Error) -> bool,
+}
+
+impl Default for ValidationConfig {
+    fn default() -> Self {
+        ValidationConfig {
+            allow_unknown_extensions: true,
+            is_error: |_, _| false,
+        }
+    }
+}
+
 /// glTF JSON wrapper plus binary payload.
 #[derive(Clone, Debug)]
 pub struct Gltf {
@@ -228,6 +417,9 @@ impl Gltf {
     }
 
     /// Loads glTF from a reader without performing validation checks.
+    ///
+    /// Binary glTF is read chunk by chunk, so any data following the `BIN`
+    /// chunk is not inspected.
     pub fn from_reader_without_validation<R>(mut reader: R) -> Result<Self>
     where
         R: io::Read + io::Seek
@@ -235,12 +427,55 @@ impl Gltf {
         let mut magic = [0u8; 4];
         reader.read_exact(&mut magic)?;
         reader.seek(io::SeekFrom::Start(0))?;
+        Self::from_reader_with_magic(&magic, reader)
+    }
+
+    /// Loads glTF from a reader that does not implement `io::Seek`, such as
+    /// a network socket or a pipe.
+    ///
+    /// The first four bytes are buffered to tell binary glTF apart from JSON
+    /// and are then chained back in front of the remaining data.
+    pub fn from_reader_no_seek<R>(mut reader: R) -> Result<Self>
+    where
+        R: io::Read,
+    {
+        let mut magic = [0u8; 4];
+        reader.read_exact(&mut magic)?;
+        let reader = io::Read::chain(io::Cursor::new(magic), reader);
+        let gltf = Self::from_reader_with_magic(&magic, reader)?;
+        let _ = gltf.document.validate()?;
+        Ok(gltf)
+    }
+
+    /// Loads glTF without validation from a reader positioned at the start
+    /// of the data, whose first four bytes are `magic`.
+    fn from_reader_with_magic<R>(magic: &[u8; 4], reader: R) -> Result<Self>
+    where
+        R: io::Read,
+    {
         let (json, blob): (json::Root, Option<Vec<u8>>);
         if magic.starts_with(b"glTF") {
-            let mut glb = binary::Glb::from_reader(reader)?;
-            // TODO: use `json::from_reader` instead of `json::from_slice`
+            // The chunks are read one at a time rather than buffering the
+            // whole file and copying them out. The JSON chunk is still read
+            // into memory since `serde_json` parses a slice considerably
+            // faster than a reader, and it is usually small next to the BIN
+            // chunk.
+            let glb = binary::Glb::from_reader_streaming(reader)?;
             json = json::deserialize::from_slice(&glb.json)?;
-            blob = glb.bin.take().map(|x| x.into_owned());
+            blob = match glb.bin {
+                Some(mut bin) => {
+                    // The chunk length is untrusted, so the data is not
+                    // preallocated.
+                    let length = bin.limit() as usize;
+                    let mut data = Vec::new();
+                    io::Read::read_to_end(&mut bin, &mut data)?;
+                    if data.len() < length {
+                        return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+                    }
+                    Some(data)
+                },
+                None => None,
+            };
         } else {
             json = json::deserialize::from_reader(reader)?;
             blob = None;
@@ -281,6 +516,64 @@ impl Gltf {
         let _ = gltf.document.validate()?;
         Ok(gltf)
     }
+
+    /// Loads glTF from JSON text without performing validation checks.
+    pub fn from_json_str_without_validation(text: &str) -> Result<Self> {
+        let json = json::deserialize::from_str(text)?;
+        let document = Document::from_json_without_validation(json);
+        Ok(Gltf { document, blob: None })
+    }
+
+    /// Loads glTF from JSON text.
+    ///
+    /// Unlike `from_slice`, binary glTF is not accepted.
+    pub fn from_json_str(text: &str) -> Result<Self> {
+        let gltf = Self::from_json_str_without_validation(text)?;
+        let _ = gltf.document.validate()?;
+        Ok(gltf)
+    }
+
+    /// Loads glTF from a slice of bytes, reporting validation problems
+    /// instead of failing.
+    ///
+    /// Optional references that are out of bounds are removed, as described
+    /// for `Document::from_json_lenient`. Only malformed JSON or binary glTF
+    /// results in an error. The caller should not use the returned `Gltf` if
+    /// `Diagnostics::errors` is not empty.
+    pub fn from_slice_lenient(slice: &[u8]) -> Result<(Self, Diagnostics)> {
+        let Gltf { document, blob } = Self::from_slice_without_validation(slice)?;
+        let (document, diagnostics) = Document::from_json_lenient(document.0);
+        Ok((Gltf { document, blob }, diagnostics))
+    }
+
+    /// Constructs glTF from a document and a separately loaded binary
+    /// payload, which provides the data of the buffer without a URI.
+    pub fn with_blob(document: Document, blob: Vec<u8>) -> Self {
+        Gltf { document, blob: Some(blob) }
+    }
+
+    /// Attaches a binary payload, returning the previous payload if any.
+    pub fn set_blob(&mut self, blob: Vec<u8>) -> Option<Vec<u8>> {
+        self.blob.replace(blob)
+    }
+
+    /// Returns the data of a buffer stored in the binary payload.
+    ///
+    /// Returns `None` if the buffer refers to a URI, or if there is no binary
+    /// payload or it is shorter than the buffer. This may be passed to the
+    /// readers of the crate, for example
+    /// `primitive.reader(|buffer| gltf.buffer_data(buffer))`.
+    pub fn buffer_data(&self, buffer: Buffer) -> Option<&[u8]> {
+        match buffer.source() {
+            buffer::Source::Bin => self.blob.as_ref().and_then(|blob| blob.get(.. buffer.length())),
+            buffer::Source::Uri(_) => None,
+        }
+    }
+
+    /// Unwraps the glTF document and binary payload.
+    pub fn into_parts(self) -> (Document, Option<Vec<u8>>) {
+        (self.document, self.blob)
+    }
 }
 
 impl ops::Deref for Gltf {
@@ -296,6 +589,24 @@ impl ops::DerefMut for Gltf {
     }
 }
 
+impl<'a> TryFrom<&'a [u8]> for Gltf {
+    type Error = Error;
+
+    /// Equivalent to `Gltf::from_slice`.
+    fn try_from(slice: &'a [u8]) -> Result<Self> {
+        Self::from_slice(slice)
+    }
+}
+
+impl str::FromStr for Gltf {
+    type Err = Error;
+
+    /// Equivalent to `Gltf::from_json_str`.
+    fn from_str(text: &str) -> Result<Self> {
+        Self::from_json_str(text)
+    }
+}
+
 impl Document {
     /// Loads glTF from pre-deserialized JSON.
     pub fn from_json(json: json::Root) -> Result<Self> {
@@ -310,11 +621,126 @@ impl Document {
         Document(json)
     }
 
+    /// Loads glTF from pre-deserialized JSON, reporting validation problems
+    /// instead of failing.
+    ///
+    /// Optional references that are out of bounds, such as a stray texture
+    /// index of a material or the mesh of a node, are removed so that they
+    /// read as absent, and reported as `IndexOutOfBounds` warnings. Required
+    /// references that are out of bounds, such as the nodes of a scene, are
+    /// reported as errors. The caller should not use the returned `Document`
+    /// if `Diagnostics::errors` is not empty.
+    pub fn from_json_lenient(mut json: json::Root) -> (Self, Diagnostics) {
+        let repairs = repair::clear_invalid_references(&mut json);
+        let document = Document::from_json_without_validation(json);
+        let mut diagnostics = document.validate_lenient();
+        diagnostics.warnings.splice(0 .. 0, repairs);
+        (document, diagnostics)
+    }
+
     /// Unwraps the glTF document.
     pub fn into_json(self) -> json::Root {
         self.0
     }
 
+    /// Serializes the glTF document as human-readable JSON.
+    ///
+    /// Object keys are written in lexicographical order and arrays keep their
+    /// original order, so the same document always produces identical output.
+    pub fn pretty_json_string(&self) -> result::Result<String, json::Error> {
+        fn canonicalize(value: json::Value) -> json::Value {
+            match value {
+                json::Value::Array(values) => {
+                    json::Value::Array(values.into_iter().map(canonicalize).collect())
+                },
+                json::Value::Object(map) => {
+                    let mut entries: Vec<_> = map.into_iter().collect();
+                    entries.sort_by(|a, b| a.0.cmp(&b.0));
+                    json::Value::Object(
+                        entries
+                            .into_iter()
+                            .map(|(key, value)| (key, canonicalize(value)))
+                            .collect()
+                    )
+                },
+                value => value,
+            }
+        }
+        let value = json::serialize::to_value(&self.0)?;
+        json::serialize::to_string_pretty(&canonicalize(value))
+    }
+
+    /// Performs all validation checks, categorizing the problems found by
+    /// severity.
+    ///
+    /// Checks against the glTF 2.0 specification are only performed when no
+    /// errors are found, since they assume the asset is otherwise usable.
+    pub fn validate_lenient(&self) -> Diagnostics {
+        self.diagnose(&ValidationConfig::default())
+    }
+
+    /// Performs all validation checks, failing if any problem is an error
+    /// according to `config`.
+    ///
+    /// On success, the remaining problems are returned categorized as for
+    /// `validate_lenient`, with `Diagnostics::errors` left empty.
+    pub fn validate_with(&self, config: &ValidationConfig) -> Result<Diagnostics> {
+        let mut diagnostics = self.diagnose(config);
+        if diagnostics.errors.is_empty() {
+            Ok(diagnostics)
+        } else {
+            Err(Error::Validation(std::mem::replace(&mut diagnostics.errors, Vec::new())))
+        }
+    }
+
+    /// Performs all validation checks, categorizing the problems found
+    /// according to `config`.
+    fn diagnose(&self, config: &ValidationConfig) -> Diagnostics {
+        use json::validation::{Error, Validate};
+        let mut diagnostics = Diagnostics::default();
+        self.0.validate_minimally(
+            &self.0,
+            json::Path::new,
+            &mut |path, error| diagnostics.errors.push((path(), error)),
+        );
+        if diagnostics.errors.is_empty() {
+            let mut violations = Vec::new();
+            self.0.validate_completely(
+                &self.0,
+                json::Path::new,
+                &mut |path, error| violations.push((path(), error)),
+            );
+            for (path, error) in violations {
+                if (config.is_error)(&path, error) {
+                    diagnostics.errors.push((path, error));
+                } else {
+                    diagnostics.warnings.push((path, error));
+                }
+            }
+        }
+
+        let enabled = |name: &String| {
+            json::extensions::ENABLED_EXTENSIONS.contains(&name.as_str())
+        };
+        for (index, name) in self.0.extensions_required.iter().enumerate() {
+            if !enabled(name) {
+                let path = json::Path::new().field("extensionsRequired").index(index);
+                if config.allow_unknown_extensions {
+                    diagnostics.warnings.push((path, Error::Unsupported));
+                } else {
+                    diagnostics.errors.push((path, Error::Unsupported));
+                }
+            }
+        }
+        for (index, name) in self.0.extensions_used.iter().enumerate() {
+            if !enabled(name) && !self.0.extensions_required.contains(name) {
+                let path = json::Path::new().field("extensionsUsed").index(index);
+                diagnostics.infos.push((path, Error::Unsupported));
+            }
+        }
+        diagnostics
+    }
+
     /// Perform validation checks on loaded glTF.
     pub(crate) fn validate(&self) -> Result<()> {
         use json::validation::Validate;
@@ -347,6 +773,11 @@ impl Document {
         }
     }
 
+    /// Returns the metadata of the glTF asset.
+    pub fn asset(&self) -> Asset {
+        Asset::new(&self.0.asset)
+    }
+
     /// Returns an `Iterator` that visits the pre-loaded buffers of the glTF asset.
     pub fn buffers(&self) -> iter::Buffers {
         iter::Buffers {
@@ -363,6 +794,22 @@ impl Document {
         }
     }
 
+    /// A copyright message suitable for display to credit the content creator.
+    pub fn copyright(&self) -> Option<&str> {
+        self.asset().copyright()
+    }
+
+    /// Returns the material used by primitives that do not reference one.
+    ///
+    /// This material is not stored in the JSON; it has the default values of
+    /// the glTF specification, such as a white base color and a metallic and
+    /// roughness factor of `1.0`. It is the same material returned by
+    /// `Primitive::material` for such primitives, and its `index` is `None`,
+    /// so defaulted and explicit materials may be rendered alike.
+    pub fn default_material(&self) -> Material {
+        Material::default(self)
+    }
+
     /// Returns the default scene, if provided.
     pub fn default_scene(&self) -> Option<Scene> {
         self.0
@@ -381,6 +828,16 @@ impl Document {
         iter::ExtensionsRequired(self.0.extensions_required.iter())
     }
 
+    /// Tool that generated this glTF model.
+    pub fn generator(&self) -> Option<&str> {
+        self.asset().generator()
+    }
+
+    /// The glTF version of this asset, for example `"2.0"`.
+    pub fn gltf_version(&self) -> &str {
+        self.asset().version()
+    }
+
     /// Returns an `Iterator` that visits the pre-loaded images of the glTF asset.
     pub fn images(&self) -> iter::Images {
         iter::Images {
@@ -389,6 +846,57 @@ impl Document {
         }
     }
 
+    /// Returns an `Iterator` that visits the image-based lights of the glTF
+    /// asset, as specified by the `EXT_lights_image_based` extension.
+    #[cfg(feature = "EXT_lights_image_based")]
+    pub fn image_based_lights(&self) -> light::ImageBasedLights {
+        let lights = self.0.extensions.lights_image_based
+            .as_ref()
+            .map_or(&[][..], |extension| &extension.lights[..]);
+        light::ImageBasedLights {
+            iter: lights.iter().enumerate(),
+            document: self,
+        }
+    }
+
+    /// Returns the color space of each image, indexed by image, as determined
+    /// by the material textures that use it.
+    ///
+    /// Images used by color textures, such as base color and emissive
+    /// textures, are `ColorSpace::Srgb` and should be uploaded to an sRGB GPU
+    /// texture format. Images used only by data textures, such as normal,
+    /// metallic-roughness, and occlusion textures, are `ColorSpace::Linear`.
+    /// Images not used by any material are `None`.
+    pub fn image_color_spaces(&self) -> Vec<Option<image::ColorSpace>> {
+        let mut color_spaces = vec![None; self.0.images.len()];
+        for material in self.materials() {
+            material.visit_textures(|texture, color_space| {
+                let sources = Some(texture.source()).into_iter();
+                #[cfg(feature = "KHR_texture_basisu")]
+                let sources = sources.chain(texture.basisu_source());
+                for image in sources {
+                    let entry = &mut color_spaces[image.index()];
+                    if *entry != Some(image::ColorSpace::Srgb) {
+                        *entry = Some(color_space);
+                    }
+                }
+            });
+        }
+        color_spaces
+    }
+
+    /// Returns the indices of the nodes used as a joint by any skin.
+    ///
+    /// Building the set scans the joints of every skin once, so prefer it
+    /// over calling `Node::is_joint` for many nodes.
+    pub fn joint_nodes(&self) -> HashSet<usize> {
+        self.0.skins
+            .iter()
+            .flat_map(|skin| skin.joints.iter())
+            .map(|joint| joint.value())
+            .collect()
+    }
+
     /// Returns an `Iterator` that visits the materials of the glTF asset.
     pub fn materials(&self) -> iter::Materials {
         iter::Materials {
@@ -397,6 +905,59 @@ impl Document {
         }
     }
 
+    /// Returns the first material with the given name, if any.
+    #[cfg(feature = "names")]
+    pub fn material_by_name(&self, name: &str) -> Option<Material> {
+        self.materials().find(|material| material.name() == Some(name))
+    }
+
+    /// Appends the contents of another document to this one.
+    ///
+    /// Every object of `other` is appended after the objects of the same
+    /// kind in this document, and all of its indices are offset to match.
+    /// Extension names are merged without duplicates. The default scene of
+    /// this document is kept, falling back to that of `other`. The asset
+    /// metadata and root extras of `other` are discarded.
+    ///
+    /// The buffers of `other` are appended after the buffers of this
+    /// document, so buffer data must be supplied in the same order. Note
+    /// that a merged document may contain more than one buffer without a
+    /// URI, which cannot be written as a single binary glTF.
+    pub fn merge(&mut self, other: Document) {
+        merge::merge(&mut self.0, other.0);
+    }
+
+    /// Returns `true` if both documents describe the same content, regardless
+    /// of how their objects are ordered.
+    ///
+    /// Every index is resolved to the object it refers to before comparing,
+    /// so reordered arrays, reordered child lists and scene root lists, and
+    /// node transforms given as TRS rather than a matrix compare equal.
+    /// Objects that are not referenced from a scene, node hierarchy or
+    /// animation are ignored, as are the asset metadata and root extras.
+    ///
+    /// Buffer contents are not compared; see `semantically_eq_with_data`.
+    pub fn semantically_eq(&self, other: &Document) -> bool {
+        compare::semantically_eq(self, other, None)
+    }
+
+    /// Like `semantically_eq`, but additionally compares the data read by
+    /// accessors and the images stored in buffer views.
+    ///
+    /// `buffers` and `other_buffers` are the imported buffer data of this
+    /// document and of `other` respectively.
+    #[cfg(feature = "import")]
+    pub fn semantically_eq_with_data(
+        &self,
+        buffers: &[buffer::Data],
+        other: &Document,
+        other_buffers: &[buffer::Data],
+    ) -> bool {
+        let buffers: Vec<&[u8]> = buffers.iter().map(|data| &data[..]).collect();
+        let other_buffers: Vec<&[u8]> = other_buffers.iter().map(|data| &data[..]).collect();
+        compare::semantically_eq(self, other, Some((&buffers, &other_buffers)))
+    }
+
     /// Returns an `Iterator` that visits the meshes of the glTF asset.
     pub fn meshes(&self) -> iter::Meshes {
         iter::Meshes {
@@ -405,6 +966,12 @@ impl Document {
         }
     }
 
+    /// Returns the first mesh with the given name, if any.
+    #[cfg(feature = "names")]
+    pub fn mesh_by_name(&self, name: &str) -> Option<Mesh> {
+        self.meshes().find(|mesh| mesh.name() == Some(name))
+    }
+
     /// Returns an `Iterator` that visits the nodes of the glTF asset.
     pub fn nodes(&self) -> iter::Nodes {
         iter::Nodes {
@@ -413,6 +980,177 @@ impl Document {
         }
     }
 
+    /// Returns the first node with the given name, if any.
+    #[cfg(feature = "names")]
+    pub fn node_by_name(&self, name: &str) -> Option<Node> {
+        self.nodes().find(|node| node.name() == Some(name))
+    }
+
+    /// Builds a lookup of the parent of each node.
+    ///
+    /// A node listed as a child by more than one node, as in malformed
+    /// hierarchies, is given the first of those nodes as its parent.
+    pub fn node_parents(&self) -> Parents {
+        Parents::new(self)
+    }
+
+    /// Builds a lookup of the parent of each node, failing with
+    /// `Error::MultipleParents` if any node is listed as a child by more
+    /// than one node.
+    pub fn node_parents_strict(&self) -> Result<Parents> {
+        let parents = Parents::new(self);
+        match parents.shared() {
+            Some(node) => Err(Error::MultipleParents { node }),
+            None => Ok(parents),
+        }
+    }
+
+    /// Returns the names of the ancestors of a node, starting from its root
+    /// node and ending with the node itself, for example
+    /// `[Some("Armature"), Some("Spine"), Some("Head")]`.
+    ///
+    /// Unnamed nodes are represented by `None`. If a node has more than one
+    /// parent, as in malformed hierarchies, the first is followed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[cfg(feature = "names")]
+    pub fn node_path(&self, index: usize) -> Vec<Option<&str>> {
+        let nodes = &self.0.nodes;
+        let parents = self.node_parents();
+
+        let mut path = vec![nodes[index].name.as_ref().map(String::as_str)];
+        let mut visited = vec![false; nodes.len()];
+        visited[index] = true;
+        let mut current = index;
+        while let Some(parent) = parents.parent_index(current) {
+            if visited[parent] {
+                break;
+            }
+            visited[parent] = true;
+            path.push(nodes[parent].name.as_ref().map(String::as_str));
+            current = parent;
+        }
+        path.reverse();
+        path
+    }
+
+    /// Removes an extension from the document, for example one that the
+    /// application cannot handle, so that the asset falls back to its core
+    /// glTF content.
+    ///
+    /// Every object of the extension is deleted and `name` is removed from
+    /// the extensions used. Returns the JSON paths of the objects that were
+    /// modified, such as `materials[0]`, where the empty path is the root.
+    ///
+    /// Extensions in `extensionsRequired`, such as `KHR_draco_mesh_compression`
+    /// without uncompressed fallback data, cannot be removed without breaking
+    /// the asset, and fail with `Error::RequiredExtension`. The document is
+    /// left unchanged on error.
+    pub fn remove_extension(&mut self, name: &str) -> Result<Vec<json::Path>> {
+        strip::remove_extension(&mut self.0, name)
+    }
+
+    /// Removes a material and updates every index that refers to the
+    /// materials following it.
+    ///
+    /// Primitives that use the material fall back to the default material.
+    /// Returns the JSON paths of those primitives.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove_material(&mut self, index: usize) -> Vec<json::Path> {
+        remove::remove_material(&mut self.0, index)
+    }
+
+    /// Removes a mesh and updates every index that refers to the meshes
+    /// following it.
+    ///
+    /// Nodes that instantiate the mesh lose their mesh, as well as their skin
+    /// and morph target weights. Returns the JSON paths of those nodes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove_mesh(&mut self, index: usize) -> Vec<json::Path> {
+        remove::remove_mesh(&mut self.0, index)
+    }
+
+    /// Removes a node and updates every index that refers to the nodes
+    /// following it.
+    ///
+    /// The node is removed from the children of its parent and from the root
+    /// nodes of scenes, the animation channels targeting it are deleted, and
+    /// skins using it as their skeleton root lose their skeleton. Animations
+    /// left without channels are removed. Children of the node are not
+    /// removed, but are no longer part of any scene unless listed elsewhere.
+    /// Returns the JSON paths of the modified objects, with nodes and
+    /// animations numbered after the removal.
+    ///
+    /// Nodes used as skin joints cannot be removed without breaking the
+    /// skinned meshes, and fail with `Error::Referenced`. The document is left
+    /// unchanged on error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove_node(&mut self, index: usize) -> Result<Vec<json::Path>> {
+        remove::remove_node(&mut self.0, index)
+    }
+
+    /// Replaces the source of an image with the given encoded image data.
+    ///
+    /// The image data is embedded into the document as a base 64 data URI and
+    /// the image MIME type is updated to `mime_type`, for example `"image/png"`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[cfg(feature = "import")]
+    pub fn replace_image(&mut self, index: usize, data: &[u8], mime_type: &str) {
+        let image = &mut self.0.images[index];
+        let uri = format!("data:{};base64,{}", mime_type, base64::encode(data));
+        image.uri = Some(uri);
+        image.buffer_view = None;
+        image.mime_type = Some(json::image::MimeType(mime_type.to_string()));
+    }
+
+    /// Rewrites the target nodes of an animation's channels by node name.
+    ///
+    /// Each channel targeting a node named `a` is retargeted to the first node
+    /// named `b`, where `name_map` maps `a` to `b`. Channels targeting unnamed
+    /// nodes, nodes whose name is not in `name_map`, or names with no matching
+    /// node in the document are left unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `animation` is out of bounds.
+    #[cfg(feature = "names")]
+    pub fn retarget_animation(
+        &mut self,
+        animation: usize,
+        name_map: &HashMap<String, String>,
+    ) {
+        let nodes = &self.0.nodes;
+        let find_node = |name: &str| {
+            nodes
+                .iter()
+                .position(|node| node.name.as_ref().map(String::as_str) == Some(name))
+        };
+        for channel in &mut self.0.animations[animation].channels {
+            let target = nodes[channel.target.node.value()]
+                .name
+                .as_ref()
+                .and_then(|name| name_map.get(name))
+                .and_then(|name| find_node(name));
+            if let Some(index) = target {
+                channel.target.node = json::Index::new(index as u32);
+            }
+        }
+    }
+
     /// Returns an `Iterator` that visits the samplers of the glTF asset.
     pub fn samplers(&self) -> iter::Samplers {
         iter::Samplers {
@@ -429,6 +1167,46 @@ impl Document {
         }
     }
 
+    /// Sets the scene to be displayed when the asset is loaded.
+    ///
+    /// Fails with `Error::Validation` if the scene does not exist, in which case
+    /// the document is left unchanged.
+    pub fn set_default_scene(&mut self, index: usize) -> Result<()> {
+        if index >= self.0.scenes.len() {
+            let path = json::Path::new().field("scene");
+            return Err(Error::Validation(vec![(path, json::validation::Error::IndexOutOfBounds)]));
+        }
+        self.0.scene = Some(json::Index::new(index as u32));
+        Ok(())
+    }
+
+    /// Replaces the root nodes of a scene.
+    ///
+    /// Fails with `Error::Validation`, listing each offending entry of `nodes`,
+    /// if any of the nodes does not exist, in which case the document is left
+    /// unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scene` is out of bounds.
+    pub fn set_scene_nodes(&mut self, scene: usize, nodes: &[usize]) -> Result<()> {
+        assert!(scene < self.0.scenes.len(), "scene index out of bounds");
+        let errors: Vec<_> = nodes
+            .iter()
+            .enumerate()
+            .filter(|&(_, &node)| node >= self.0.nodes.len())
+            .map(|(i, _)| {
+                let path = json::Path::new().field("scenes").index(scene).field("nodes").index(i);
+                (path, json::validation::Error::IndexOutOfBounds)
+            })
+            .collect();
+        if !errors.is_empty() {
+            return Err(Error::Validation(errors));
+        }
+        self.0.scenes[scene].nodes = nodes.iter().map(|&node| json::Index::new(node as u32)).collect();
+        Ok(())
+    }
+
     /// Returns an `Iterator` that visits the skins of the glTF asset.
     pub fn skins(&self) -> iter::Skins {
         iter::Skins {
@@ -445,6 +1223,22 @@ impl Document {
         }
     }
 
+    /// Returns the number of triangles of all meshes, as given by
+    /// `Mesh::triangle_count`.
+    ///
+    /// Each mesh is counted once, regardless of how many nodes instantiate it.
+    pub fn triangle_count(&self) -> usize {
+        self.meshes().map(|mesh| mesh.triangle_count()).sum()
+    }
+
+    /// Returns the number of vertices of all meshes, as given by
+    /// `Mesh::vertex_count`.
+    ///
+    /// Each mesh is counted once, regardless of how many nodes instantiate it.
+    pub fn vertex_count(&self) -> usize {
+        self.meshes().map(|mesh| mesh.vertex_count()).sum()
+    }
+
     /// Returns an `Iterator` that visits the pre-loaded buffer views of the glTF
     /// asset.
     pub fn views(&self) -> iter::Views {
@@ -457,29 +1251,62 @@ impl Document {
 
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        use std::error::Error;
-        write!(f, "{}", self.description())
+        use std::error::Error as StdError;
+        match *self {
+            #[cfg(feature = "import")]
+            Error::MissingBlob { buffer } => {
+                write!(f, "{} (buffer {})", self.description(), buffer)
+            },
+            Error::Referenced { ref path } => {
+                write!(f, "{} ({})", self.description(), path)
+            },
+            Error::RequiredExtension { ref name } => {
+                write!(f, "{} ({})", self.description(), name)
+            },
+            #[cfg(feature = "utils")]
+            Error::UnnormalizedWeights { vertex, sum } => {
+                write!(f, "{} (vertex {} sums to {})", self.description(), vertex, sum)
+            },
+            #[cfg(feature = "import")]
+            Error::UnsupportedScheme { ref uri } => {
+                write!(f, "{} (URI {:?})", self.description(), uri)
+            },
+            _ => write!(f, "{}", self.description()),
+        }
     }
 }
 
 impl std::error::Error for Error {
     fn description(&self) -> &str {
         match *self {
+            #[cfg(feature = "import")]
+            Error::AccessorBounds { .. } => "accessor exceeds the bounds of its buffer view",
+            #[cfg(feature = "utils")]
+            Error::AccessorType { .. } => "accessor type does not match item type",
             #[cfg(feature = "import")]
             Error::Base64(ref e) => e.description(),
             Error::Binary(ref e) => e.description(),
             #[cfg(feature = "import")]
             Error::BufferLength { .. } => "buffer length does not match expected length",
+            #[cfg(feature = "import")]
+            Error::BufferViewBounds { .. } => "buffer view exceeds the bounds of its buffer",
             Error::Deserialize(ref e) => e.description(),
             Error::Io(ref e) => e.description(),
             #[cfg(feature = "import")]
             Error::Image(ref e) => e.description(),
             #[cfg(feature = "import")]
-            Error::MissingBlob => "missing BIN section of binary glTF",
+            Error::MissingBlob { .. } => "missing BIN section of binary glTF",
+            #[cfg(any(feature = "import", feature = "utils"))]
+            Error::MissingBufferData { .. } => "missing buffer data",
+            Error::MultipleParents { .. } => "node has more than one parent",
+            Error::Referenced { .. } => "object is still referenced",
+            Error::RequiredExtension { .. } => "extension is required by the document",
+            #[cfg(feature = "utils")]
+            Error::UnnormalizedWeights { .. } => "joint weights do not sum to 1.0",
             #[cfg(feature = "import")]
             Error::UnsupportedImageEncoding => "unsupported image encoding",
             #[cfg(feature = "import")]
-            Error::UnsupportedScheme => "unsupported URI scheme",
+            Error::UnsupportedScheme { .. } => "unsupported URI scheme",
             Error::Validation(_) => "invalid glTF",
         }
     }
@@ -521,15 +1348,15 @@ impl Normalize<i8> for i8 {
 }
 
 impl Normalize<u8> for i8 {
-    fn normalize(self) -> u8 { self.max(0) as u8 * 2 }
+    fn normalize(self) -> u8 { Normalize::<f32>::normalize(self).normalize() }
 }
 
 impl Normalize<i16> for i8 {
-    fn normalize(self) -> i16 { self as i16 * 0x100 }
+    fn normalize(self) -> i16 { Normalize::<f32>::normalize(self).normalize() }
 }
 
 impl Normalize<u16> for i8 {
-    fn normalize(self) -> u16 { self.max(0) as u16 * 0x200 }
+    fn normalize(self) -> u16 { Normalize::<f32>::normalize(self).normalize() }
 }
 
 impl Normalize<f32> for i8 {
@@ -537,7 +1364,7 @@ impl Normalize<f32> for i8 {
 }
 
 impl Normalize<i8> for u8 {
-    fn normalize(self) -> i8 { (self / 2) as i8 }
+    fn normalize(self) -> i8 { Normalize::<f32>::normalize(self).normalize() }
 }
 
 impl Normalize<u8> for u8 {
@@ -545,23 +1372,23 @@ impl Normalize<u8> for u8 {
 }
 
 impl Normalize<i16> for u8 {
-    fn normalize(self) -> i16 { self as i16 * 0x80 }
+    fn normalize(self) -> i16 { Normalize::<f32>::normalize(self).normalize() }
 }
 
 impl Normalize<u16> for u8 {
-    fn normalize(self) -> u16 { self.max(0) as u16 * 2 }
+    fn normalize(self) -> u16 { Normalize::<f32>::normalize(self).normalize() }
 }
 
 impl Normalize<f32> for u8 {
-    fn normalize(self) -> f32 { (self as f32 * 32767.0_f32.recip()).max(-1.0) }
+    fn normalize(self) -> f32 { self as f32 * 255.0_f32.recip() }
 }
 
 impl Normalize<i8> for i16 {
-    fn normalize(self) -> i8 { (self / 0x100) as i8 }
+    fn normalize(self) -> i8 { Normalize::<f32>::normalize(self).normalize() }
 }
 
 impl Normalize<u8> for i16 {
-    fn normalize(self) -> u8 { (self.max(0) / 0x80) as u8 }
+    fn normalize(self) -> u8 { Normalize::<f32>::normalize(self).normalize() }
 }
 
 impl Normalize<i16> for i16 {
@@ -569,7 +1396,7 @@ impl Normalize<i16> for i16 {
 }
 
 impl Normalize<u16> for i16 {
-    fn normalize(self) -> u16 { self.max(0) as u16 * 2 }
+    fn normalize(self) -> u16 { Normalize::<f32>::normalize(self).normalize() }
 }
 
 impl Normalize<f32> for i16 {
@@ -577,15 +1404,15 @@ impl Normalize<f32> for i16 {
 }
 
 impl Normalize<i8> for u16 {
-    fn normalize(self) -> i8 { (self / 0x200) as i8 }
+    fn normalize(self) -> i8 { Normalize::<f32>::normalize(self).normalize() }
 }
 
 impl Normalize<u8> for u16 {
-    fn normalize(self) -> u8 { (self / 0x100) as u8 }
+    fn normalize(self) -> u8 { Normalize::<f32>::normalize(self).normalize() }
 }
 
 impl Normalize<i16> for u16 {
-    fn normalize(self) -> i16 { (self / 2) as i16 }
+    fn normalize(self) -> i16 { Normalize::<f32>::normalize(self).normalize() }
 }
 
 impl Normalize<u16> for u16 {
@@ -597,19 +1424,19 @@ impl Normalize<f32> for u16 {
 }
 
 impl Normalize<i8> for f32 {
-    fn normalize(self) -> i8 { (self * 127.0) as i8 }
+    fn normalize(self) -> i8 { (self.max(-1.0).min(1.0) * 127.0).round() as i8 }
 }
 
 impl Normalize<u8> for f32 {
-    fn normalize(self) -> u8 { (self.max(0.0) * 255.0) as u8 }
+    fn normalize(self) -> u8 { (self.max(0.0).min(1.0) * 255.0).round() as u8 }
 }
 
 impl Normalize<i16> for f32 {
-    fn normalize(self) -> i16 { (self * 32767.0) as i16 }
+    fn normalize(self) -> i16 { (self.max(-1.0).min(1.0) * 32767.0).round() as i16 }
 }
 
 impl Normalize<u16> for f32 {
-    fn normalize(self) -> u16 { (self.max(0.0) * 65535.0) as u16 }
+    fn normalize(self) -> u16 { (self.max(0.0).min(1.0) * 65535.0).round() as u16 }
 }
 
 impl Normalize<f32> for f32 {
@@ -0,0 +1,139 @@
+use json;
+use std::{iter, slice};
+
+use image::Image;
+use Document;
+
+/// An environment light defined by prefiltered images, as specified by the
+/// `EXT_lights_image_based` extension.
+#[derive(Clone, Debug)]
+pub struct ImageBasedLight<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON index.
+    index: usize,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::scene::ImageBasedLight,
+}
+
+/// An `Iterator` that visits every image-based light in a glTF asset.
+#[derive(Clone, Debug)]
+pub struct ImageBasedLights<'a> {
+    /// Internal light iterator.
+    pub(crate) iter: iter::Enumerate<slice::Iter<'a, json::extensions::scene::ImageBasedLight>>,
+
+    /// The internal root glTF object.
+    pub(crate) document: &'a Document,
+}
+
+/// An `Iterator` that visits the specular cubemap of each mip level of an
+/// image-based light.
+#[derive(Clone, Debug)]
+pub struct SpecularImages<'a> {
+    /// Internal cubemap iterator.
+    iter: slice::Iter<'a, [json::Index<json::image::Image>; 6]>,
+
+    /// The internal root glTF object.
+    document: &'a Document,
+}
+
+impl<'a> ImageBasedLight<'a> {
+    /// Constructs an `ImageBasedLight`.
+    pub(crate) fn new(
+        document: &'a Document,
+        index: usize,
+        json: &'a json::extensions::scene::ImageBasedLight,
+    ) -> Self {
+        Self {
+            document: document,
+            index: index,
+            json: json,
+        }
+    }
+
+    /// Returns the internal JSON index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Optional user-defined name for this object.
+    #[cfg(feature = "names")]
+    pub fn name(&self) -> Option<&str> {
+        self.json.name.as_ref().map(String::as_str)
+    }
+
+    /// Returns the `[x, y, z, w]` rotation of the environment, where `w` is
+    /// the scalar.
+    pub fn rotation(&self) -> [f32; 4] {
+        self.json.rotation.0
+    }
+
+    /// Returns the brightness multiplier for the environment.
+    ///
+    /// The default value is `1.0`.
+    pub fn intensity(&self) -> f32 {
+        self.json.intensity
+    }
+
+    /// Returns the first nine spherical harmonic coefficients of the
+    /// irradiance function, each given as an RGB triple.
+    pub fn irradiance_coefficients(&self) -> &'a [[f32; 3]; 9] {
+        &self.json.irradiance_coefficients
+    }
+
+    /// Returns the width and height in pixels of the largest specular mip
+    /// level.
+    pub fn specular_image_size(&self) -> u32 {
+        self.json.specular_image_size
+    }
+
+    /// Returns an `Iterator` that visits the specular cubemap of each mip
+    /// level, starting from the largest.
+    ///
+    /// Each cubemap lists its faces in the order +X, -X, +Y, -Y, +Z, -Z.
+    pub fn specular_images(&self) -> SpecularImages<'a> {
+        SpecularImages {
+            iter: self.json.specular_images.iter(),
+            document: self.document,
+        }
+    }
+}
+
+impl<'a> ExactSizeIterator for ImageBasedLights<'a> {}
+impl<'a> Iterator for ImageBasedLights<'a> {
+    type Item = ImageBasedLight<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|(index, json)| ImageBasedLight::new(self.document, index, json))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for SpecularImages<'a> {}
+impl<'a> Iterator for SpecularImages<'a> {
+    type Item = [Image<'a>; 6];
+    fn next(&mut self) -> Option<Self::Item> {
+        let document = self.document;
+        let face = |index: &json::Index<json::image::Image>| {
+            document.images().nth(index.value()).unwrap()
+        };
+        self.iter.next().map(|faces| [
+            face(&faces[0]),
+            face(&faces[1]),
+            face(&faces[2]),
+            face(&faces[3]),
+            face(&faces[4]),
+            face(&faces[5]),
+        ])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
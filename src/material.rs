@@ -1,4 +1,9 @@
 use {json, texture, Document};
+use image::ColorSpace;
+#[cfg(feature = "extensions")]
+use extension_value;
+#[cfg(feature = "extras")]
+use {extras_as, Result};
 
 pub use json::material::AlphaMode;
 
@@ -7,6 +12,7 @@ lazy_static! {
 }
 
 /// The material appearance of a primitive.
+#[derive(Clone, Debug)]
 pub struct Material<'a> {
     /// The parent `Document` struct.
     document: &'a Document,
@@ -43,7 +49,8 @@ impl<'a> Material<'a> {
 
     /// Returns the internal JSON index if this `Material` was explicity defined.
     ///
-    /// This function returns `None` if the `Material` is the default material.
+    /// This function returns `None` if the `Material` is the default material,
+    /// consistently with `Primitive::material_index`.
     pub fn index(&self) -> Option<usize> {
         self.index
     }
@@ -146,14 +153,230 @@ impl<'a> Material<'a> {
         self.json.emissive_factor.0
     }
 
+    /// Parameter values that define a clear coating layer on top of the
+    /// material, as specified by the `KHR_materials_clearcoat` extension.
+    ///
+    /// Returns `None` if the extension is not present.
+    #[cfg(feature = "KHR_materials_clearcoat")]
+    pub fn clearcoat(&self) -> Option<Clearcoat<'a>> {
+        self.json.extensions.clearcoat.as_ref().map(|json| {
+            Clearcoat::new(self.document, json)
+        })
+    }
+
+    /// The strength to be multiplied with the emissive factor, as specified by
+    /// the `KHR_materials_emissive_strength` extension.
+    ///
+    /// Returns `None` if the extension is not present.
+    #[cfg(feature = "KHR_materials_emissive_strength")]
+    pub fn emissive_strength(&self) -> Option<f32> {
+        self.json.extensions.emissive_strength.as_ref().map(|x| x.emissive_strength.0)
+    }
+
+    /// The index of refraction of the material, as specified by the
+    /// `KHR_materials_ior` extension.
+    ///
+    /// Returns `None` if the extension is not present, in which case the
+    /// index of refraction of the metallic-roughness model is `1.5`. Use
+    /// `ior().unwrap_or(1.5)` to apply that default.
+    ///
+    /// The index of refraction determines the reflectance of dielectrics at
+    /// normal incidence, `F0 = ((ior - 1) / (ior + 1))^2`, which is `0.04` for
+    /// the default value. A value of `0.0` is permitted as a special case of
+    /// infinite refraction, in which case `F0` is `1.0`.
+    #[cfg(feature = "KHR_materials_ior")]
+    pub fn ior(&self) -> Option<f32> {
+        self.json.extensions.ior.as_ref().map(|x| x.ior.0)
+    }
+
+    /// Parameter values that define a sheen layer on top of the material, as
+    /// seen on cloth, as specified by the `KHR_materials_sheen` extension.
+    ///
+    /// Returns `None` if the extension is not present. The defaults of the
+    /// extension, a black sheen color, disable the sheen layer.
+    #[cfg(feature = "KHR_materials_sheen")]
+    pub fn sheen(&self) -> Option<Sheen<'a>> {
+        self.json.extensions.sheen.as_ref().map(|json| {
+            Sheen::new(self.document, json)
+        })
+    }
+
+    /// Parameter values that define the strength and color of the specular
+    /// reflection of the material, as specified by the `KHR_materials_specular`
+    /// extension.
+    ///
+    /// Returns `None` if the extension is not present. The defaults of the
+    /// extension, a factor of `1.0` and a white color, leave the dielectric
+    /// specular reflectance of the metallic-roughness model unchanged.
+    #[cfg(feature = "KHR_materials_specular")]
+    pub fn specular(&self) -> Option<Specular<'a>> {
+        self.json.extensions.specular.as_ref().map(|json| {
+            Specular::new(self.document, json)
+        })
+    }
+
+    /// Parameter values that define the transmission of light through the
+    /// material, as specified by the `KHR_materials_transmission` extension.
+    ///
+    /// Returns `None` if the extension is not present.
+    #[cfg(feature = "KHR_materials_transmission")]
+    pub fn transmission(&self) -> Option<Transmission<'a>> {
+        self.json.extensions.transmission.as_ref().map(|json| {
+            Transmission::new(self.document, json)
+        })
+    }
+
+    /// Parameter values that define the volume of the material, as specified
+    /// by the `KHR_materials_volume` extension.
+    ///
+    /// Returns `None` if the extension is not present.
+    #[cfg(feature = "KHR_materials_volume")]
+    pub fn volume(&self) -> Option<Volume<'a>> {
+        self.json.extensions.volume.as_ref().map(|json| {
+            Volume::new(self.document, json)
+        })
+    }
+
+    /// Visits the textures of the material along with the color space their
+    /// color channels are encoded in. Textures of enabled material extensions
+    /// are included.
+    pub(crate) fn visit_textures<F>(&self, mut f: F)
+    where
+        F: FnMut(texture::Texture<'a>, ColorSpace),
+    {
+        use self::ColorSpace::{Linear, Srgb};
+
+        let mut visit = |texture: Option<texture::Texture<'a>>, color_space| {
+            if let Some(texture) = texture {
+                f(texture, color_space);
+            }
+        };
+
+        let pbr = self.pbr_metallic_roughness();
+        visit(pbr.base_color_texture().map(|info| info.texture()), Srgb);
+        visit(pbr.metallic_roughness_texture().map(|info| info.texture()), Linear);
+        visit(self.normal_texture().map(|info| info.texture()), Linear);
+        visit(self.occlusion_texture().map(|info| info.texture()), Linear);
+        visit(self.emissive_texture().map(|info| info.texture()), Srgb);
+        #[cfg(feature = "KHR_materials_clearcoat")]
+        {
+            if let Some(clearcoat) = self.clearcoat() {
+                visit(clearcoat.clearcoat_texture().map(|info| info.texture()), Linear);
+                visit(clearcoat.clearcoat_roughness_texture().map(|info| info.texture()), Linear);
+                visit(clearcoat.clearcoat_normal_texture().map(|info| info.texture()), Linear);
+            }
+        }
+        #[cfg(feature = "KHR_materials_sheen")]
+        {
+            if let Some(sheen) = self.sheen() {
+                visit(sheen.sheen_color_texture().map(|info| info.texture()), Srgb);
+                visit(sheen.sheen_roughness_texture().map(|info| info.texture()), Linear);
+            }
+        }
+        #[cfg(feature = "KHR_materials_specular")]
+        {
+            if let Some(specular) = self.specular() {
+                visit(specular.specular_texture().map(|info| info.texture()), Linear);
+                visit(specular.specular_color_texture().map(|info| info.texture()), Srgb);
+            }
+        }
+        #[cfg(feature = "KHR_materials_transmission")]
+        {
+            if let Some(transmission) = self.transmission() {
+                visit(transmission.transmission_texture().map(|info| info.texture()), Linear);
+            }
+        }
+        #[cfg(feature = "KHR_materials_volume")]
+        {
+            if let Some(volume) = self.volume() {
+                visit(volume.thickness_texture().map(|info| info.texture()), Linear);
+            }
+        }
+    }
+
+    /// Returns the `TEXCOORD` set indices referenced by the textures of the
+    /// material, in ascending order and without duplicates.
+    ///
+    /// The length of the result is the number of texture co-ordinate streams
+    /// a renderer needs to upload for primitives using this material, each of
+    /// which may be read with `Primitive::read_tex_coords`. Textures of
+    /// enabled material extensions are included.
+    pub fn used_tex_coord_sets(&self) -> Vec<u32> {
+        let pbr = self.pbr_metallic_roughness();
+        let mut sets = vec![];
+        sets.extend(pbr.base_color_texture().map(|info| info.tex_coord()));
+        sets.extend(pbr.metallic_roughness_texture().map(|info| info.tex_coord()));
+        sets.extend(self.normal_texture().map(|info| info.tex_coord()));
+        sets.extend(self.occlusion_texture().map(|info| info.tex_coord()));
+        sets.extend(self.emissive_texture().map(|info| info.tex_coord()));
+        #[cfg(feature = "KHR_materials_clearcoat")]
+        {
+            if let Some(clearcoat) = self.clearcoat() {
+                sets.extend(clearcoat.clearcoat_texture().map(|info| info.tex_coord()));
+                sets.extend(clearcoat.clearcoat_roughness_texture().map(|info| info.tex_coord()));
+                sets.extend(clearcoat.clearcoat_normal_texture().map(|info| info.tex_coord()));
+            }
+        }
+        #[cfg(feature = "KHR_materials_sheen")]
+        {
+            if let Some(sheen) = self.sheen() {
+                sets.extend(sheen.sheen_color_texture().map(|info| info.tex_coord()));
+                sets.extend(sheen.sheen_roughness_texture().map(|info| info.tex_coord()));
+            }
+        }
+        #[cfg(feature = "KHR_materials_specular")]
+        {
+            if let Some(specular) = self.specular() {
+                sets.extend(specular.specular_texture().map(|info| info.tex_coord()));
+                sets.extend(specular.specular_color_texture().map(|info| info.tex_coord()));
+            }
+        }
+        #[cfg(feature = "KHR_materials_transmission")]
+        {
+            if let Some(transmission) = self.transmission() {
+                sets.extend(transmission.transmission_texture().map(|info| info.tex_coord()));
+            }
+        }
+        #[cfg(feature = "KHR_materials_volume")]
+        {
+            if let Some(volume) = self.volume() {
+                sets.extend(volume.thickness_texture().map(|info| info.tex_coord()));
+            }
+        }
+        sets.sort();
+        sets.dedup();
+        sets
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &json::Extras {
         &self.json.extras
     }
+
+    /// Deserializes the application specific data into a user type.
+    ///
+    /// Returns `None` if there is no application specific data.
+    #[cfg(feature = "extras")]
+    pub fn extras_as<T>(&self) -> Option<Result<T>>
+    where
+        T: json::deserialize::DeserializeOwned,
+    {
+        extras_as(&self.json.extras)
+    }
+
+    /// Queries the JSON of an extension by name.
+    ///
+    /// Extensions that are parsed into typed fields are serialized back to
+    /// JSON. Returns `None` if the extension is not present.
+    #[cfg(feature = "extensions")]
+    pub fn extension_value(&self, name: &str) -> Option<json::Value> {
+        extension_value(&self.json.extensions, name)
+    }
 }
 
 /// A set of parameter values that are used to define the metallic-roughness
 /// material model from Physically-Based Rendering (PBR) methodology.
+#[derive(Clone, Debug)]
 pub struct PbrMetallicRoughness<'a> {
     /// The parent `Document` struct.
     document: &'a Document,
@@ -225,7 +448,304 @@ impl<'a> PbrMetallicRoughness<'a> {
     }
 }
 
+/// Parameter values that define a clear coating layer on top of a material.
+#[cfg(feature = "KHR_materials_clearcoat")]
+#[derive(Clone, Debug)]
+pub struct Clearcoat<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::material::Clearcoat,
+}
+
+#[cfg(feature = "KHR_materials_clearcoat")]
+impl<'a> Clearcoat<'a> {
+    /// Constructs `Clearcoat`.
+    pub(crate) fn new(
+        document: &'a Document,
+        json: &'a json::extensions::material::Clearcoat,
+    ) -> Self {
+        Self {
+            document: document,
+            json: json,
+        }
+    }
+
+    /// Returns the clearcoat layer intensity.
+    ///
+    /// The default value is `0.0`.
+    pub fn clearcoat_factor(&self) -> f32 {
+        self.json.clearcoat_factor.0
+    }
+
+    /// The clearcoat layer intensity texture.
+    ///
+    /// The intensity is sampled from the R channel and multiplied by the
+    /// clearcoat factor.
+    pub fn clearcoat_texture(&self) -> Option<texture::Info<'a>> {
+        self.json.clearcoat_texture.as_ref().map(|json| {
+            let texture = self.document.textures().nth(json.index.value()).unwrap();
+            texture::Info::new(texture, json)
+        })
+    }
+
+    /// Returns the clearcoat layer roughness.
+    ///
+    /// The default value is `0.0`.
+    pub fn clearcoat_roughness_factor(&self) -> f32 {
+        self.json.clearcoat_roughness_factor.0
+    }
+
+    /// The clearcoat layer roughness texture.
+    ///
+    /// The roughness is sampled from the G channel and multiplied by the
+    /// clearcoat roughness factor.
+    pub fn clearcoat_roughness_texture(&self) -> Option<texture::Info<'a>> {
+        self.json.clearcoat_roughness_texture.as_ref().map(|json| {
+            let texture = self.document.textures().nth(json.index.value()).unwrap();
+            texture::Info::new(texture, json)
+        })
+    }
+
+    /// The clearcoat layer normal map texture.
+    pub fn clearcoat_normal_texture(&self) -> Option<NormalTexture<'a>> {
+        self.json.clearcoat_normal_texture.as_ref().map(|json| {
+            let texture = self.document.textures().nth(json.index.value()).unwrap();
+            NormalTexture::new(texture, json)
+        })
+    }
+}
+
+/// Parameter values that define a sheen layer on top of a material.
+#[cfg(feature = "KHR_materials_sheen")]
+#[derive(Clone, Debug)]
+pub struct Sheen<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::material::Sheen,
+}
+
+#[cfg(feature = "KHR_materials_sheen")]
+impl<'a> Sheen<'a> {
+    /// Constructs `Sheen`.
+    pub(crate) fn new(
+        document: &'a Document,
+        json: &'a json::extensions::material::Sheen,
+    ) -> Self {
+        Self {
+            document: document,
+            json: json,
+        }
+    }
+
+    /// Returns the sheen color in linear space.
+    ///
+    /// The default value is `[0.0, 0.0, 0.0]`.
+    pub fn sheen_color_factor(&self) -> [f32; 3] {
+        self.json.sheen_color_factor.0
+    }
+
+    /// The sheen color texture.
+    ///
+    /// The sheen color is sampled from the RGB channels in sRGB color space
+    /// and multiplied by the sheen color factor.
+    pub fn sheen_color_texture(&self) -> Option<texture::Info<'a>> {
+        self.json.sheen_color_texture.as_ref().map(|json| {
+            let texture = self.document.textures().nth(json.index.value()).unwrap();
+            texture::Info::new(texture, json)
+        })
+    }
+
+    /// Returns the sheen roughness.
+    ///
+    /// The default value is `0.0`.
+    pub fn sheen_roughness_factor(&self) -> f32 {
+        self.json.sheen_roughness_factor.0
+    }
+
+    /// The sheen roughness texture.
+    ///
+    /// The sheen roughness is sampled from the A channel and multiplied by
+    /// the sheen roughness factor.
+    pub fn sheen_roughness_texture(&self) -> Option<texture::Info<'a>> {
+        self.json.sheen_roughness_texture.as_ref().map(|json| {
+            let texture = self.document.textures().nth(json.index.value()).unwrap();
+            texture::Info::new(texture, json)
+        })
+    }
+}
+
+/// Parameter values that define the specular reflection of a material.
+#[cfg(feature = "KHR_materials_specular")]
+#[derive(Clone, Debug)]
+pub struct Specular<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::material::Specular,
+}
+
+#[cfg(feature = "KHR_materials_specular")]
+impl<'a> Specular<'a> {
+    /// Constructs `Specular`.
+    pub(crate) fn new(
+        document: &'a Document,
+        json: &'a json::extensions::material::Specular,
+    ) -> Self {
+        Self {
+            document: document,
+            json: json,
+        }
+    }
+
+    /// Returns the strength of the specular reflection.
+    ///
+    /// The default value is `1.0`.
+    pub fn specular_factor(&self) -> f32 {
+        self.json.specular_factor.0
+    }
+
+    /// The specular strength texture.
+    ///
+    /// The strength is sampled from the A channel and multiplied by the
+    /// specular factor.
+    pub fn specular_texture(&self) -> Option<texture::Info<'a>> {
+        self.json.specular_texture.as_ref().map(|json| {
+            let texture = self.document.textures().nth(json.index.value()).unwrap();
+            texture::Info::new(texture, json)
+        })
+    }
+
+    /// Returns the F0 color of the specular reflection in linear space.
+    ///
+    /// The default value is `[1.0, 1.0, 1.0]`.
+    pub fn specular_color_factor(&self) -> [f32; 3] {
+        self.json.specular_color_factor.0
+    }
+
+    /// The specular color texture.
+    ///
+    /// The color is sampled from the RGB channels in sRGB color space and
+    /// multiplied by the specular color factor.
+    pub fn specular_color_texture(&self) -> Option<texture::Info<'a>> {
+        self.json.specular_color_texture.as_ref().map(|json| {
+            let texture = self.document.textures().nth(json.index.value()).unwrap();
+            texture::Info::new(texture, json)
+        })
+    }
+}
+
+/// Parameter values that define the transmission of light through a material.
+#[cfg(feature = "KHR_materials_transmission")]
+#[derive(Clone, Debug)]
+pub struct Transmission<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::material::Transmission,
+}
+
+#[cfg(feature = "KHR_materials_transmission")]
+impl<'a> Transmission<'a> {
+    /// Constructs `Transmission`.
+    pub(crate) fn new(
+        document: &'a Document,
+        json: &'a json::extensions::material::Transmission,
+    ) -> Self {
+        Self {
+            document: document,
+            json: json,
+        }
+    }
+
+    /// Returns the base percentage of light transmitted through the surface.
+    ///
+    /// The default value is `0.0`.
+    pub fn transmission_factor(&self) -> f32 {
+        self.json.transmission_factor.0
+    }
+
+    /// The transmission texture.
+    ///
+    /// The transmission percentage is sampled from the R channel and
+    /// multiplied by the transmission factor.
+    pub fn transmission_texture(&self) -> Option<texture::Info<'a>> {
+        self.json.transmission_texture.as_ref().map(|json| {
+            let texture = self.document.textures().nth(json.index.value()).unwrap();
+            texture::Info::new(texture, json)
+        })
+    }
+}
+
+/// Parameter values that define the volume beneath the surface of a material.
+#[cfg(feature = "KHR_materials_volume")]
+#[derive(Clone, Debug)]
+pub struct Volume<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::material::Volume,
+}
+
+#[cfg(feature = "KHR_materials_volume")]
+impl<'a> Volume<'a> {
+    /// Constructs `Volume`.
+    pub(crate) fn new(
+        document: &'a Document,
+        json: &'a json::extensions::material::Volume,
+    ) -> Self {
+        Self {
+            document: document,
+            json: json,
+        }
+    }
+
+    /// Returns the thickness of the volume in the coordinate space of the
+    /// mesh.
+    ///
+    /// The default value is `0.0`.
+    pub fn thickness_factor(&self) -> f32 {
+        self.json.thickness_factor.0
+    }
+
+    /// The thickness texture.
+    ///
+    /// The thickness is sampled from the G channel and multiplied by the
+    /// thickness factor.
+    pub fn thickness_texture(&self) -> Option<texture::Info<'a>> {
+        self.json.thickness_texture.as_ref().map(|json| {
+            let texture = self.document.textures().nth(json.index.value()).unwrap();
+            texture::Info::new(texture, json)
+        })
+    }
+
+    /// Returns the average distance light travels in the medium before
+    /// interacting with a particle.
+    ///
+    /// The default value is infinity.
+    pub fn attenuation_distance(&self) -> f32 {
+        self.json.attenuation_distance
+            .map(|x| x.0)
+            .unwrap_or(::std::f32::INFINITY)
+    }
+
+    /// Returns the color white light turns into due to absorption when
+    /// reaching the attenuation distance.
+    ///
+    /// The default value is `[1.0, 1.0, 1.0]`.
+    pub fn attenuation_color(&self) -> [f32; 3] {
+        self.json.attenuation_color.0
+    }
+}
+
 /// Defines the normal texture of a material.
+#[derive(Clone, Debug)]
 pub struct NormalTexture<'a> {
     /// The parent `Texture` struct.
     texture: texture::Texture<'a>,
@@ -268,6 +788,7 @@ impl<'a> NormalTexture<'a> {
 }
 
 /// Defines the occlusion texture of a material.
+#[derive(Clone, Debug)]
 pub struct OcclusionTexture<'a> {
     /// The parent `Texture` struct.
     texture: texture::Texture<'a>,
@@ -0,0 +1,266 @@
+use json;
+use json::Index;
+
+/// The lengths of the arrays of the destination document, by which every
+/// index of the merged document is shifted.
+struct Offsets {
+    accessors: u32,
+    buffers: u32,
+    cameras: u32,
+    images: u32,
+    materials: u32,
+    meshes: u32,
+    nodes: u32,
+    samplers: u32,
+    scenes: u32,
+    skins: u32,
+    textures: u32,
+    views: u32,
+    #[cfg(feature = "EXT_lights_image_based")]
+    lights: u32,
+}
+
+impl Offsets {
+    fn new(root: &json::Root) -> Self {
+        Offsets {
+            accessors: root.accessors.len() as u32,
+            buffers: root.buffers.len() as u32,
+            cameras: root.cameras.len() as u32,
+            images: root.images.len() as u32,
+            materials: root.materials.len() as u32,
+            meshes: root.meshes.len() as u32,
+            nodes: root.nodes.len() as u32,
+            samplers: root.samplers.len() as u32,
+            scenes: root.scenes.len() as u32,
+            skins: root.skins.len() as u32,
+            textures: root.textures.len() as u32,
+            views: root.buffer_views.len() as u32,
+            #[cfg(feature = "EXT_lights_image_based")]
+            lights: root.extensions.lights_image_based
+                .as_ref()
+                .map_or(0, |extension| extension.lights.len() as u32),
+        }
+    }
+}
+
+/// Offsets an index by `offset`.
+fn shift<T>(index: &mut Index<T>, offset: u32) {
+    *index = Index::new(index.value() as u32 + offset);
+}
+
+/// Offsets the index of a texture reference.
+fn shift_texture(info: &mut Option<json::texture::Info>, offsets: &Offsets) {
+    if let Some(info) = info.as_mut() {
+        shift(&mut info.index, offsets.textures);
+    }
+}
+
+/// Appends the contents of `other` to `root`, remapping every index of
+/// `other` to refer to its objects' new positions.
+pub(crate) fn merge(root: &mut json::Root, mut other: json::Root) {
+    let offsets = Offsets::new(root);
+
+    for accessor in &mut other.accessors {
+        if let Some(view) = accessor.buffer_view.as_mut() {
+            shift(view, offsets.views);
+        }
+        if let Some(sparse) = accessor.sparse.as_mut() {
+            shift(&mut sparse.indices.buffer_view, offsets.views);
+            shift(&mut sparse.values.buffer_view, offsets.views);
+        }
+    }
+
+    for animation in &mut other.animations {
+        for channel in &mut animation.channels {
+            shift(&mut channel.target.node, offsets.nodes);
+        }
+        for sampler in &mut animation.samplers {
+            shift(&mut sampler.input, offsets.accessors);
+            shift(&mut sampler.output, offsets.accessors);
+        }
+    }
+
+    for view in &mut other.buffer_views {
+        shift(&mut view.buffer, offsets.buffers);
+    }
+
+    for image in &mut other.images {
+        if let Some(view) = image.buffer_view.as_mut() {
+            shift(view, offsets.views);
+        }
+    }
+
+    for material in &mut other.materials {
+        let pbr = &mut material.pbr_metallic_roughness;
+        shift_texture(&mut pbr.base_color_texture, &offsets);
+        shift_texture(&mut pbr.metallic_roughness_texture, &offsets);
+        if let Some(normal) = material.normal_texture.as_mut() {
+            shift(&mut normal.index, offsets.textures);
+        }
+        if let Some(occlusion) = material.occlusion_texture.as_mut() {
+            shift(&mut occlusion.index, offsets.textures);
+        }
+        shift_texture(&mut material.emissive_texture, &offsets);
+        #[cfg(feature = "KHR_materials_clearcoat")]
+        {
+            if let Some(clearcoat) = material.extensions.clearcoat.as_mut() {
+                shift_texture(&mut clearcoat.clearcoat_texture, &offsets);
+                shift_texture(&mut clearcoat.clearcoat_roughness_texture, &offsets);
+                if let Some(normal) = clearcoat.clearcoat_normal_texture.as_mut() {
+                    shift(&mut normal.index, offsets.textures);
+                }
+            }
+        }
+        #[cfg(feature = "KHR_materials_sheen")]
+        {
+            if let Some(sheen) = material.extensions.sheen.as_mut() {
+                shift_texture(&mut sheen.sheen_color_texture, &offsets);
+                shift_texture(&mut sheen.sheen_roughness_texture, &offsets);
+            }
+        }
+        #[cfg(feature = "KHR_materials_specular")]
+        {
+            if let Some(specular) = material.extensions.specular.as_mut() {
+                shift_texture(&mut specular.specular_texture, &offsets);
+                shift_texture(&mut specular.specular_color_texture, &offsets);
+            }
+        }
+        #[cfg(feature = "KHR_materials_transmission")]
+        {
+            if let Some(transmission) = material.extensions.transmission.as_mut() {
+                shift_texture(&mut transmission.transmission_texture, &offsets);
+            }
+        }
+        #[cfg(feature = "KHR_materials_volume")]
+        {
+            if let Some(volume) = material.extensions.volume.as_mut() {
+                shift_texture(&mut volume.thickness_texture, &offsets);
+            }
+        }
+    }
+
+    for mesh in &mut other.meshes {
+        for primitive in &mut mesh.primitives {
+            for accessor in primitive.attributes.values_mut() {
+                shift(accessor, offsets.accessors);
+            }
+            if let Some(indices) = primitive.indices.as_mut() {
+                shift(indices, offsets.accessors);
+            }
+            if let Some(material) = primitive.material.as_mut() {
+                shift(material, offsets.materials);
+            }
+            for target in primitive.targets.iter_mut().flat_map(|targets| targets.iter_mut()) {
+                let accessors = target.positions
+                    .iter_mut()
+                    .chain(target.normals.iter_mut())
+                    .chain(target.tangents.iter_mut());
+                for accessor in accessors {
+                    shift(accessor, offsets.accessors);
+                }
+            }
+        }
+    }
+
+    for node in &mut other.nodes {
+        if let Some(camera) = node.camera.as_mut() {
+            shift(camera, offsets.cameras);
+        }
+        for child in node.children.iter_mut().flat_map(|children| children.iter_mut()) {
+            shift(child, offsets.nodes);
+        }
+        if let Some(mesh) = node.mesh.as_mut() {
+            shift(mesh, offsets.meshes);
+        }
+        if let Some(skin) = node.skin.as_mut() {
+            shift(skin, offsets.skins);
+        }
+    }
+
+    for scene in &mut other.scenes {
+        for node in &mut scene.nodes {
+            shift(node, offsets.nodes);
+        }
+        #[cfg(feature = "EXT_lights_image_based")]
+        {
+            if let Some(extension) = scene.extensions.lights_image_based.as_mut() {
+                shift(&mut extension.light, offsets.lights);
+            }
+        }
+    }
+
+    for skin in &mut other.skins {
+        if let Some(accessor) = skin.inverse_bind_matrices.as_mut() {
+            shift(accessor, offsets.accessors);
+        }
+        for joint in &mut skin.joints {
+            shift(joint, offsets.nodes);
+        }
+        if let Some(skeleton) = skin.skeleton.as_mut() {
+            shift(skeleton, offsets.nodes);
+        }
+    }
+
+    for texture in &mut other.textures {
+        if let Some(sampler) = texture.sampler.as_mut() {
+            shift(sampler, offsets.samplers);
+        }
+        if let Some(source) = texture.source.as_mut() {
+            shift(source, offsets.images);
+        }
+        #[cfg(feature = "KHR_texture_basisu")]
+        {
+            if let Some(basisu) = texture.extensions.texture_basisu.as_mut() {
+                shift(&mut basisu.source, offsets.images);
+            }
+        }
+    }
+
+    #[cfg(feature = "EXT_lights_image_based")]
+    {
+        if let Some(extension) = other.extensions.lights_image_based.take() {
+            let mut lights = extension.lights;
+            for light in &mut lights {
+                for face in light.specular_images.iter_mut().flat_map(|cubemap| cubemap.iter_mut()) {
+                    shift(face, offsets.images);
+                }
+            }
+            root.extensions.lights_image_based
+                .get_or_insert_with(Default::default)
+                .lights
+                .extend(lights);
+        }
+    }
+
+    if root.scene.is_none() {
+        root.scene = other.scene.map(|mut scene| {
+            shift(&mut scene, offsets.scenes);
+            scene
+        });
+    }
+
+    for name in other.extensions_used {
+        if !root.extensions_used.contains(&name) {
+            root.extensions_used.push(name);
+        }
+    }
+    for name in other.extensions_required {
+        if !root.extensions_required.contains(&name) {
+            root.extensions_required.push(name);
+        }
+    }
+
+    root.accessors.extend(other.accessors);
+    root.animations.extend(other.animations);
+    root.buffers.extend(other.buffers);
+    root.buffer_views.extend(other.buffer_views);
+    root.cameras.extend(other.cameras);
+    root.images.extend(other.images);
+    root.materials.extend(other.materials);
+    root.meshes.extend(other.meshes);
+    root.nodes.extend(other.nodes);
+    root.samplers.extend(other.samplers);
+    root.scenes.extend(other.scenes);
+    root.skins.extend(other.skins);
+    root.textures.extend(other.textures);
+}
@@ -1,4 +1,4 @@
-use std::{collections, iter, slice};
+use std::{iter, slice, vec};
 use json;
 
 use super::{Attribute, Mesh, MorphTarget, Primitive};
@@ -23,12 +23,11 @@ pub struct Attributes<'a> {
     /// The parent `Primitive` struct.
     pub(crate) prim: &'a Primitive<'a>,
 
-    /// The internal attribute iterator.
-    pub(crate) iter: collections::hash_map::Iter<
-            'a,
-        json::validation::Checked<json::mesh::Semantic>,
-        json::Index<json::accessor::Accessor>,
-        >,
+    /// The internal attribute iterator, sorted by semantic.
+    pub(crate) iter: vec::IntoIter<(
+        &'a json::validation::Checked<json::mesh::Semantic>,
+        &'a json::Index<json::accessor::Accessor>,
+    )>,
 }
 
 /// An `Iterator` that visits the primitives of a `Mesh`.
@@ -56,10 +56,20 @@ pub mod iter;
 pub mod util;
 
 use json;
-use {Accessor, Buffer, Document, Material};
+use {Accessor, Buffer, Document, Material, Normalize};
+#[cfg(feature = "extensions")]
+use extension_value;
+#[cfg(feature = "extras")]
+use extras_as;
+#[cfg(any(feature = "extras", feature = "utils"))]
+use Result;
 
 #[cfg(feature = "utils")]
 use accessor;
+#[cfg(feature = "utils")]
+use accessor::util::Source;
+#[cfg(feature = "utils")]
+use Error;
 
 pub use json::mesh::{Mode, Semantic};
 use json::validation::Checked;
@@ -153,6 +163,26 @@ impl<'a> Mesh<'a>  {
         &self.json.extras
     }
 
+    /// Deserializes the application specific data into a user type.
+    ///
+    /// Returns `None` if there is no application specific data.
+    #[cfg(feature = "extras")]
+    pub fn extras_as<T>(&self) -> Option<Result<T>>
+    where
+        T: json::deserialize::DeserializeOwned,
+    {
+        extras_as(&self.json.extras)
+    }
+
+    /// Queries the JSON of an extension by name.
+    ///
+    /// Extensions that are parsed into typed fields are serialized back to
+    /// JSON. Returns `None` if the extension is not present.
+    #[cfg(feature = "extensions")]
+    pub fn extension_value(&self, name: &str) -> Option<json::Value> {
+        extension_value(&self.json.extensions, name)
+    }
+
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     pub fn name(&self) -> Option<&str> {
@@ -167,12 +197,54 @@ impl<'a> Mesh<'a>  {
         }
     }
 
+    /// Returns the number of triangles rendered by the primitives of the
+    /// mesh in the `Triangles`, `TriangleStrip`, and `TriangleFan` modes.
+    ///
+    /// The count is derived from the number of indices, or of vertices for
+    /// non-indexed primitives, without reading buffer data. Degenerate
+    /// triangles are counted.
+    pub fn triangle_count(&self) -> usize {
+        let accessors = &self.document.0.accessors;
+        self.json.primitives
+            .iter()
+            .filter(|primitive| primitive.mode.unwrap().is_triangles())
+            .map(|primitive| {
+                let count = match primitive.indices {
+                    Some(indices) => accessors[indices.value()].count as usize,
+                    None => primitive_vertex_count(accessors, primitive),
+                };
+                primitive.mode.unwrap().primitive_count(count)
+            })
+            .sum()
+    }
+
+    /// Returns the number of vertices of the primitives of the mesh, that is
+    /// the sum of the counts of their `POSITION` accessors.
+    ///
+    /// Vertices shared by primitives through the same accessor are counted
+    /// once per primitive.
+    pub fn vertex_count(&self) -> usize {
+        let accessors = &self.document.0.accessors;
+        self.json.primitives
+            .iter()
+            .map(|primitive| primitive_vertex_count(accessors, primitive))
+            .sum()
+    }
+
     /// Defines the weights to be applied to the morph targets.
-    pub fn weights(&self) -> Option<&[f32]> {
+    pub fn weights(&self) -> Option<&'a [f32]> {
         self.json.weights.as_ref().map(Vec::as_slice)
     }
 }
 
+/// Returns the count of the `POSITION` accessor of a primitive, or zero if it
+/// has no positions.
+fn primitive_vertex_count(accessors: &[json::Accessor], primitive: &json::mesh::Primitive) -> usize {
+    primitive.attributes
+        .get(&Checked::Valid(Semantic::Positions))
+        .map_or(0, |index| accessors[index.value()].count as usize)
+}
+
 impl<'a> Primitive<'a> {
     /// Constructs a `Primitive`.
     pub(crate) fn new(
@@ -188,13 +260,21 @@ impl<'a> Primitive<'a> {
     }
 
     /// Returns the bounds of the `POSITION` vertex attribute.
+    ///
+    /// The bounds of normalized integer positions, as permitted by
+    /// `KHR_mesh_quantization`, are normalized like the positions themselves.
     pub fn bounding_box(&self) -> BoundingBox {
         // NOTE: cannot panic if validated "minimally"
         let pos_accessor_index = self.json.attributes.get(&Checked::Valid(Semantic::Positions)).unwrap();
         let pos_accessor = self.mesh.document.accessors().nth(pos_accessor_index.value()).unwrap();
         let min: [f32; 3] = json::deserialize::from_value(pos_accessor.min().unwrap()).unwrap();
         let max: [f32; 3] = json::deserialize::from_value(pos_accessor.max().unwrap()).unwrap();
-        Bounds { min, max }
+        if pos_accessor.normalized() {
+            let data_type = pos_accessor.data_type();
+            Bounds { min: normalize_bound(data_type, min), max: normalize_bound(data_type, max) }
+        } else {
+            Bounds { min, max }
+        }
     }
 
     /// Optional application specific data.
@@ -202,6 +282,26 @@ impl<'a> Primitive<'a> {
         &self.json.extras
     }
 
+    /// Deserializes the application specific data into a user type.
+    ///
+    /// Returns `None` if there is no application specific data.
+    #[cfg(feature = "extras")]
+    pub fn extras_as<T>(&self) -> Option<Result<T>>
+    where
+        T: json::deserialize::DeserializeOwned,
+    {
+        extras_as(&self.json.extras)
+    }
+
+    /// Queries the JSON of an extension by name.
+    ///
+    /// Extensions that are parsed into typed fields are serialized back to
+    /// JSON. Returns `None` if the extension is not present.
+    #[cfg(feature = "extensions")]
+    pub fn extension_value(&self, name: &str) -> Option<json::Value> {
+        extension_value(&self.json.extensions, name)
+    }
+
     /// Return the accessor with the given semantic.
     pub fn get(&self, semantic: &Semantic) -> Option<Accessor> {
         self.json.attributes
@@ -215,22 +315,54 @@ impl<'a> Primitive<'a> {
     }
 
     /// Returns the accessor containing the primitive indices, if provided.
+    ///
+    /// The accessor describes where the indices are stored rather than their
+    /// values, which is useful for sharing index buffers between primitives
+    /// that reference the same buffer view. Use [`Reader::read_indices`] to
+    /// decode the indices instead.
+    ///
+    /// [`Reader::read_indices`]: struct.Reader.html#method.read_indices
     pub fn indices(&self) -> Option<Accessor> {
         self.json.indices
             .as_ref()
             .map(|index| self.mesh.document.accessors().nth(index.value()).unwrap())
     }
 
-    /// Returns an `Iterator` that visits the vertex attributes.
+    /// Returns an `Iterator` that visits the vertex attributes and the
+    /// accessors containing their values.
+    ///
+    /// Attributes are visited in the order of their semantics, that is
+    /// `POSITION`, `NORMAL`, `TANGENT`, then `COLOR_n`, `TEXCOORD_n`,
+    /// `JOINTS_n`, and `WEIGHTS_n` by increasing set index. When the `extras`
+    /// feature is enabled, application specific attributes are visited last
+    /// in order of name. The order in the JSON document is not retained.
     pub fn attributes(&self) -> iter::Attributes {
+        let mut attributes: Vec<_> = self.json.attributes.iter().collect();
+        attributes.sort_by_key(|&(semantic, _)| semantic);
         iter::Attributes {
             document: self.mesh.document,
             prim: self,
-            iter: self.json.attributes.iter(),
+            iter: attributes.into_iter(),
         }
     }
 
-    /// Returns the material to apply to this primitive when rendering
+    /// Returns the number of vertex attributes of the primitive.
+    pub fn attribute_count(&self) -> usize {
+        self.json.attributes.len()
+    }
+
+    /// Returns `true` if the primitive has a vertex attribute with the given
+    /// semantic, for example `Semantic::TexCoords(1)` for `TEXCOORD_1`.
+    ///
+    /// Unlike `get`, this does not look up the accessor.
+    pub fn has_attribute(&self, semantic: &Semantic) -> bool {
+        self.json.attributes.contains_key(&Checked::Valid(semantic.clone()))
+    }
+
+    /// Returns the material to apply to this primitive when rendering.
+    ///
+    /// When the primitive has no material, the default material is returned,
+    /// whose `index()` is `None`. See `material_index`.
     pub fn material(&self) -> Material {
         self.json.material
             .as_ref()
@@ -238,6 +370,16 @@ impl<'a> Primitive<'a> {
             .unwrap_or_else(|| Material::default(self.mesh.document))
     }
 
+    /// Returns the index of the material of this primitive, or `None` if the
+    /// primitive uses the default material.
+    ///
+    /// This is equal to `material().index()`, and is suitable as the key of
+    /// a material cache as the default material never shares an index with a
+    /// material of the document.
+    pub fn material_index(&self) -> Option<usize> {
+        self.json.material.as_ref().map(|index| index.value())
+    }
+
     /// The type of primitives to render.
     pub fn mode(&self) -> Mode {
         self.json.mode.unwrap()
@@ -259,6 +401,11 @@ impl<'a> Primitive<'a> {
     }
 
     /// Constructs the primitive reader.
+    ///
+    /// Validation guarantees that every attribute accessor of the primitive
+    /// has the same `count` as `POSITION`, so the attribute iterators yield
+    /// aligned vertices. This does not hold for documents loaded without
+    /// validation.
     #[cfg(feature = "utils")]
     pub fn reader<'s, F>(
         &'a self,
@@ -276,20 +423,46 @@ impl<'a, 's, F> Reader<'a, 's, F>
     where F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
 {
     /// Visits the vertex positions of a primitive.
+    ///
+    /// Quantized positions are converted to `f32`, see `ReadPositions`.
     pub fn read_positions(&self) -> Option<util::ReadPositions<'s>> {
+        use accessor::DataType;
+        use self::util::ReadPositions;
+
         if let Some(accessor) = self.primitive.get(&Semantic::Positions) {
-            if let Some(slice) = (self.get_buffer_data)(accessor.clone().view().buffer()) {
-                return Some(accessor::Iter::new(accessor, slice))
+            if let Ok(source) = Source::new(&accessor, &self.get_buffer_data) {
+                return Some(
+                    match accessor.data_type() {
+                        DataType::I8  => ReadPositions::I8(accessor::Iter::from_source(accessor, source)),
+                        DataType::U8  => ReadPositions::U8(accessor::Iter::from_source(accessor, source)),
+                        DataType::I16 => ReadPositions::I16(accessor::Iter::from_source(accessor, source)),
+                        DataType::U16 => ReadPositions::U16(accessor::Iter::from_source(accessor, source)),
+                        DataType::F32 => ReadPositions::F32(accessor::Iter::from_source(accessor, source)),
+                        _ => unreachable!(),
+                    }
+                )
             }
         }
         None
     }
 
     /// Visits the vertex normals of a primitive.
+    ///
+    /// Quantized normals are converted to `f32`, see `ReadNormals`.
     pub fn read_normals(&self) -> Option<util::ReadNormals<'s>> {
+        use accessor::DataType;
+        use self::util::ReadNormals;
+
         if let Some(accessor) = self.primitive.get(&Semantic::Normals) {
-            if let Some(slice) = (self.get_buffer_data)(accessor.clone().view().buffer()) {
-                return Some(accessor::Iter::new(accessor, slice))
+            if let Ok(source) = Source::new(&accessor, &self.get_buffer_data) {
+                return Some(
+                    match accessor.data_type() {
+                        DataType::I8  => ReadNormals::I8(accessor::Iter::from_source(accessor, source)),
+                        DataType::I16 => ReadNormals::I16(accessor::Iter::from_source(accessor, source)),
+                        DataType::F32 => ReadNormals::F32(accessor::Iter::from_source(accessor, source)),
+                        _ => unreachable!(),
+                    }
+                )
             }
         }
         None
@@ -297,30 +470,44 @@ impl<'a, 's, F> Reader<'a, 's, F>
 
     /// Visits the vertex tangents of a primitive.
     pub fn read_tangents(&self) -> Option<util::ReadTangents<'s>> {
+        use accessor::DataType;
+        use self::util::ReadTangents;
+
         if let Some(accessor) = self.primitive.get(&Semantic::Tangents) {
-            if let Some(slice) = (self.get_buffer_data)(accessor.clone().view().buffer()) {
-                return Some(accessor::Iter::new(accessor, slice))
+            if let Ok(source) = Source::new(&accessor, &self.get_buffer_data) {
+                return Some(
+                    match accessor.data_type() {
+                        DataType::I8  => ReadTangents::I8(accessor::Iter::from_source(accessor, source)),
+                        DataType::I16 => ReadTangents::I16(accessor::Iter::from_source(accessor, source)),
+                        DataType::F32 => ReadTangents::F32(accessor::Iter::from_source(accessor, source)),
+                        _ => unreachable!(),
+                    }
+                )
             }
         }
         None
     }
 
     /// Visits the vertex colors of a primitive.
+    ///
+    /// Colors are read as linear, as required by the specification. For
+    /// assets known to contain sRGB encoded colors, the casting iterators
+    /// provide `srgb_to_linear` to opt into conversion.
     pub fn read_colors(&self, set: u32) -> Option<util::ReadColors<'s>> {
         use accessor::DataType::{U8, U16, F32};
         use accessor::Dimensions::{Vec3, Vec4};
         use self::util::ReadColors;
 
         if let Some(accessor) = self.primitive.get(&Semantic::Colors(set)) {
-            if let Some(slice) = (self.get_buffer_data)(accessor.clone().view().buffer()) {
+            if let Ok(source) = Source::new(&accessor, &self.get_buffer_data) {
                 return Some(
                     match (accessor.data_type(), accessor.dimensions()) {
-                        (U8, Vec3)  => ReadColors::RgbU8(accessor::Iter::new(accessor, slice)),
-                        (U16, Vec3) => ReadColors::RgbU16(accessor::Iter::new(accessor, slice)),
-                        (F32, Vec3) => ReadColors::RgbF32(accessor::Iter::new(accessor, slice)),
-                        (U8, Vec4)  => ReadColors::RgbaU8(accessor::Iter::new(accessor, slice)),
-                        (U16, Vec4) => ReadColors::RgbaU16(accessor::Iter::new(accessor, slice)),
-                        (F32, Vec4) => ReadColors::RgbaF32(accessor::Iter::new(accessor, slice)),
+                        (U8, Vec3)  => ReadColors::RgbU8(accessor::Iter::from_source(accessor, source)),
+                        (U16, Vec3) => ReadColors::RgbU16(accessor::Iter::from_source(accessor, source)),
+                        (F32, Vec3) => ReadColors::RgbF32(accessor::Iter::from_source(accessor, source)),
+                        (U8, Vec4)  => ReadColors::RgbaU8(accessor::Iter::from_source(accessor, source)),
+                        (U16, Vec4) => ReadColors::RgbaU16(accessor::Iter::from_source(accessor, source)),
+                        (F32, Vec4) => ReadColors::RgbaF32(accessor::Iter::from_source(accessor, source)),
                         _ => unreachable!(),
                     }
                 )
@@ -331,17 +518,22 @@ impl<'a, 's, F> Reader<'a, 's, F>
     }
 
     /// Visits the vertex draw sequence of a primitive.
+    ///
+    /// The indices are decoded from the accessor returned by
+    /// [`Primitive::indices`], and `None` is returned when that is `None`.
+    ///
+    /// [`Primitive::indices`]: struct.Primitive.html#method.indices
     pub fn read_indices(&self) -> Option<util::ReadIndices<'s>> {
         use accessor::DataType;
         use self::util::ReadIndices;
 
         if let Some(accessor) = self.primitive.indices() {
-            if let Some(slice) = (self.get_buffer_data)(accessor.clone().view().buffer()) {
+            if let Ok(source) = Source::new(&accessor, &self.get_buffer_data) {
                 return Some(
                     match accessor.data_type() {
-                        DataType::U8  => ReadIndices::U8(accessor::Iter::new(accessor, slice)),
-                        DataType::U16 => ReadIndices::U16(accessor::Iter::new(accessor, slice)),
-                        DataType::U32 => ReadIndices::U32(accessor::Iter::new(accessor, slice)),
+                        DataType::U8  => ReadIndices::U8(accessor::Iter::from_source(accessor, source)),
+                        DataType::U16 => ReadIndices::U16(accessor::Iter::from_source(accessor, source)),
+                        DataType::U32 => ReadIndices::U32(accessor::Iter::from_source(accessor, source)),
                         _ => unreachable!(),
                     }
                 )
@@ -351,17 +543,68 @@ impl<'a, 's, F> Reader<'a, 's, F>
         None
     }
 
+    /// Visits the vertex draw sequence of a primitive as `u32` indices.
+    ///
+    /// Indices of any type are widened to `u32`.  For non-indexed primitives
+    /// the implicit sequence `0..vertex_count` is visited, where the vertex
+    /// count is the number of vertex positions.
+    pub fn read_indices_u32(&self) -> Option<util::ReadIndicesU32<'s>> {
+        use self::util::ReadIndicesU32;
+
+        if self.primitive.indices().is_some() {
+            self.read_indices().map(|indices| ReadIndicesU32::Indexed(indices.into_u32()))
+        } else {
+            self.primitive
+                .get(&Semantic::Positions)
+                .map(|accessor| ReadIndicesU32::NonIndexed(0 .. accessor.count() as u32))
+        }
+    }
+
+    /// Returns the smallest and largest vertex index referenced by a primitive.
+    ///
+    /// The bounds are taken from the `min` and `max` properties of the indices
+    /// accessor when present, otherwise they are computed by visiting the
+    /// indices. Non-indexed primitives yield `0..vertex_count`.
+    pub fn index_bounds(&self) -> Option<Bounds<u32>> {
+        if let Some(accessor) = self.primitive.indices() {
+            if let (Some(min), Some(max)) = (accessor.min(), accessor.max()) {
+                let min = json::deserialize::from_value::<[u32; 1]>(min);
+                let max = json::deserialize::from_value::<[u32; 1]>(max);
+                if let (Ok([min]), Ok([max])) = (min, max) {
+                    return Some(Bounds { min, max });
+                }
+            }
+        }
+        self.read_indices_u32().and_then(|indices| {
+            indices.fold(None, |bounds, index| match bounds {
+                Some(Bounds { min, max }) => Some(Bounds { min: index.min(min), max: index.max(max) }),
+                None => Some(Bounds { min: index, max: index }),
+            })
+        })
+    }
+
+    /// Visits the vertex positions of the triangles of a primitive.
+    ///
+    /// Handles the `Triangles`, `TriangleStrip`, and `TriangleFan` modes and
+    /// follows the draw sequence of `read_indices_u32`. Returns `None` for
+    /// other modes or if the primitive has no positions.
+    pub fn read_triangles(&self) -> Option<util::ReadTriangles> {
+        let positions = self.read_positions()?.collect();
+        let indices = self.read_indices_u32()?.collect();
+        util::ReadTriangles::new(self.primitive.mode(), positions, indices)
+    }
+
     /// Visits the joint indices of the primitive.
     pub fn read_joints(&self, set: u32) -> Option<util::ReadJoints<'s>> {
         use accessor::DataType;
         use self::util::ReadJoints;
 
         if let Some(accessor) = self.primitive.get(&Semantic::Joints(set)) {
-            if let Some(slice) = (self.get_buffer_data)(accessor.view().buffer()) {
+            if let Ok(source) = Source::new(&accessor, &self.get_buffer_data) {
                 return Some(
                     match accessor.data_type() {
-                        DataType::U8  => ReadJoints::U8(accessor::Iter::new(accessor, slice)),
-                        DataType::U16 => ReadJoints::U16(accessor::Iter::new(accessor, slice)),
+                        DataType::U8  => ReadJoints::U8(accessor::Iter::from_source(accessor, source)),
+                        DataType::U16 => ReadJoints::U16(accessor::Iter::from_source(accessor, source)),
                         _ => unreachable!(),
                     }
                 )
@@ -372,17 +615,23 @@ impl<'a, 's, F> Reader<'a, 's, F>
     }
 
     /// Visits the vertex texture co-ordinates of a primitive.
+    ///
+    /// `set` is the index of the `TEXCOORD` attribute, as given by the
+    /// `tex_coord` of the texture being sampled. See
+    /// `Material::used_tex_coord_sets` for the sets a material requires.
     pub fn read_tex_coords(&self, set: u32) -> Option<util::ReadTexCoords<'s>> {
         use accessor::DataType;
         use self::util::ReadTexCoords;
 
         if let Some(accessor) = self.primitive.get(&Semantic::TexCoords(set)) {
-            if let Some(slice) = (self.get_buffer_data)(accessor.view().buffer()) {
+            if let Ok(source) = Source::new(&accessor, &self.get_buffer_data) {
                 return Some(
                     match accessor.data_type() {
-                        DataType::U8  => ReadTexCoords::U8(accessor::Iter::new(accessor, slice)),
-                        DataType::U16 => ReadTexCoords::U16(accessor::Iter::new(accessor, slice)),
-                        DataType::F32 => ReadTexCoords::F32(accessor::Iter::new(accessor, slice)),
+                        DataType::I8  => ReadTexCoords::I8(accessor::Iter::from_source(accessor, source)),
+                        DataType::U8  => ReadTexCoords::U8(accessor::Iter::from_source(accessor, source)),
+                        DataType::I16 => ReadTexCoords::I16(accessor::Iter::from_source(accessor, source)),
+                        DataType::U16 => ReadTexCoords::U16(accessor::Iter::from_source(accessor, source)),
+                        DataType::F32 => ReadTexCoords::F32(accessor::Iter::from_source(accessor, source)),
                         _ => unreachable!(),
                     }
                 )
@@ -398,12 +647,12 @@ impl<'a, 's, F> Reader<'a, 's, F>
         use self::util::ReadWeights;
 
         if let Some(accessor) = self.primitive.get(&Semantic::Weights(set)) {
-            if let Some(slice) = (self.get_buffer_data)(accessor.view().buffer()) {
+            if let Ok(source) = Source::new(&accessor, &self.get_buffer_data) {
                 return Some(
                     match accessor.data_type() {
-                        DataType::U8  => ReadWeights::U8(accessor::Iter::new(accessor, slice)),
-                        DataType::U16 => ReadWeights::U16(accessor::Iter::new(accessor, slice)),
-                        DataType::F32 => ReadWeights::F32(accessor::Iter::new(accessor, slice)),
+                        DataType::U8  => ReadWeights::U8(accessor::Iter::from_source(accessor, source)),
+                        DataType::U16 => ReadWeights::U16(accessor::Iter::from_source(accessor, source)),
+                        DataType::F32 => ReadWeights::F32(accessor::Iter::from_source(accessor, source)),
                         _ => unreachable!(),
                     }
                 )
@@ -413,6 +662,29 @@ impl<'a, 's, F> Reader<'a, 's, F>
         None
     }
 
+    /// Checks that the joint weights of each vertex, summed over every
+    /// `WEIGHTS_n` set, equal `1.0` to within `tolerance`.
+    ///
+    /// Returns `Error::UnnormalizedWeights` for the first vertex whose weights
+    /// do not. Primitives without joint weights always pass.
+    pub fn check_weight_sums(&self, tolerance: f32) -> Result<()> {
+        let mut sums: Vec<f32> = Vec::new();
+        for set in 0 .. {
+            let weights = match self.read_weights(set) {
+                Some(weights) => weights.into_f32(),
+                None => break,
+            };
+            sums.resize(weights.len(), 0.0);
+            for (sum, weights) in sums.iter_mut().zip(weights) {
+                *sum += weights[0] + weights[1] + weights[2] + weights[3];
+            }
+        }
+        match sums.iter().position(|sum| (sum - 1.0).abs() > tolerance) {
+            Some(vertex) => Err(Error::UnnormalizedWeights { vertex, sum: sums[vertex] }),
+            None => Ok(()),
+        }
+    }
+
     /// Visits the morph targets of the primitive.
     pub fn read_morph_targets(&self) -> util::ReadMorphTargets<'a, 's, F> {
         util::ReadMorphTargets {
@@ -420,6 +692,55 @@ impl<'a, 's, F> Reader<'a, 's, F>
             reader: self.clone(),
         }
     }
+
+    /// Returns the vertex positions of the primitive with its morph targets
+    /// applied, that is each base position plus the sum of the weighted
+    /// position displacements.
+    ///
+    /// `weights` holds one weight per morph target, such as those returned by
+    /// `Node::resolved_weights`. Morph targets without position displacements
+    /// leave the positions unchanged. Returns `None` if the primitive has no
+    /// positions, or if the number of weights differs from the number of
+    /// morph targets of the primitive.
+    ///
+    /// Quantized positions and displacements are converted to `f32` before
+    /// the weights are applied, see `ReadPositions` and
+    /// `ReadPositionDisplacements`.
+    pub fn read_morphed_positions(&self, weights: &[f32]) -> Option<Vec<[f32; 3]>> {
+        if weights.len() != self.primitive.morph_targets().len() {
+            return None;
+        }
+
+        let mut positions: Vec<[f32; 3]> = self.read_positions()?.collect();
+        for ((displacements, _, _), &weight) in self.read_morph_targets().zip(weights) {
+            if weight == 0.0 {
+                continue;
+            }
+            for (position, displacement) in positions.iter_mut().zip(displacements.into_iter().flatten()) {
+                for i in 0 .. 3 {
+                    position[i] += weight * displacement[i];
+                }
+            }
+        }
+        Some(positions)
+    }
+}
+
+/// Normalizes a bound of a normalized integer accessor of the given component
+/// type.
+fn normalize_bound(data_type: ::accessor::DataType, bound: [f32; 3]) -> [f32; 3] {
+    use accessor::DataType;
+
+    let normalize = |x: f32| -> f32 {
+        match data_type {
+            DataType::I8 => (x as i8).normalize(),
+            DataType::U8 => (x as u8).normalize(),
+            DataType::I16 => (x as i16).normalize(),
+            DataType::U16 => (x as u16).normalize(),
+            _ => x,
+        }
+    };
+    [normalize(bound[0]), normalize(bound[1]), normalize(bound[2])]
 }
 
 impl<'a> MorphTarget<'a> {
@@ -1,13 +1,22 @@
 use std::marker::PhantomData;
 
+use image::srgb_to_linear;
 use Normalize;
 
 use mesh::util::ReadColors;
 
 /// Casting iterator for `Colors`.
+///
+/// Integer colors are always treated as normalized, as the specification
+/// only permits normalized integer colors.
 #[derive(Clone, Debug)]
 pub struct CastingIter<'a, T>(ReadColors<'a>, PhantomData<T>);
 
+/// An `Iterator` adapter that converts sRGB encoded colors to linear color
+/// space, leaving alpha unchanged.
+#[derive(Clone, Debug)]
+pub struct SrgbToLinear<I>(I);
+
 /// Type which describes how to cast any color into RGB u8.
 #[derive(Clone, Debug)]
 pub struct RgbU8;
@@ -34,18 +43,26 @@ pub struct RgbaF32;
 
 trait ColorChannel {
     fn max_color() -> Self;
+    fn to_unit(self) -> f32;
+    fn from_unit(x: f32) -> Self;
 }
 
 impl ColorChannel for u8 {
     fn max_color() -> Self { u8::max_value() }
+    fn to_unit(self) -> f32 { self as f32 / 255.0 }
+    fn from_unit(x: f32) -> Self { (x * 255.0).round().max(0.0).min(255.0) as u8 }
 }
 
 impl ColorChannel for u16 {
     fn max_color() -> Self { u16::max_value() }
+    fn to_unit(self) -> f32 { self as f32 / 65535.0 }
+    fn from_unit(x: f32) -> Self { (x * 65535.0).round().max(0.0).min(65535.0) as u16 }
 }
 
 impl ColorChannel for f32 {
     fn max_color() -> Self { 1.0 }
+    fn to_unit(self) -> f32 { self }
+    fn from_unit(x: f32) -> Self { x }
 }
 
 trait ColorArray<T> {
@@ -63,6 +80,47 @@ impl<T: Copy + ColorChannel> ColorArray<T> for [T; 4] {
     fn into_rgba(self) -> [T; 4] { self }
 }
 
+/// Trait for colors that may be converted from sRGB encoding to linear color
+/// space.
+pub trait SrgbColor {
+    /// Converts the color channels to linear color space, leaving alpha
+    /// unchanged.
+    fn srgb_to_linear(self) -> Self;
+}
+
+fn srgb_channel_to_linear<T: ColorChannel>(x: T) -> T {
+    T::from_unit(srgb_to_linear(x.to_unit()))
+}
+
+macro_rules! impl_srgb_color {
+    ($($ty:ty),*) => {
+        $(
+            impl SrgbColor for [$ty; 3] {
+                fn srgb_to_linear(self) -> Self {
+                    [
+                        srgb_channel_to_linear(self[0]),
+                        srgb_channel_to_linear(self[1]),
+                        srgb_channel_to_linear(self[2]),
+                    ]
+                }
+            }
+
+            impl SrgbColor for [$ty; 4] {
+                fn srgb_to_linear(self) -> Self {
+                    [
+                        srgb_channel_to_linear(self[0]),
+                        srgb_channel_to_linear(self[1]),
+                        srgb_channel_to_linear(self[2]),
+                        self[3],
+                    ]
+                }
+            }
+        )*
+    }
+}
+
+impl_srgb_color!(u8, u16, f32);
+
 /// Trait for types which describe casting behaviour.
 pub trait Cast {
     /// Output type.
@@ -96,6 +154,44 @@ impl<'a, A> CastingIter<'a, A> {
     pub fn unwrap(self) -> ReadColors<'a> {
         self.0
     }
+
+    /// Converts the colors from sRGB encoding to linear color space, leaving
+    /// alpha unchanged.
+    ///
+    /// Vertex colors are linear by definition, so this is only useful for
+    /// assets known to have been authored with sRGB encoded colors.
+    pub fn srgb_to_linear(self) -> SrgbToLinear<Self> {
+        SrgbToLinear(self)
+    }
+}
+
+impl<I> ExactSizeIterator for SrgbToLinear<I>
+    where I: ExactSizeIterator, I::Item: SrgbColor
+{}
+
+impl<I> Iterator for SrgbToLinear<I>
+    where I: Iterator, I::Item: SrgbColor
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(SrgbColor::srgb_to_linear)
+    }
+
+    #[inline]
+    fn nth(&mut self, x: usize) -> Option<Self::Item> {
+        self.0.nth(x).map(SrgbColor::srgb_to_linear)
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        self.0.last().map(SrgbColor::srgb_to_linear)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
 }
 
 impl<'a, A: Cast> ExactSizeIterator for CastingIter<'a, A> {}
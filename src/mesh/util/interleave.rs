@@ -0,0 +1,86 @@
+use accessor::util::{copy_items, Source};
+use accessor::{DataType, Dimensions};
+use mesh::{Reader, Semantic};
+use Buffer;
+
+/// Describes where an attribute is stored in an interleaved vertex buffer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VertexAttribute {
+    /// The semantic of the attribute.
+    pub semantic: Semantic,
+
+    /// The offset of the attribute from the start of each vertex in bytes.
+    pub offset: usize,
+
+    /// The component data type of the attribute.
+    pub data_type: DataType,
+
+    /// The dimensions of the attribute.
+    pub dimensions: Dimensions,
+
+    /// Whether integer components represent normalized values.
+    pub normalized: bool,
+}
+
+/// Describes the vertices of an interleaved vertex buffer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VertexLayout {
+    /// The attributes of each vertex, in the order requested.
+    pub attributes: Vec<VertexAttribute>,
+
+    /// The distance between consecutive vertices in bytes.
+    pub stride: usize,
+
+    /// The number of vertices.
+    pub count: usize,
+}
+
+/// Rounds `size` up to a multiple of four bytes.
+fn align(size: usize) -> usize {
+    (size + 3) & !3
+}
+
+/// Packs the given attributes of a primitive into a single vertex buffer, in
+/// which the attributes of each vertex are stored consecutively.
+///
+/// Each attribute keeps the data type, dimensions, and normalization of its
+/// accessor and starts at a multiple of four bytes, as do vertices, as
+/// required for vertex attributes by the glTF specification. Padding bytes
+/// are zero.
+///
+/// Returns `None` if the primitive lacks any of the attributes, if their
+/// accessors differ in count, or if the required buffer data is unavailable.
+pub fn interleave_primitive<'a, 's, F>(
+    reader: &Reader<'a, 's, F>,
+    semantics: &[Semantic],
+) -> Option<(Vec<u8>, VertexLayout)>
+where
+    F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+{
+    let mut accessors = Vec::with_capacity(semantics.len());
+    let mut attributes = Vec::with_capacity(semantics.len());
+    let mut stride = 0;
+    for semantic in semantics {
+        let accessor = reader.primitive.get(semantic)?;
+        attributes.push(VertexAttribute {
+            semantic: semantic.clone(),
+            offset: stride,
+            data_type: accessor.data_type(),
+            dimensions: accessor.dimensions(),
+            normalized: accessor.normalized(),
+        });
+        stride += align(accessor.size());
+        accessors.push(accessor);
+    }
+
+    let count = accessors.first().map_or(0, |accessor| accessor.count());
+    if accessors.iter().any(|accessor| accessor.count() != count) {
+        return None;
+    }
+    let mut data = vec![0; count * stride];
+    for (accessor, attribute) in accessors.iter().zip(&attributes) {
+        let source = Source::new(accessor, &reader.get_buffer_data).ok()?;
+        copy_items(accessor, source, &mut data, attribute.offset, stride);
+    }
+    Some((data, VertexLayout { attributes, stride, count }))
+}
@@ -4,6 +4,9 @@ pub mod colors;
 /// Casting iterator adapters for vertex indices.
 pub mod indices;
 
+/// Packing of vertex attributes into interleaved vertex buffers.
+pub mod interleave;
+
 /// Casting iterator adapters for joint indices.
 pub mod joints;
 
@@ -13,29 +16,144 @@ pub mod tex_coords;
 /// Casting iterator adapters for node weights.
 pub mod weights;
 
+/// Deduplication of the vertices of a primitive.
+pub mod weld;
+
 use mesh;
+use std::ops;
+
+use accessor::{DataType, Iter};
+use accessor::util::Source;
+use {Accessor, Buffer, Normalize};
+
+/// Converts integer components to `f32` without normalization, for reading
+/// accessors whose `normalized` flag is not set.
+pub(crate) trait AsFloat {
+    /// The `f32` equivalent of `Self`.
+    type Output;
+
+    /// Converts each component with an `as` cast.
+    fn as_float(self) -> Self::Output;
+}
+
+macro_rules! impl_as_float {
+    ($($ty:ty),*) => {
+        $(
+            impl AsFloat for [$ty; 2] {
+                type Output = [f32; 2];
+                fn as_float(self) -> [f32; 2] {
+                    [self[0] as f32, self[1] as f32]
+                }
+            }
+
+            impl AsFloat for [$ty; 3] {
+                type Output = [f32; 3];
+                fn as_float(self) -> [f32; 3] {
+                    [self[0] as f32, self[1] as f32, self[2] as f32]
+                }
+            }
+
+            impl AsFloat for [$ty; 4] {
+                type Output = [f32; 4];
+                fn as_float(self) -> [f32; 4] {
+                    [self[0] as f32, self[1] as f32, self[2] as f32, self[3] as f32]
+                }
+            }
+        )*
+    }
+}
 
-use accessor::Iter;
-use Buffer;
+impl_as_float!(i8, u8, i16, u16);
+
+/// Converts integer components to `f32`, normalizing them if the accessor is
+/// `normalized` and casting them otherwise.
+pub(crate) fn dequantize<T>(x: T, normalized: bool) -> T::Output
+where
+    T: AsFloat + Normalize<<T as AsFloat>::Output>,
+{
+    if normalized { x.normalize() } else { x.as_float() }
+}
 
 /// XYZ vertex positions of type `[f32; 3]`.
-pub type ReadPositions<'a> = Iter<'a, [f32; 3]>;
+///
+/// Positions stored as integers, as permitted by `KHR_mesh_quantization`,
+/// are normalized if their accessor is `normalized` and cast otherwise.
+#[derive(Clone, Debug)]
+pub enum ReadPositions<'a> {
+    /// Positions of type `[i8; 3]`.
+    I8(Iter<'a, [i8; 3]>),
+    /// Positions of type `[u8; 3]`.
+    U8(Iter<'a, [u8; 3]>),
+    /// Positions of type `[i16; 3]`.
+    I16(Iter<'a, [i16; 3]>),
+    /// Positions of type `[u16; 3]`.
+    U16(Iter<'a, [u16; 3]>),
+    /// Positions of type `[f32; 3]`.
+    F32(Iter<'a, [f32; 3]>),
+}
 
 /// XYZ vertex normals of type `[f32; 3]`.
-pub type ReadNormals<'a> = Iter<'a, [f32; 3]>;
+///
+/// Normals stored as normalized integers, as permitted by
+/// `KHR_mesh_quantization`, are normalized to `[-1.0, 1.0]`.
+#[derive(Clone, Debug)]
+pub enum ReadNormals<'a> {
+    /// Normals of type `[i8; 3]`.
+    I8(Iter<'a, [i8; 3]>),
+    /// Normals of type `[i16; 3]`.
+    I16(Iter<'a, [i16; 3]>),
+    /// Normals of type `[f32; 3]`.
+    F32(Iter<'a, [f32; 3]>),
+}
 
 /// XYZW vertex tangents of type `[f32; 4]` where the `w` component is a
 /// sign value (-1 or +1) indicating the handedness of the tangent basis.
-pub type ReadTangents<'a> = Iter<'a, [f32; 4]>;
+///
+/// Tangents stored as normalized integers have their `xyz` components
+/// normalized to `[-1.0, 1.0]` while the `w` component is taken as the sign of
+/// the stored value.
+#[derive(Clone, Debug)]
+pub enum ReadTangents<'a> {
+    /// Tangents of type `[i8; 4]`.
+    I8(Iter<'a, [i8; 4]>),
+    /// Tangents of type `[i16; 4]`.
+    I16(Iter<'a, [i16; 4]>),
+    /// Tangents of type `[f32; 4]`.
+    F32(Iter<'a, [f32; 4]>),
+}
 
 /// XYZ vertex position displacements of type `[f32; 3]`.
-pub type ReadPositionDisplacements<'a> = Iter<'a, [f32; 3]>;
+///
+/// Displacements stored as integers, as permitted by `KHR_mesh_quantization`,
+/// are normalized if their accessor is `normalized` and cast otherwise.
+#[derive(Clone, Debug)]
+pub enum ReadPositionDisplacements<'a> {
+    /// Position displacements of type `[i8; 3]`.
+    I8(Iter<'a, [i8; 3]>),
+    /// Position displacements of type `[i16; 3]`.
+    I16(Iter<'a, [i16; 3]>),
+    /// Position displacements of type `[f32; 3]`.
+    F32(Iter<'a, [f32; 3]>),
+}
 
 /// XYZ vertex normal displacements of type `[f32; 3]`.
-pub type ReadNormalDisplacements<'a> = Iter<'a, [f32; 3]>;
+///
+/// Displacements stored as normalized integers, as permitted by
+/// `KHR_mesh_quantization`, are normalized to `[-1.0, 1.0]`.
+#[derive(Clone, Debug)]
+pub enum ReadNormalDisplacements<'a> {
+    /// Normal displacements of type `[i8; 3]`.
+    I8(Iter<'a, [i8; 3]>),
+    /// Normal displacements of type `[i16; 3]`.
+    I16(Iter<'a, [i16; 3]>),
+    /// Normal displacements of type `[f32; 3]`.
+    F32(Iter<'a, [f32; 3]>),
+}
 
-/// XYZ vertex tangent displacements.
-pub type ReadTangentDisplacements<'a> = Iter<'a, [f32; 3]>;
+/// XYZ vertex tangent displacements of type `[f32; 3]`.
+///
+/// Tangent displacements are stored like normal displacements.
+pub type ReadTangentDisplacements<'a> = ReadNormalDisplacements<'a>;
 
 /// Vertex colors.
 #[derive(Clone, Debug)]
@@ -65,6 +183,35 @@ pub enum ReadIndices<'a> {
     U32(Iter<'a, u32>),
 }
 
+/// Index data of type `u32`.
+#[derive(Clone, Debug)]
+pub enum ReadIndicesU32<'a> {
+    /// Index data of an indexed primitive widened to `u32`.
+    Indexed(self::indices::CastingIter<'a, self::indices::U32>),
+    /// The implicit `0..vertex_count` sequence of a non-indexed primitive.
+    NonIndexed(ops::Range<u32>),
+}
+
+/// Vertex positions of the triangles of a primitive, of type `[[f32; 3]; 3]`.
+///
+/// Triangles are assembled according to the primitive mode, with the winding
+/// order defined by the glTF specification for strips and fans. Triangles
+/// that refer to a vertex without a position are skipped.
+#[derive(Clone, Debug)]
+pub struct ReadTriangles {
+    /// The primitive mode, one of the triangle modes.
+    mode: mesh::Mode,
+
+    /// The vertex positions of the primitive.
+    positions: Vec<[f32; 3]>,
+
+    /// The vertex draw sequence of the primitive.
+    indices: Vec<u32>,
+
+    /// The index of the next triangle.
+    triangle: usize,
+}
+
 /// Vertex joints.
 #[derive(Clone, Debug)]
 pub enum ReadJoints<'a> {
@@ -81,8 +228,12 @@ pub enum ReadJoints<'a> {
 /// UV texture co-ordinates.
 #[derive(Clone, Debug)]
 pub enum ReadTexCoords<'a> {
+    /// UV texture co-ordinates of type `[i8; 2]>`.
+    I8(Iter<'a, [i8; 2]>),
     /// UV texture co-ordinates of type `[u8; 2]>`.
     U8(Iter<'a, [u8; 2]>),
+    /// UV texture co-ordinates of type `[i16; 2]>`.
+    I16(Iter<'a, [i16; 2]>),
     /// UV texture co-ordinates of type `[u16; 2]>`.
     U16(Iter<'a, [u16; 2]>),
     /// UV texture co-ordinates of type `[f32; 2]`.
@@ -130,36 +281,23 @@ where
             .morph_targets()
             .nth(self.index - 1)
             .map(|morph_target| {
-                let positions = if let Some(accessor) = morph_target.positions() {
-                    let buffer = accessor.view().buffer();
-                    if let Some(slice) = ((self.reader).get_buffer_data)(buffer) {
-                        Some(Iter::new(accessor, slice))
-                    } else {
-                        None
-                    }
-                } else {
-                    None
-                };
-                let normals = if let Some(accessor) = morph_target.normals() {
-                    let buffer = accessor.view().buffer();
-                    if let Some(slice) = ((self.reader).get_buffer_data)(buffer) {
-                        Some(Iter::new(accessor, slice))
-                    } else {
-                        None
-                    }
-                } else {
-                    None
-                };
-                let tangents = if let Some(accessor) = morph_target.tangents() {
-                    let buffer = accessor.view().buffer();
-                    if let Some(slice) = ((self.reader).get_buffer_data)(buffer) {
-                        Some(Iter::new(accessor, slice))
-                    } else {
-                        None
-                    }
-                } else {
-                    None
-                };
+                let positions = morph_target.positions().and_then(|accessor| {
+                    let source = Source::new(&accessor, &self.reader.get_buffer_data).ok()?;
+                    Some(match accessor.data_type() {
+                        DataType::I8 => ReadPositionDisplacements::I8(Iter::from_source(accessor, source)),
+                        DataType::I16 => ReadPositionDisplacements::I16(Iter::from_source(accessor, source)),
+                        DataType::F32 => ReadPositionDisplacements::F32(Iter::from_source(accessor, source)),
+                        _ => unreachable!(),
+                    })
+                });
+                let normals = morph_target.normals().and_then(|accessor| {
+                    let source = Source::new(&accessor, &self.reader.get_buffer_data).ok()?;
+                    Some(ReadNormalDisplacements::from_source(accessor, source))
+                });
+                let tangents = morph_target.tangents().and_then(|accessor| {
+                    let source = Source::new(&accessor, &self.reader.get_buffer_data).ok()?;
+                    Some(ReadNormalDisplacements::from_source(accessor, source))
+                });
                 (positions, normals, tangents)
             })
     }
@@ -169,6 +307,400 @@ where
     }
 }
 
+impl<'a> ReadNormalDisplacements<'a> {
+    /// Constructs a reader for normal or tangent displacements.
+    fn from_source(accessor: Accessor, source: Source<'a>) -> Self {
+        match accessor.data_type() {
+            DataType::I8 => ReadNormalDisplacements::I8(Iter::from_source(accessor, source)),
+            DataType::I16 => ReadNormalDisplacements::I16(Iter::from_source(accessor, source)),
+            DataType::F32 => ReadNormalDisplacements::F32(Iter::from_source(accessor, source)),
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// Normalizes the `xyz` components of a tangent, preserving the sign of `w`.
+fn normalize_tangent<T>(x: [T; 4]) -> [f32; 4]
+where
+    T: Normalize<f32> + Copy + Default + PartialOrd,
+{
+    let w = if x[3] < T::default() { -1.0 } else { 1.0 };
+    [x[0].normalize(), x[1].normalize(), x[2].normalize(), w]
+}
+
+impl<'a> ExactSizeIterator for ReadPositions<'a> {}
+impl<'a> Iterator for ReadPositions<'a> {
+    type Item = [f32; 3];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match *self {
+            ReadPositions::I8(ref mut i) => {
+                let normalized = i.normalized();
+                i.next().map(|x| dequantize(x, normalized))
+            },
+            ReadPositions::U8(ref mut i) => {
+                let normalized = i.normalized();
+                i.next().map(|x| dequantize(x, normalized))
+            },
+            ReadPositions::I16(ref mut i) => {
+                let normalized = i.normalized();
+                i.next().map(|x| dequantize(x, normalized))
+            },
+            ReadPositions::U16(ref mut i) => {
+                let normalized = i.normalized();
+                i.next().map(|x| dequantize(x, normalized))
+            },
+            ReadPositions::F32(ref mut i) => i.next(),
+        }
+    }
+
+    #[inline]
+    fn nth(&mut self, x: usize) -> Option<Self::Item> {
+        match *self {
+            ReadPositions::I8(ref mut i) => {
+                let normalized = i.normalized();
+                i.nth(x).map(|x| dequantize(x, normalized))
+            },
+            ReadPositions::U8(ref mut i) => {
+                let normalized = i.normalized();
+                i.nth(x).map(|x| dequantize(x, normalized))
+            },
+            ReadPositions::I16(ref mut i) => {
+                let normalized = i.normalized();
+                i.nth(x).map(|x| dequantize(x, normalized))
+            },
+            ReadPositions::U16(ref mut i) => {
+                let normalized = i.normalized();
+                i.nth(x).map(|x| dequantize(x, normalized))
+            },
+            ReadPositions::F32(ref mut i) => i.nth(x),
+        }
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        match self {
+            ReadPositions::I8(i) => {
+                let normalized = i.normalized();
+                i.last().map(|x| dequantize(x, normalized))
+            },
+            ReadPositions::U8(i) => {
+                let normalized = i.normalized();
+                i.last().map(|x| dequantize(x, normalized))
+            },
+            ReadPositions::I16(i) => {
+                let normalized = i.normalized();
+                i.last().map(|x| dequantize(x, normalized))
+            },
+            ReadPositions::U16(i) => {
+                let normalized = i.normalized();
+                i.last().map(|x| dequantize(x, normalized))
+            },
+            ReadPositions::F32(i) => i.last(),
+        }
+    }
+
+    fn count(self) -> usize {
+        self.size_hint().0
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match *self {
+            ReadPositions::I8(ref i) => i.size_hint(),
+            ReadPositions::U8(ref i) => i.size_hint(),
+            ReadPositions::I16(ref i) => i.size_hint(),
+            ReadPositions::U16(ref i) => i.size_hint(),
+            ReadPositions::F32(ref i) => i.size_hint(),
+        }
+    }
+}
+
+impl<'a> ExactSizeIterator for ReadPositionDisplacements<'a> {}
+impl<'a> Iterator for ReadPositionDisplacements<'a> {
+    type Item = [f32; 3];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match *self {
+            ReadPositionDisplacements::I8(ref mut i) => {
+                let normalized = i.normalized();
+                i.next().map(|x| dequantize(x, normalized))
+            },
+            ReadPositionDisplacements::I16(ref mut i) => {
+                let normalized = i.normalized();
+                i.next().map(|x| dequantize(x, normalized))
+            },
+            ReadPositionDisplacements::F32(ref mut i) => i.next(),
+        }
+    }
+
+    #[inline]
+    fn nth(&mut self, x: usize) -> Option<Self::Item> {
+        match *self {
+            ReadPositionDisplacements::I8(ref mut i) => {
+                let normalized = i.normalized();
+                i.nth(x).map(|x| dequantize(x, normalized))
+            },
+            ReadPositionDisplacements::I16(ref mut i) => {
+                let normalized = i.normalized();
+                i.nth(x).map(|x| dequantize(x, normalized))
+            },
+            ReadPositionDisplacements::F32(ref mut i) => i.nth(x),
+        }
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        match self {
+            ReadPositionDisplacements::I8(i) => {
+                let normalized = i.normalized();
+                i.last().map(|x| dequantize(x, normalized))
+            },
+            ReadPositionDisplacements::I16(i) => {
+                let normalized = i.normalized();
+                i.last().map(|x| dequantize(x, normalized))
+            },
+            ReadPositionDisplacements::F32(i) => i.last(),
+        }
+    }
+
+    fn count(self) -> usize {
+        self.size_hint().0
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match *self {
+            ReadPositionDisplacements::I8(ref i) => i.size_hint(),
+            ReadPositionDisplacements::I16(ref i) => i.size_hint(),
+            ReadPositionDisplacements::F32(ref i) => i.size_hint(),
+        }
+    }
+}
+
+impl<'a> ExactSizeIterator for ReadNormalDisplacements<'a> {}
+impl<'a> Iterator for ReadNormalDisplacements<'a> {
+    type Item = [f32; 3];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match *self {
+            ReadNormalDisplacements::I8(ref mut i) => i.next().map(Normalize::normalize),
+            ReadNormalDisplacements::I16(ref mut i) => i.next().map(Normalize::normalize),
+            ReadNormalDisplacements::F32(ref mut i) => i.next(),
+        }
+    }
+
+    #[inline]
+    fn nth(&mut self, x: usize) -> Option<Self::Item> {
+        match *self {
+            ReadNormalDisplacements::I8(ref mut i) => i.nth(x).map(Normalize::normalize),
+            ReadNormalDisplacements::I16(ref mut i) => i.nth(x).map(Normalize::normalize),
+            ReadNormalDisplacements::F32(ref mut i) => i.nth(x),
+        }
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        match self {
+            ReadNormalDisplacements::I8(i) => i.last().map(Normalize::normalize),
+            ReadNormalDisplacements::I16(i) => i.last().map(Normalize::normalize),
+            ReadNormalDisplacements::F32(i) => i.last(),
+        }
+    }
+
+    fn count(self) -> usize {
+        self.size_hint().0
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match *self {
+            ReadNormalDisplacements::I8(ref i) => i.size_hint(),
+            ReadNormalDisplacements::I16(ref i) => i.size_hint(),
+            ReadNormalDisplacements::F32(ref i) => i.size_hint(),
+        }
+    }
+}
+
+impl<'a> ExactSizeIterator for ReadNormals<'a> {}
+impl<'a> Iterator for ReadNormals<'a> {
+    type Item = [f32; 3];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match *self {
+            ReadNormals::I8(ref mut i) => i.next().map(Normalize::normalize),
+            ReadNormals::I16(ref mut i) => i.next().map(Normalize::normalize),
+            ReadNormals::F32(ref mut i) => i.next(),
+        }
+    }
+
+    #[inline]
+    fn nth(&mut self, x: usize) -> Option<Self::Item> {
+        match *self {
+            ReadNormals::I8(ref mut i) => i.nth(x).map(Normalize::normalize),
+            ReadNormals::I16(ref mut i) => i.nth(x).map(Normalize::normalize),
+            ReadNormals::F32(ref mut i) => i.nth(x),
+        }
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        match self {
+            ReadNormals::I8(i) => i.last().map(Normalize::normalize),
+            ReadNormals::I16(i) => i.last().map(Normalize::normalize),
+            ReadNormals::F32(i) => i.last(),
+        }
+    }
+
+    fn count(self) -> usize {
+        self.size_hint().0
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match *self {
+            ReadNormals::I8(ref i) => i.size_hint(),
+            ReadNormals::I16(ref i) => i.size_hint(),
+            ReadNormals::F32(ref i) => i.size_hint(),
+        }
+    }
+}
+
+impl<'a> ExactSizeIterator for ReadTangents<'a> {}
+impl<'a> Iterator for ReadTangents<'a> {
+    type Item = [f32; 4];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match *self {
+            ReadTangents::I8(ref mut i) => i.next().map(normalize_tangent),
+            ReadTangents::I16(ref mut i) => i.next().map(normalize_tangent),
+            ReadTangents::F32(ref mut i) => i.next(),
+        }
+    }
+
+    #[inline]
+    fn nth(&mut self, x: usize) -> Option<Self::Item> {
+        match *self {
+            ReadTangents::I8(ref mut i) => i.nth(x).map(normalize_tangent),
+            ReadTangents::I16(ref mut i) => i.nth(x).map(normalize_tangent),
+            ReadTangents::F32(ref mut i) => i.nth(x),
+        }
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        match self {
+            ReadTangents::I8(i) => i.last().map(normalize_tangent),
+            ReadTangents::I16(i) => i.last().map(normalize_tangent),
+            ReadTangents::F32(i) => i.last(),
+        }
+    }
+
+    fn count(self) -> usize {
+        self.size_hint().0
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match *self {
+            ReadTangents::I8(ref i) => i.size_hint(),
+            ReadTangents::I16(ref i) => i.size_hint(),
+            ReadTangents::F32(ref i) => i.size_hint(),
+        }
+    }
+}
+
+impl<'a> ExactSizeIterator for ReadIndicesU32<'a> {}
+impl<'a> Iterator for ReadIndicesU32<'a> {
+    type Item = u32;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match *self {
+            ReadIndicesU32::Indexed(ref mut i) => i.next(),
+            ReadIndicesU32::NonIndexed(ref mut i) => i.next(),
+        }
+    }
+
+    #[inline]
+    fn nth(&mut self, x: usize) -> Option<Self::Item> {
+        match *self {
+            ReadIndicesU32::Indexed(ref mut i) => i.nth(x),
+            ReadIndicesU32::NonIndexed(ref mut i) => i.nth(x),
+        }
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        match self {
+            ReadIndicesU32::Indexed(i) => i.last(),
+            ReadIndicesU32::NonIndexed(i) => i.last(),
+        }
+    }
+
+    fn count(self) -> usize {
+        self.size_hint().0
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match *self {
+            ReadIndicesU32::Indexed(ref i) => i.size_hint(),
+            ReadIndicesU32::NonIndexed(ref i) => i.size_hint(),
+        }
+    }
+}
+
+impl ReadTriangles {
+    /// Constructs a `ReadTriangles`, or returns `None` if `mode` is not a
+    /// triangle mode.
+    pub(crate) fn new(mode: mesh::Mode, positions: Vec<[f32; 3]>, indices: Vec<u32>) -> Option<Self> {
+        match mode {
+            mesh::Mode::Triangles | mesh::Mode::TriangleStrip | mesh::Mode::TriangleFan => {
+                Some(ReadTriangles { mode, positions, indices, triangle: 0 })
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns the number of triangles described by the draw sequence.
+    fn len(&self) -> usize {
+        match self.mode {
+            mesh::Mode::Triangles => self.indices.len() / 3,
+            _ => self.indices.len().saturating_sub(2),
+        }
+    }
+
+    /// Returns the positions of the vertices of triangle `i`.
+    fn triangle(&self, i: usize) -> Option<[[f32; 3]; 3]> {
+        let vertices = match self.mode {
+            mesh::Mode::Triangles => [3 * i, 3 * i + 1, 3 * i + 2],
+            mesh::Mode::TriangleStrip => [i, i + 1 + i % 2, i + 2 - i % 2],
+            _ => [i + 1, i + 2, 0],
+        };
+        let position = |vertex: usize| self.positions.get(self.indices[vertex] as usize).cloned();
+        Some([position(vertices[0])?, position(vertices[1])?, position(vertices[2])?])
+    }
+}
+
+impl Iterator for ReadTriangles {
+    type Item = [[f32; 3]; 3];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.triangle < self.len() {
+            let triangle = self.triangle(self.triangle);
+            self.triangle += 1;
+            if triangle.is_some() {
+                return triangle;
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.len() - self.triangle))
+    }
+}
+
 impl<'a> ReadColors<'a> {
     /// Reinterpret colors as RGB u8, discarding alpha, if present.  Lossy if
     /// the underlying iterator yields u16, f32 or any RGBA.
@@ -223,19 +755,23 @@ impl<'a> ReadJoints<'a> {
 
 impl<'a> ReadTexCoords<'a> {
     /// Reinterpret texture coordinates as u8.  Lossy if the underlying iterator
-    /// yields u16 or f32.
+    /// yields i8, i16, u16 or f32.
     pub fn into_u8(self) -> self::tex_coords::CastingIter<'a, self::tex_coords::U8> {
         self::tex_coords::CastingIter::new(self)
     }
 
     /// Reinterpret texture coordinates as u16.  Lossy if the underlying
-    /// iterator yields f32.
+    /// iterator yields i8, i16 or f32.
     pub fn into_u16(self) -> self::tex_coords::CastingIter<'a, self::tex_coords::U16> {
         self::tex_coords::CastingIter::new(self)
     }
 
     /// Reinterpret texture coordinates as f32.  Lossy if the underlying
     /// iterator yields u16.
+    ///
+    /// Normalized integer co-ordinates are mapped to the range `[0.0, 1.0]`,
+    /// or `[-1.0, 1.0]` if signed, so that the result does not depend on the
+    /// storage type.  Unnormalized integers are converted as is.
     pub fn into_f32(self) -> self::tex_coords::CastingIter<'a, self::tex_coords::F32> {
         self::tex_coords::CastingIter::new(self)
     }
@@ -256,6 +792,8 @@ impl<'a> ReadWeights<'a> {
 
     /// Reinterpret weights as f32.  Lossy if the underlying iterator yields
     /// u16.
+    ///
+    /// Normalized u8 and u16 weights are mapped to the range `[0.0, 1.0]`.
     pub fn into_f32(self) -> self::weights::CastingIter<'a, self::weights::F32> {
         self::weights::CastingIter::new(self)
     }
@@ -2,9 +2,13 @@ use std::marker::PhantomData;
 
 use Normalize;
 
-use mesh::util::ReadTexCoords;
+use mesh::util::{dequantize, AsFloat, ReadTexCoords};
 
 /// Casting iterator for `TexCoords`.
+///
+/// Integer texture coordinates whose accessor is not `normalized` are cast
+/// as if they were `f32` values. Signed integer texture coordinates, as
+/// permitted by `KHR_mesh_quantization`, are converted via `f32`.
 #[derive(Clone, Debug)]
 pub struct CastingIter<'a, T>(ReadTexCoords<'a>, PhantomData<T>);
 
@@ -46,6 +50,14 @@ impl<'a, A> CastingIter<'a, A> {
     }
 }
 
+fn cast_u8<A: Cast>(x: [u8; 2], normalized: bool) -> A::Output {
+    if normalized { A::cast_u8(x) } else { A::cast_f32(x.as_float()) }
+}
+
+fn cast_u16<A: Cast>(x: [u16; 2], normalized: bool) -> A::Output {
+    if normalized { A::cast_u16(x) } else { A::cast_f32(x.as_float()) }
+}
+
 impl<'a, A: Cast> ExactSizeIterator for CastingIter<'a, A> {}
 impl<'a, A: Cast> Iterator for CastingIter<'a, A> {
     type Item = A::Output;
@@ -53,8 +65,22 @@ impl<'a, A: Cast> Iterator for CastingIter<'a, A> {
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
         match self.0 {
-            ReadTexCoords::U8(ref mut i)  => i.next().map(A::cast_u8),
-            ReadTexCoords::U16(ref mut i) => i.next().map(A::cast_u16),
+            ReadTexCoords::I8(ref mut i) => {
+                let normalized = i.normalized();
+                i.next().map(|x| A::cast_f32(dequantize(x, normalized)))
+            },
+            ReadTexCoords::I16(ref mut i) => {
+                let normalized = i.normalized();
+                i.next().map(|x| A::cast_f32(dequantize(x, normalized)))
+            },
+            ReadTexCoords::U8(ref mut i) => {
+                let normalized = i.normalized();
+                i.next().map(|x| cast_u8::<A>(x, normalized))
+            },
+            ReadTexCoords::U16(ref mut i) => {
+                let normalized = i.normalized();
+                i.next().map(|x| cast_u16::<A>(x, normalized))
+            },
             ReadTexCoords::F32(ref mut i) => i.next().map(A::cast_f32),
         }
     }
@@ -62,16 +88,44 @@ impl<'a, A: Cast> Iterator for CastingIter<'a, A> {
     #[inline]
     fn nth(&mut self, x: usize) -> Option<Self::Item> {
         match self.0 {
-            ReadTexCoords::U8(ref mut i)  => i.nth(x).map(A::cast_u8),
-            ReadTexCoords::U16(ref mut i) => i.nth(x).map(A::cast_u16),
+            ReadTexCoords::I8(ref mut i) => {
+                let normalized = i.normalized();
+                i.nth(x).map(|x| A::cast_f32(dequantize(x, normalized)))
+            },
+            ReadTexCoords::I16(ref mut i) => {
+                let normalized = i.normalized();
+                i.nth(x).map(|x| A::cast_f32(dequantize(x, normalized)))
+            },
+            ReadTexCoords::U8(ref mut i) => {
+                let normalized = i.normalized();
+                i.nth(x).map(|x| cast_u8::<A>(x, normalized))
+            },
+            ReadTexCoords::U16(ref mut i) => {
+                let normalized = i.normalized();
+                i.nth(x).map(|x| cast_u16::<A>(x, normalized))
+            },
             ReadTexCoords::F32(ref mut i) => i.nth(x).map(A::cast_f32),
         }
     }
 
     fn last(self) -> Option<Self::Item> {
         match self.0 {
-            ReadTexCoords::U8(i)  => i.last().map(A::cast_u8),
-            ReadTexCoords::U16(i) => i.last().map(A::cast_u16),
+            ReadTexCoords::I8(i) => {
+                let normalized = i.normalized();
+                i.last().map(|x| A::cast_f32(dequantize(x, normalized)))
+            },
+            ReadTexCoords::I16(i) => {
+                let normalized = i.normalized();
+                i.last().map(|x| A::cast_f32(dequantize(x, normalized)))
+            },
+            ReadTexCoords::U8(i) => {
+                let normalized = i.normalized();
+                i.last().map(|x| cast_u8::<A>(x, normalized))
+            },
+            ReadTexCoords::U16(i) => {
+                let normalized = i.normalized();
+                i.last().map(|x| cast_u16::<A>(x, normalized))
+            },
             ReadTexCoords::F32(i) => i.last().map(A::cast_f32),
         }
     }
@@ -83,7 +137,9 @@ impl<'a, A: Cast> Iterator for CastingIter<'a, A> {
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
         match self.0 {
+            ReadTexCoords::I8(ref i)  => i.size_hint(),
             ReadTexCoords::U8(ref i)  => i.size_hint(),
+            ReadTexCoords::I16(ref i) => i.size_hint(),
             ReadTexCoords::U16(ref i) => i.size_hint(),
             ReadTexCoords::F32(ref i) => i.size_hint(),
         }
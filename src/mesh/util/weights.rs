@@ -5,6 +5,9 @@ use Normalize;
 use mesh::util::ReadWeights;
 
 /// Casting iterator for `Weights`.
+///
+/// Integer weights are always treated as normalized, as the specification
+/// only permits normalized integer weights.
 #[derive(Clone, Debug)]
 pub struct CastingIter<'a, T>(ReadWeights<'a>, PhantomData<T>);
 
@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+
+use mesh::Reader;
+use Buffer;
+
+/// A vertex assembled from the attributes of a primitive.
+///
+/// Attributes that the primitive does not provide are zero.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Vertex {
+    /// XYZ vertex position.
+    pub position: [f32; 3],
+
+    /// XYZ vertex normal.
+    pub normal: [f32; 3],
+
+    /// UV texture co-ordinates of the first set.
+    pub tex_coord: [f32; 2],
+}
+
+/// Describes which vertices `weld_primitive` considers identical.
+#[derive(Clone, Copy, Debug)]
+pub struct Options {
+    /// The resolution at which attribute values are compared.
+    ///
+    /// Each component is snapped to the nearest multiple of `epsilon`, so
+    /// values that round to the same multiple are considered equal. The
+    /// default value is `1e-6`.
+    pub epsilon: f32,
+
+    /// Whether vertices must have equal normals to be welded.
+    ///
+    /// The default value is `true`.
+    pub normals: bool,
+
+    /// Whether vertices must have equal texture co-ordinates to be welded.
+    ///
+    /// The default value is `true`.
+    pub tex_coords: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            epsilon: 1e-6,
+            normals: true,
+            tex_coords: true,
+        }
+    }
+}
+
+/// Deduplicates the vertices of a primitive, returning the distinct vertices
+/// and an index buffer that refers to them.
+///
+/// Positions always participate in the comparison, whereas normals and
+/// texture co-ordinates participate as specified by `options`. When
+/// vertices that differ in a non-participating attribute are welded, the
+/// attribute of the first such vertex is kept. The index buffer preserves the
+/// draw order of the primitive, which may be indexed or not.
+///
+/// Returns `None` if the primitive has no positions, the required buffer
+/// data is unavailable, or an index refers to a vertex that does not exist.
+pub fn weld_primitive<'a, 's, F>(
+    reader: &Reader<'a, 's, F>,
+    options: &Options,
+) -> Option<(Vec<Vertex>, Vec<u32>)>
+where
+    F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+{
+    let positions: Vec<_> = reader.read_positions()?.collect();
+    let normals: Vec<_> = reader
+        .read_normals()
+        .map_or_else(Vec::new, |normals| normals.collect());
+    let tex_coords: Vec<_> = reader
+        .read_tex_coords(0)
+        .map_or_else(Vec::new, |tex_coords| tex_coords.into_f32().collect());
+    let indices = reader.read_indices_u32()?;
+
+    let snap = |x: f32| (x / options.epsilon).round() as i64;
+    let mut vertices = Vec::new();
+    let mut welded = HashMap::new();
+    let mut output = Vec::with_capacity(indices.len());
+    for index in indices {
+        let index = index as usize;
+        let vertex = Vertex {
+            position: *positions.get(index)?,
+            normal: normals.get(index).cloned().unwrap_or_default(),
+            tex_coord: tex_coords.get(index).cloned().unwrap_or_default(),
+        };
+        let mut key = [0i64; 8];
+        for (k, &x) in key.iter_mut().zip(&vertex.position) {
+            *k = snap(x);
+        }
+        if options.normals {
+            for (k, &x) in key[3 .. 6].iter_mut().zip(&vertex.normal) {
+                *k = snap(x);
+            }
+        }
+        if options.tex_coords {
+            for (k, &x) in key[6 .. 8].iter_mut().zip(&vertex.tex_coord) {
+                *k = snap(x);
+            }
+        }
+        let next = vertices.len() as u32;
+        let welded_index = *welded.entry(key).or_insert(next);
+        if welded_index == next {
+            vertices.push(vertex);
+        }
+        output.push(welded_index);
+    }
+    Some((vertices, output))
+}
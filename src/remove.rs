@@ -0,0 +1,134 @@
+use json;
+use json::{Index, Path};
+
+use {Error, Result};
+
+/// Decrements an index that follows the removed index `removed`.
+fn remap<T>(index: &mut Index<T>, removed: usize) {
+    if index.value() > removed {
+        *index = Index::new(index.value() as u32 - 1);
+    }
+}
+
+/// Removes the references to `removed` from a list of indices and remaps the
+/// rest, returning whether any reference was removed.
+fn remove_from<T>(indices: &mut Vec<Index<T>>, removed: usize) -> bool {
+    let len = indices.len();
+    indices.retain(|index| index.value() != removed);
+    for index in indices.iter_mut() {
+        remap(index, removed);
+    }
+    indices.len() != len
+}
+
+/// Clears an optional reference to `removed` and remaps any other reference,
+/// returning whether the reference was cleared.
+fn clear<T>(index: &mut Option<Index<T>>, removed: usize) -> bool {
+    if index.map_or(false, |index| index.value() == removed) {
+        *index = None;
+        true
+    } else {
+        if let Some(index) = index.as_mut() {
+            remap(index, removed);
+        }
+        false
+    }
+}
+
+/// Removes a material, clearing the material of the primitives that use it
+/// so that they fall back to the default material.
+pub(crate) fn remove_material(root: &mut json::Root, index: usize) -> Vec<Path> {
+    assert!(index < root.materials.len(), "material index out of bounds");
+    let mut modified = Vec::new();
+    for (i, mesh) in root.meshes.iter_mut().enumerate() {
+        for (j, primitive) in mesh.primitives.iter_mut().enumerate() {
+            if clear(&mut primitive.material, index) {
+                modified.push(Path::new().field("meshes").index(i).field("primitives").index(j));
+            }
+        }
+    }
+    root.materials.remove(index);
+    modified
+}
+
+/// Removes a mesh, clearing the mesh of the nodes that instantiate it along
+/// with their skin and morph target weights, which require a mesh.
+pub(crate) fn remove_mesh(root: &mut json::Root, index: usize) -> Vec<Path> {
+    assert!(index < root.meshes.len(), "mesh index out of bounds");
+    let mut modified = Vec::new();
+    for (i, node) in root.nodes.iter_mut().enumerate() {
+        if clear(&mut node.mesh, index) {
+            node.skin = None;
+            node.weights = None;
+            modified.push(Path::new().field("nodes").index(i));
+        }
+    }
+    root.meshes.remove(index);
+    modified
+}
+
+/// Removes a node, detaching it from its parent and scenes and deleting the
+/// animation channels that target it, along with animations left without
+/// channels. The paths of modified nodes and animations refer to their
+/// indices after the removal.
+///
+/// Fails with `Error::Referenced` if the node is the joint of a skin, in
+/// which case `root` is unchanged.
+pub(crate) fn remove_node(root: &mut json::Root, index: usize) -> Result<Vec<Path>> {
+    assert!(index < root.nodes.len(), "node index out of bounds");
+    for (i, skin) in root.skins.iter().enumerate() {
+        if let Some(j) = skin.joints.iter().position(|joint| joint.value() == index) {
+            let path = Path::new().field("skins").index(i).field("joints").index(j);
+            return Err(Error::Referenced { path });
+        }
+    }
+
+    let mut modified = Vec::new();
+    let mut animations = Vec::with_capacity(root.animations.len());
+    for mut animation in root.animations.drain(..) {
+        let len = animation.channels.len();
+        animation.channels.retain(|channel| channel.target.node.value() != index);
+        for channel in &mut animation.channels {
+            remap(&mut channel.target.node, index);
+        }
+        if animation.channels.len() != len {
+            // An animation must have at least one channel, and its samplers
+            // are of no use without one.
+            if animation.channels.is_empty() {
+                continue;
+            }
+            modified.push(Path::new().field("animations").index(animations.len()));
+        }
+        animations.push(animation);
+    }
+    root.animations = animations;
+    for (i, node) in root.nodes.iter_mut().enumerate() {
+        let removed = node.children.as_mut().map_or(false, |children| remove_from(children, index));
+        if removed {
+            if node.children.as_ref().map_or(false, Vec::is_empty) {
+                node.children = None;
+            }
+            if i != index {
+                // Report the parent by its index after the removal.
+                let i = if i > index { i - 1 } else { i };
+                modified.push(Path::new().field("nodes").index(i));
+            }
+        }
+    }
+    for (i, scene) in root.scenes.iter_mut().enumerate() {
+        if remove_from(&mut scene.nodes, index) {
+            modified.push(Path::new().field("scenes").index(i));
+        }
+    }
+    for (i, skin) in root.skins.iter_mut().enumerate() {
+        for joint in &mut skin.joints {
+            remap(joint, index);
+        }
+        if clear(&mut skin.skeleton, index) {
+            modified.push(Path::new().field("skins").index(i));
+        }
+    }
+
+    root.nodes.remove(index);
+    Ok(modified)
+}
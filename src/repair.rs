@@ -0,0 +1,258 @@
+use json;
+use json::validation::Error;
+use json::{Index, Path};
+
+/// Clears an optional index that is out of bounds of an array of `len`
+/// objects, recording a warning at `path`.
+fn clear<T>(
+    index: &mut Option<Index<T>>,
+    len: usize,
+    path: Path,
+    warnings: &mut Vec<(Path, Error)>,
+) {
+    if index.map_or(false, |index| index.value() >= len) {
+        *index = None;
+        warnings.push((path, Error::IndexOutOfBounds));
+    }
+}
+
+/// Clears an optional texture reference whose texture index is out of
+/// bounds, recording a warning at `path`.
+fn clear_texture<T, F>(
+    info: &mut Option<T>,
+    index: F,
+    len: usize,
+    path: Path,
+    warnings: &mut Vec<(Path, Error)>,
+) where
+    F: Fn(&T) -> usize,
+{
+    if info.as_ref().map_or(false, |info| index(info) >= len) {
+        *info = None;
+        warnings.push((path, Error::IndexOutOfBounds));
+    }
+}
+
+/// Clears the out of bounds texture references of the material extensions,
+/// recording a warning for each relative to `path`.
+#[cfg_attr(
+    not(any(
+        feature = "KHR_materials_clearcoat",
+        feature = "KHR_materials_sheen",
+        feature = "KHR_materials_specular",
+        feature = "KHR_materials_transmission",
+        feature = "KHR_materials_volume",
+    )),
+    allow(unused_variables)
+)]
+fn clear_extension_textures(
+    extensions: &mut json::extensions::material::Material,
+    textures: usize,
+    path: Path,
+    warnings: &mut Vec<(Path, Error)>,
+) {
+    #[cfg(feature = "KHR_materials_clearcoat")]
+    {
+        if let Some(clearcoat) = extensions.clearcoat.as_mut() {
+            let path = path.field("KHR_materials_clearcoat");
+            clear_texture(
+                &mut clearcoat.clearcoat_texture,
+                |info| info.index.value(),
+                textures,
+                path.field("clearcoatTexture"),
+                warnings,
+            );
+            clear_texture(
+                &mut clearcoat.clearcoat_roughness_texture,
+                |info| info.index.value(),
+                textures,
+                path.field("clearcoatRoughnessTexture"),
+                warnings,
+            );
+            clear_texture(
+                &mut clearcoat.clearcoat_normal_texture,
+                |info| info.index.value(),
+                textures,
+                path.field("clearcoatNormalTexture"),
+                warnings,
+            );
+        }
+    }
+    #[cfg(feature = "KHR_materials_sheen")]
+    {
+        if let Some(sheen) = extensions.sheen.as_mut() {
+            let path = path.field("KHR_materials_sheen");
+            clear_texture(
+                &mut sheen.sheen_color_texture,
+                |info| info.index.value(),
+                textures,
+                path.field("sheenColorTexture"),
+                warnings,
+            );
+            clear_texture(
+                &mut sheen.sheen_roughness_texture,
+                |info| info.index.value(),
+                textures,
+                path.field("sheenRoughnessTexture"),
+                warnings,
+            );
+        }
+    }
+    #[cfg(feature = "KHR_materials_specular")]
+    {
+        if let Some(specular) = extensions.specular.as_mut() {
+            let path = path.field("KHR_materials_specular");
+            clear_texture(
+                &mut specular.specular_texture,
+                |info| info.index.value(),
+                textures,
+                path.field("specularTexture"),
+                warnings,
+            );
+            clear_texture(
+                &mut specular.specular_color_texture,
+                |info| info.index.value(),
+                textures,
+                path.field("specularColorTexture"),
+                warnings,
+            );
+        }
+    }
+    #[cfg(feature = "KHR_materials_transmission")]
+    {
+        if let Some(transmission) = extensions.transmission.as_mut() {
+            clear_texture(
+                &mut transmission.transmission_texture,
+                |info| info.index.value(),
+                textures,
+                path.field("KHR_materials_transmission").field("transmissionTexture"),
+                warnings,
+            );
+        }
+    }
+    #[cfg(feature = "KHR_materials_volume")]
+    {
+        if let Some(volume) = extensions.volume.as_mut() {
+            clear_texture(
+                &mut volume.thickness_texture,
+                |info| info.index.value(),
+                textures,
+                path.field("KHR_materials_volume").field("thicknessTexture"),
+                warnings,
+            );
+        }
+    }
+}
+
+/// Removes the out of bounds references of a document that have a sensible
+/// fallback when absent, returning a warning for each.
+///
+/// These are the default scene, the camera, mesh and skin of nodes, the
+/// material of primitives, the textures of materials and their extensions,
+/// the sampler and `KHR_texture_basisu` source of textures, and the skeleton
+/// and inverse bind matrices of skins. Required references, such as texture
+/// sources and the nodes of scenes, are left unchanged.
+pub(crate) fn clear_invalid_references(root: &mut json::Root) -> Vec<(Path, Error)> {
+    let accessors = root.accessors.len();
+    let cameras = root.cameras.len();
+    #[cfg(feature = "KHR_texture_basisu")]
+    let images = root.images.len();
+    let materials = root.materials.len();
+    let meshes = root.meshes.len();
+    let nodes = root.nodes.len();
+    let samplers = root.samplers.len();
+    let scenes = root.scenes.len();
+    let skins = root.skins.len();
+    let textures = root.textures.len();
+    let mut warnings = Vec::new();
+
+    clear(&mut root.scene, scenes, Path::new().field("scene"), &mut warnings);
+
+    for (i, material) in root.materials.iter_mut().enumerate() {
+        let path = Path::new().field("materials").index(i);
+        let pbr = path.field("pbrMetallicRoughness");
+        let pbr_json = &mut material.pbr_metallic_roughness;
+        clear_texture(
+            &mut pbr_json.base_color_texture,
+            |info| info.index.value(),
+            textures,
+            pbr.field("baseColorTexture"),
+            &mut warnings,
+        );
+        clear_texture(
+            &mut pbr_json.metallic_roughness_texture,
+            |info| info.index.value(),
+            textures,
+            pbr.field("metallicRoughnessTexture"),
+            &mut warnings,
+        );
+        clear_texture(
+            &mut material.normal_texture,
+            |info| info.index.value(),
+            textures,
+            path.field("normalTexture"),
+            &mut warnings,
+        );
+        clear_texture(
+            &mut material.occlusion_texture,
+            |info| info.index.value(),
+            textures,
+            path.field("occlusionTexture"),
+            &mut warnings,
+        );
+        clear_texture(
+            &mut material.emissive_texture,
+            |info| info.index.value(),
+            textures,
+            path.field("emissiveTexture"),
+            &mut warnings,
+        );
+        clear_extension_textures(&mut material.extensions, textures, path.field("extensions"), &mut warnings);
+    }
+
+    for (i, mesh) in root.meshes.iter_mut().enumerate() {
+        for (j, primitive) in mesh.primitives.iter_mut().enumerate() {
+            let path = Path::new().field("meshes").index(i).field("primitives").index(j);
+            clear(&mut primitive.material, materials, path.field("material"), &mut warnings);
+        }
+    }
+
+    for (i, node) in root.nodes.iter_mut().enumerate() {
+        let path = Path::new().field("nodes").index(i);
+        clear(&mut node.camera, cameras, path.field("camera"), &mut warnings);
+        clear(&mut node.mesh, meshes, path.field("mesh"), &mut warnings);
+        clear(&mut node.skin, skins, path.field("skin"), &mut warnings);
+    }
+
+    for (i, skin) in root.skins.iter_mut().enumerate() {
+        let path = Path::new().field("skins").index(i);
+        clear(
+            &mut skin.inverse_bind_matrices,
+            accessors,
+            path.field("inverseBindMatrices"),
+            &mut warnings,
+        );
+        clear(&mut skin.skeleton, nodes, path.field("skeleton"), &mut warnings);
+    }
+
+    for (i, texture) in root.textures.iter_mut().enumerate() {
+        let path = Path::new().field("textures").index(i);
+        clear(&mut texture.sampler, samplers, path.field("sampler"), &mut warnings);
+        #[cfg(feature = "KHR_texture_basisu")]
+        {
+            // The `KHR_texture_basisu` image may only be dropped in favour of
+            // the fallback image.
+            if texture.source.is_some() {
+                clear_texture(
+                    &mut texture.extensions.texture_basisu,
+                    |basisu| basisu.source.value(),
+                    images,
+                    path.field("extensions").field("KHR_texture_basisu"),
+                    &mut warnings,
+                );
+            }
+        }
+    }
+
+    warnings
+}
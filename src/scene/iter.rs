@@ -1,7 +1,8 @@
+use cgmath;
 use json;
-use std::slice;
+use std::{slice, vec};
 
-use {Document, Node};
+use {Document, Material, Node};
 
 /// An `Iterator` that visits the nodes in a scene.
 #[derive(Clone, Debug)]
@@ -23,6 +24,147 @@ pub struct Children<'a> {
     pub(crate) iter: slice::Iter<'a, json::Index<json::scene::Node>>,
 }
 
+/// An `Iterator` that visits the descendants of a node in depth-first
+/// pre-order.
+///
+/// Each node is visited at most once, hence malformed node hierarchies that
+/// contain cycles are not traversed indefinitely.
+#[derive(Clone, Debug)]
+pub struct Descendants<'a> {
+    /// The children remaining to be visited at each level of the traversal.
+    pub(crate) stack: Vec<Children<'a>>,
+
+    /// Marks the nodes that have already been visited.
+    pub(crate) visited: Vec<bool>,
+}
+
+/// An `Iterator` that visits the distinct materials used by a scene.
+#[derive(Clone, Debug)]
+pub struct Materials<'a> {
+    /// The parent `Document` struct.
+    pub(crate) document: &'a Document,
+
+    /// The internal material index iterator.
+    pub(crate) iter: vec::IntoIter<usize>,
+
+    /// Whether the default material remains to be visited.
+    pub(crate) default: bool,
+}
+
+/// An `Iterator` that visits the nodes of a scene in depth-first pre-order,
+/// meaning that each parent node is visited before its children.
+///
+/// Each node is visited at most once, hence malformed node hierarchies that
+/// contain cycles are not traversed indefinitely.
+#[derive(Clone, Debug)]
+pub struct Preorder<'a> {
+    /// Nodes yet to be visited paired with the world transform of their parent.
+    pub(crate) stack: Vec<(Node<'a>, [[f32; 4]; 4])>,
+
+    /// Marks the nodes that have already been visited.
+    pub(crate) visited: Vec<bool>,
+}
+
+/// An `Iterator` that visits the nodes of a scene in depth-first pre-order
+/// along with their world transforms.
+#[derive(Clone, Debug)]
+pub struct PreorderTransforms<'a>(Preorder<'a>);
+
+impl<'a> Preorder<'a> {
+    /// Constructs a pre-order traversal starting from the given root nodes.
+    pub(crate) fn new(document: &'a Document, roots: Nodes<'a>) -> Self {
+        let identity = cgmath::Matrix4::<f32>::from_scale(1.0).into();
+        let mut stack: Vec<_> = roots.map(|node| (node, identity)).collect();
+        stack.reverse();
+        Preorder {
+            stack,
+            visited: vec![false; document.nodes().len()],
+        }
+    }
+
+    /// Yields the world transform of each node alongside the node itself.
+    ///
+    /// The world transform is the product of the local transforms of each
+    /// ancestor of the node and the local transform of the node itself.
+    pub fn with_transforms(self) -> PreorderTransforms<'a> {
+        PreorderTransforms(self)
+    }
+
+    fn next_with_transform(&mut self) -> Option<(Node<'a>, [[f32; 4]; 4])> {
+        while let Some((node, parent_transform)) = self.stack.pop() {
+            if self.visited[node.index()] {
+                continue;
+            }
+            self.visited[node.index()] = true;
+            let parent_transform = cgmath::Matrix4::from(parent_transform);
+            let local_transform = cgmath::Matrix4::from(node.transform().matrix());
+            let transform: [[f32; 4]; 4] = (parent_transform * local_transform).into();
+            let children: Vec<_> = node.children().collect();
+            for child in children.into_iter().rev() {
+                self.stack.push((child, transform));
+            }
+            return Some((node, transform));
+        }
+        None
+    }
+}
+
+impl<'a> Iterator for Preorder<'a> {
+    type Item = Node<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_with_transform().map(|(node, _)| node)
+    }
+}
+
+impl<'a> Iterator for PreorderTransforms<'a> {
+    type Item = (Node<'a>, [[f32; 4]; 4]);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next_with_transform()
+    }
+}
+
+impl<'a> Iterator for Descendants<'a> {
+    type Item = Node<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let next = self.stack.last_mut()?.next();
+            match next {
+                Some(node) => {
+                    if self.visited[node.index()] {
+                        continue;
+                    }
+                    self.visited[node.index()] = true;
+                    self.stack.push(node.children());
+                    return Some(node);
+                },
+                None => {
+                    self.stack.pop();
+                },
+            }
+        }
+    }
+}
+
+impl<'a> ExactSizeIterator for Materials<'a> {}
+impl<'a> Iterator for Materials<'a> {
+    type Item = Material<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(index) = self.iter.next() {
+            Some(self.document.materials().nth(index).unwrap())
+        } else if self.default {
+            self.default = false;
+            Some(Material::default(self.document))
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.iter.len() + self.default as usize;
+        (len, Some(len))
+    }
+}
+
 impl<'a> ExactSizeIterator for Nodes<'a> {}
 impl<'a> Iterator for Nodes<'a> {
     type Item = Node<'a>;
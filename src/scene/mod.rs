@@ -2,7 +2,14 @@ use cgmath;
 use cgmath::prelude::*;
 use json;
 
+use mesh::{BoundingBox, Semantic};
 use {Camera, Document, Mesh, Skin};
+#[cfg(feature = "extensions")]
+use extension_value;
+#[cfg(feature = "extras")]
+use {extras_as, Result};
+#[cfg(feature = "EXT_lights_image_based")]
+use light::ImageBasedLight;
 
 /// Iterators.
 pub mod iter;
@@ -97,11 +104,27 @@ pub struct Node<'a> {
     json: &'a json::scene::Node,
 }
 
+/// A lookup of the parent of each node, built by `Document::node_parents`.
+///
+/// glTF nodes only refer to their children, so finding the parent of a node
+/// requires scanning the whole hierarchy. The lookup is built once and may
+/// then be queried for any number of nodes.
+#[derive(Clone, Debug)]
+pub struct Parents<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The index of the first node listing each node as a child.
+    parents: Vec<Option<usize>>,
+
+    /// The first node listed as a child by more than one node.
+    shared: Option<usize>,
+}
+
 /// The root nodes of a scene.
 #[derive(Clone, Debug)]
 pub struct Scene<'a> {
     /// The parent `Document` struct.
-    #[allow(dead_code)]
     document: &'a Document,
 
     /// The corresponding JSON index.
@@ -138,20 +161,51 @@ impl<'a> Node<'a> {
     }
 
     /// Returns an `Iterator` that visits the node's children.
-    pub fn children(&self) -> iter::Children {
+    pub fn children(&self) -> iter::Children<'a> {
         iter::Children {
             document: self.document,
             iter: self.json.children.as_ref().map_or([].iter(), |x| x.iter()),
         }
     }
 
+    /// Returns an `Iterator` that visits every descendant of the node in
+    /// depth-first pre-order, excluding the node itself.
+    pub fn descendants(&self) -> iter::Descendants<'a> {
+        let mut visited = vec![false; self.document.nodes().len()];
+        visited[self.index] = true;
+        iter::Descendants {
+            stack: vec![self.children()],
+            visited,
+        }
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &json::Extras {
         &self.json.extras
     }
 
+    /// Deserializes the application specific data into a user type.
+    ///
+    /// Returns `None` if there is no application specific data.
+    #[cfg(feature = "extras")]
+    pub fn extras_as<T>(&self) -> Option<Result<T>>
+    where
+        T: json::deserialize::DeserializeOwned,
+    {
+        extras_as(&self.json.extras)
+    }
+
+    /// Queries the JSON of an extension by name.
+    ///
+    /// Extensions that are parsed into typed fields are serialized back to
+    /// JSON. Returns `None` if the extension is not present.
+    #[cfg(feature = "extensions")]
+    pub fn extension_value(&self, name: &str) -> Option<json::Value> {
+        extension_value(&self.json.extensions, name)
+    }
+
     /// Returns the mesh referenced by this node.
-    pub fn mesh(&self) -> Option<Mesh> {
+    pub fn mesh(&self) -> Option<Mesh<'a>> {
         self.json.mesh.as_ref().map(|index| {
             self.document.meshes().nth(index.value()).unwrap()
         })
@@ -183,6 +237,18 @@ impl<'a> Node<'a> {
         }
     }
 
+    /// Returns the transform of this node relative to one of its ancestors,
+    /// as a column-major matrix.
+    ///
+    /// The result is the product of the local transforms of the nodes on the
+    /// path from `ancestor` down to and including this node, excluding the
+    /// transform of `ancestor` itself. Returns `None` if `ancestor` is not an
+    /// ancestor of this node.
+    pub fn transform_relative_to(&self, ancestor: &Node) -> Option<[[f32; 4]; 4]> {
+        let mut visited = vec![false; self.document.nodes().len()];
+        relative_transform(ancestor, self.index, &mut visited).map(Into::into)
+    }
+
     /// Returns the skin referenced by this node.
     pub fn skin(&self) -> Option<Skin> {
         self.json.skin.as_ref().map(|index| {
@@ -190,10 +256,86 @@ impl<'a> Node<'a> {
         })
     }
 
+    /// Returns `true` if this node is used as a joint by any skin.
+    ///
+    /// The joints of every skin are scanned, see `Document::joint_nodes` to
+    /// query many nodes.
+    pub fn is_joint(&self) -> bool {
+        self.document.skins().any(|skin| skin.joints().any(|joint| joint.index() == self.index))
+    }
+
     /// Returns the weights of the instantiated morph target.
-    pub fn weights(&self) -> Option<&[f32]> {
+    pub fn weights(&self) -> Option<&'a [f32]> {
         self.json.weights.as_ref().map(Vec::as_slice)
     }
+
+    /// Returns the morph target weights of this node, falling back to the
+    /// default weights of the referenced mesh when the node does not specify
+    /// any.
+    pub fn resolved_weights(&self) -> Option<&'a [f32]> {
+        self.weights().or_else(|| self.mesh().and_then(|mesh| mesh.weights()))
+    }
+}
+
+impl<'a> Parents<'a> {
+    /// Constructs the parent lookup of a document.
+    pub(crate) fn new(document: &'a Document) -> Self {
+        let nodes = &document.0.nodes;
+        let mut parents = vec![None; nodes.len()];
+        let mut shared = None;
+        for (parent, node) in nodes.iter().enumerate() {
+            for child in node.children.iter().flat_map(|children| children.iter()) {
+                let entry = &mut parents[child.value()];
+                if entry.is_none() {
+                    *entry = Some(parent);
+                } else if shared.is_none() {
+                    shared = Some(child.value());
+                }
+            }
+        }
+        Self { document, parents, shared }
+    }
+
+    /// Returns the index of the parent of the node at `index`.
+    pub(crate) fn parent_index(&self, index: usize) -> Option<usize> {
+        self.parents[index]
+    }
+
+    /// Returns the first node found to be listed as a child by more than one
+    /// node, as in malformed hierarchies.
+    pub(crate) fn shared(&self) -> Option<usize> {
+        self.shared
+    }
+
+    /// Returns the parent of a node, or `None` if the node is a root node.
+    ///
+    /// If the node is listed as a child by more than one node, as in
+    /// malformed hierarchies, the first of those nodes in document order is
+    /// returned.
+    pub fn parent_of(&self, node: &Node) -> Option<Node<'a>> {
+        self.parent_index(node.index).map(|index| {
+            self.document.nodes().nth(index).unwrap()
+        })
+    }
+}
+
+/// Searches the descendants of `node` for the node with index `target` and
+/// returns its transform relative to `node`.
+fn relative_transform(node: &Node, target: usize, visited: &mut [bool]) -> Option<Matrix4> {
+    for child in node.children() {
+        if visited[child.index] {
+            continue;
+        }
+        visited[child.index] = true;
+        let local = Matrix4::from(child.transform().matrix());
+        if child.index == target {
+            return Some(local);
+        }
+        if let Some(transform) = relative_transform(&child, target, visited) {
+            return Some(local * transform);
+        }
+    }
+    None
 }
 
 impl<'a> Scene<'a> {
@@ -210,6 +352,52 @@ impl<'a> Scene<'a> {
         }
     }
 
+    /// Returns the axis-aligned bounding box of the geometry of the scene in
+    /// world space.
+    ///
+    /// The bounding box of each primitive is transformed by the world
+    /// transform of each node instantiating its mesh, and the boxes enclosing
+    /// the eight transformed corners are combined. Skinning and morph targets
+    /// are not taken into account, nor are primitives whose positions lack
+    /// `min` and `max` bounds.
+    ///
+    /// For scenes without geometry the returned box is empty, with `min` set
+    /// to positive infinity and `max` set to negative infinity.
+    pub fn bounding_box(&self) -> BoundingBox {
+        let mut min = [::std::f32::INFINITY; 3];
+        let mut max = [::std::f32::NEG_INFINITY; 3];
+        for (node, transform) in self.nodes_preorder().with_transforms() {
+            let mesh = match node.mesh() {
+                Some(mesh) => mesh,
+                None => continue,
+            };
+            let transform = Matrix4::from(transform);
+            for primitive in mesh.primitives() {
+                let has_bounds = primitive
+                    .get(&Semantic::Positions)
+                    .map_or(false, |positions| positions.min().is_some() && positions.max().is_some());
+                if !has_bounds {
+                    continue;
+                }
+                let bounds = primitive.bounding_box();
+                for corner in 0 .. 8 {
+                    let local = cgmath::Vector4::new(
+                        if corner & 1 == 0 { bounds.min[0] } else { bounds.max[0] },
+                        if corner & 2 == 0 { bounds.min[1] } else { bounds.max[1] },
+                        if corner & 4 == 0 { bounds.min[2] } else { bounds.max[2] },
+                        1.0,
+                    );
+                    let world = transform * local;
+                    for i in 0 .. 3 {
+                        min[i] = min[i].min(world[i]);
+                        max[i] = max[i].max(world[i]);
+                    }
+                }
+            }
+        }
+        BoundingBox { min, max }
+    }
+
     /// Returns the internal JSON index.
     pub fn index(&self) -> usize {
         self.index
@@ -220,6 +408,60 @@ impl<'a> Scene<'a> {
         &self.json.extras
     }
 
+    /// Deserializes the application specific data into a user type.
+    ///
+    /// Returns `None` if there is no application specific data.
+    #[cfg(feature = "extras")]
+    pub fn extras_as<T>(&self) -> Option<Result<T>>
+    where
+        T: json::deserialize::DeserializeOwned,
+    {
+        extras_as(&self.json.extras)
+    }
+
+    /// Returns the image-based light illuminating the scene, as specified by
+    /// the `EXT_lights_image_based` extension.
+    #[cfg(feature = "EXT_lights_image_based")]
+    pub fn image_based_light(&self) -> Option<ImageBasedLight<'a>> {
+        self.json.extensions.lights_image_based.as_ref().map(|extension| {
+            self.document
+                .image_based_lights()
+                .nth(extension.light.value())
+                .unwrap()
+        })
+    }
+
+    /// Returns an `Iterator` that visits each distinct material used by the
+    /// primitives of the nodes reachable from the scene.
+    ///
+    /// Materials are visited in document order, followed by the default
+    /// material if any reachable primitive has no material.
+    pub fn materials(&self) -> iter::Materials<'a> {
+        let mut used = vec![false; self.document.materials().len()];
+        let mut default = false;
+        for node in self.nodes_preorder() {
+            if let Some(mesh) = node.mesh() {
+                for primitive in mesh.primitives() {
+                    match primitive.material_index() {
+                        Some(index) => used[index] = true,
+                        None => default = true,
+                    }
+                }
+            }
+        }
+        let indices: Vec<_> = used
+            .into_iter()
+            .enumerate()
+            .filter(|&(_, used)| used)
+            .map(|(index, _)| index)
+            .collect();
+        iter::Materials {
+            document: self.document,
+            iter: indices.into_iter(),
+            default,
+        }
+    }
+
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     pub fn name(&self) -> Option<&str> {
@@ -233,6 +475,16 @@ impl<'a> Scene<'a> {
             iter: self.json.nodes.iter(),
         }
     }
+
+    /// Returns an `Iterator` that visits every node of the scene in
+    /// depth-first pre-order, starting from the root nodes.
+    ///
+    /// Each parent node is guaranteed to be visited before its children.
+    /// Nodes reachable more than once, as in malformed hierarchies containing
+    /// cycles, are only visited the first time they are encountered.
+    pub fn nodes_preorder(&self) -> iter::Preorder<'a> {
+        iter::Preorder::new(self.document, self.nodes())
+    }
 }
 
 #[cfg(test)]
@@ -98,8 +98,12 @@ impl<'a> Skin<'a> {
         self.json.name.as_ref().map(String::as_str)
     }
 
-    /// Returns the node used as the skeleton root. When `None`, joints
-    /// transforms resolve to scene root.
+    /// Returns the node used as the skeleton root, which defines the origin of
+    /// joint space.
+    ///
+    /// When `None`, the closest common ancestor of the joints should be used
+    /// as the skeleton root, or the scene root if the joints have no common
+    /// ancestor.
     pub fn skeleton(&self) -> Option<Node<'a>> {
         self.json.skeleton.as_ref().map(|index| {
             self.document.nodes().nth(index.value()).unwrap()
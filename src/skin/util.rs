@@ -1,4 +1,5 @@
 use accessor;
+use accessor::util::Source;
 
 use {Buffer, Skin};
 
@@ -23,8 +24,8 @@ where
     /// the skin.
     pub fn read_inverse_bind_matrices(&self) -> Option<ReadInverseBindMatrices<'s>> {
         if let Some(accessor) = self.skin.inverse_bind_matrices() {
-            if let Some(slice) = (self.get_buffer_data)(accessor.view().buffer()) {
-                return Some(accessor::Iter::new(accessor, slice))
+            if let Ok(source) = Source::new(&accessor, &self.get_buffer_data) {
+                return Some(accessor::Iter::from_source(accessor, source))
             }
         }
 
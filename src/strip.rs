@@ -0,0 +1,60 @@
+use json;
+use json::deserialize::from_value;
+use json::serialize::to_value;
+use json::{Path, Value};
+
+use {Error, Result};
+
+/// Removes every object of the extension `name` from a JSON tree, returning
+/// the paths of the objects it was removed from.
+///
+/// Application specific data is not visited, so `extras` that happen to
+/// contain an `extensions` property are left alone.
+fn strip(value: &mut Value, name: &str, path: &Path, modified: &mut Vec<Path>) {
+    match *value {
+        Value::Object(ref mut map) => {
+            let mut removed = false;
+            if let Some(&mut Value::Object(ref mut extensions)) = map.get_mut("extensions") {
+                removed = extensions.remove(name).is_some();
+                if removed {
+                    modified.push(path.clone());
+                }
+                removed &= extensions.is_empty();
+            }
+            if removed {
+                map.remove("extensions");
+            }
+            for (key, value) in map.iter_mut() {
+                if key != "extras" {
+                    strip(value, name, &path.field(key), modified);
+                }
+            }
+        },
+        Value::Array(ref mut values) => {
+            for (index, value) in values.iter_mut().enumerate() {
+                strip(value, name, &path.index(index), modified);
+            }
+        },
+        _ => {},
+    }
+}
+
+/// Removes the extension `name` from a document and from its lists of used
+/// and required extensions.
+///
+/// Fails with `Error::RequiredExtension` if the document requires the
+/// extension, in which case `root` is unchanged.
+pub(crate) fn remove_extension(root: &mut json::Root, name: &str) -> Result<Vec<Path>> {
+    if root.extensions_required.iter().any(|required| required == name) {
+        return Err(Error::RequiredExtension { name: name.to_string() });
+    }
+
+    let mut value = to_value(&*root)?;
+    let mut modified = Vec::new();
+    strip(&mut value, name, &Path::new(), &mut modified);
+    if !modified.is_empty() {
+        *root = from_value(value)?;
+    }
+    root.extensions_used.retain(|used| used != name);
+    Ok(modified)
+}
@@ -1,6 +1,8 @@
 use {image, json, Document};
+#[cfg(feature = "extensions")]
+use extension_value;
 
-pub use json::texture::{MagFilter, MinFilter, WrappingMode};
+pub use json::texture::{Filter, MagFilter, MinFilter, WrappingMode};
 
 lazy_static! {
     static ref DEFAULT_SAMPLER: json::texture::Sampler = Default::default();
@@ -74,11 +76,20 @@ impl<'a> Sampler<'a> {
     }
 
     /// Magnification filter.
+    ///
+    /// Returns `None` if the filter is undefined, in which case the
+    /// specification leaves the choice of filter to the implementation.
     pub fn mag_filter(&self) -> Option<MagFilter> {
         self.json.mag_filter.map(|filter| filter.unwrap())
     }
 
     /// Minification filter.
+    ///
+    /// Returns `None` if the filter is undefined, in which case the
+    /// specification leaves the choice of filter to the implementation.
+    /// A common choice is `MinFilter::LinearMipmapLinear`, whose base and
+    /// mipmap filters may be obtained with `MinFilter::base_filter` and
+    /// `MinFilter::mipmap_filter` respectively.
     pub fn min_filter(&self) -> Option<MinFilter> {
         self.json.min_filter.map(|filter| filter.unwrap())
     }
@@ -90,11 +101,15 @@ impl<'a> Sampler<'a> {
     }
 
     /// `s` wrapping mode.
+    ///
+    /// The default value is `Repeat`.
     pub fn wrap_s(&self) -> WrappingMode {
         self.json.wrap_s.unwrap()
     }
 
     /// `t` wrapping mode.
+    ///
+    /// The default value is `Repeat`.
     pub fn wrap_t(&self) -> WrappingMode {
         self.json.wrap_t.unwrap()
     }
@@ -131,6 +146,9 @@ impl<'a> Texture<'a> {
     }
 
     /// Returns the sampler used by this texture.
+    ///
+    /// When the texture does not specify a sampler, the default sampler is
+    /// returned, which has `Repeat` wrapping and undefined filters.
     pub fn sampler(&self) -> Sampler<'a> {
         self.json.sampler
             .as_ref()
@@ -139,14 +157,68 @@ impl<'a> Texture<'a> {
     }
 
     /// Returns the image used by this texture.
+    ///
+    /// When the texture omits `source`, the image supplied by the
+    /// `KHR_texture_basisu` extension is returned instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the texture has neither a `source` nor a `KHR_texture_basisu`
+    /// image, which is only possible for documents that were not validated.
     pub fn source(&self) -> image::Image<'a> {
-        self.document.images().nth(self.json.source.value() as usize).unwrap()
+        let index = self.source_index().expect("texture has no source");
+        self.document.images().nth(index).unwrap()
+    }
+
+    /// Returns the index of the image used by this texture, if any.
+    fn source_index(&self) -> Option<usize> {
+        let index = self.json.source.map(|index| index.value());
+        #[cfg(feature = "KHR_texture_basisu")]
+        let index = index.or_else(|| {
+            self.json.extensions.texture_basisu.as_ref().map(|basisu| basisu.source.value())
+        });
+        index
+    }
+
+    /// Returns the KTX2 image used by this texture, as specified by the
+    /// `KHR_texture_basisu` extension.
+    ///
+    /// When present, this image should be preferred over `source()`, which is
+    /// then a fallback for clients that cannot decode KTX2 images.
+    #[cfg(feature = "KHR_texture_basisu")]
+    pub fn basisu_source(&self) -> Option<image::Image<'a>> {
+        self.json.extensions.texture_basisu
+            .as_ref()
+            .map(|basisu| self.document.images().nth(basisu.source.value()).unwrap())
+    }
+
+    /// Returns the decoded data of the image used by this texture.
+    ///
+    /// `images` is the decoded image set returned by `gltf::import` or
+    /// `gltf::import_images`, which is ordered like `Document::images()`, so
+    /// the data of this texture's source is at the index of `source()`.
+    ///
+    /// Returns `None` if the texture has no source image, or if `images` has
+    /// no entry for it, for example when it was decoded from a different
+    /// document.
+    #[cfg(feature = "import")]
+    pub fn image_data<'s>(&self, images: &'s [image::Data]) -> Option<&'s image::Data> {
+        images.get(self.source_index()?)
     }
 
     /// Optional application specific data.
     pub fn extras(&self) -> &json::Extras {
         &self.json.extras
     }
+
+    /// Queries the JSON of an extension by name.
+    ///
+    /// Extensions that are parsed into typed fields are serialized back to
+    /// JSON. Returns `None` if the extension is not present.
+    #[cfg(feature = "extensions")]
+    pub fn extension_value(&self, name: &str) -> Option<json::Value> {
+        extension_value(&self.json.extensions, name)
+    }
 }
 
 
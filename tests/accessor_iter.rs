@@ -0,0 +1,142 @@
+extern crate byteorder;
+extern crate gltf;
+
+use byteorder::ByteOrder;
+use gltf::accessor::{DataType, Dimensions, Iter};
+
+#[test]
+fn accessor_iter_exact_size() {
+    let (document, buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    for accessor in document.accessors() {
+        let buffer_data = &buffers[accessor.view().unwrap().buffer().index()];
+        let count = accessor.count();
+        match (accessor.data_type(), accessor.dimensions()) {
+            (DataType::F32, Dimensions::Vec3) => {
+                let mut iter = Iter::<[f32; 3]>::new(accessor, buffer_data);
+                assert_eq!(count, iter.len());
+                assert_eq!((count, Some(count)), iter.size_hint());
+                iter.next();
+                assert_eq!(count - 1, iter.len());
+                assert_eq!(count - 1, iter.count());
+            },
+            (DataType::U16, Dimensions::Scalar) => {
+                let iter = Iter::<u16>::new(accessor, buffer_data);
+                assert_eq!(count, iter.len());
+                assert_eq!((count, Some(count)), iter.size_hint());
+                assert_eq!(count, iter.collect::<Vec<_>>().len());
+            },
+            _ => {},
+        }
+    }
+}
+
+#[test]
+fn accessor_iter_typed() {
+    let (document, buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| Some(&buffers[buffer.index()].0[..]);
+    for accessor in document.accessors() {
+        let buffer_data = &buffers[accessor.view().unwrap().buffer().index()];
+        match (accessor.data_type(), accessor.dimensions()) {
+            (DataType::F32, Dimensions::Vec3) => {
+                let expected: Vec<_> = Iter::<[f32; 3]>::new(accessor.clone(), buffer_data).collect();
+                let items: Vec<_> = accessor.iter::<[f32; 3], _>(get_buffer_data).unwrap().collect();
+                assert_eq!(expected, items);
+                assert!(accessor.iter::<[f32; 2], _>(get_buffer_data).is_err());
+                assert!(accessor.iter::<[u32; 3], _>(get_buffer_data).is_err());
+            },
+            (DataType::U16, Dimensions::Scalar) => {
+                let items: Vec<_> = accessor.iter::<u16, _>(get_buffer_data).unwrap().collect();
+                assert_eq!(accessor.count(), items.len());
+                assert!(accessor.iter::<u32, _>(get_buffer_data).is_err());
+            },
+            _ => {},
+        }
+        assert!(accessor.iter::<[f32; 3], _>(|_| None).is_err());
+    }
+}
+
+#[test]
+fn accessor_iter_sparse() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 28 } ],
+        "bufferViews": [
+            { "buffer": 0, "byteOffset": 0, "byteLength": 16 },
+            { "buffer": 0, "byteOffset": 16, "byteLength": 2 },
+            { "buffer": 0, "byteOffset": 20, "byteLength": 8 }
+        ],
+        "accessors": [
+            {
+                "bufferView": 0,
+                "componentType": 5126,
+                "count": 4,
+                "type": "SCALAR",
+                "sparse": {
+                    "count": 2,
+                    "indices": { "bufferView": 1, "componentType": 5121 },
+                    "values": { "bufferView": 2 }
+                }
+            }
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    let mut data = vec![0; 28];
+    byteorder::LE::write_f32_into(&[0.0, 1.0, 2.0, 3.0], &mut data[0 .. 16]);
+    data[16] = 1;
+    data[17] = 3;
+    byteorder::LE::write_f32_into(&[10.0, 30.0], &mut data[20 .. 28]);
+
+    let accessor = gltf.accessors().nth(0).unwrap();
+    let iter = accessor.iter::<f32, _>(|_| Some(&data)).unwrap();
+    assert_eq!(4, iter.len());
+    assert_eq!(vec![0.0, 10.0, 2.0, 30.0], iter.collect::<Vec<_>>());
+    assert_eq!(Some(30.0), iter.last());
+    assert_eq!(Some(2.0), iter.clone().nth(2));
+
+    let mut iter = iter;
+    assert_eq!(Some(30.0), iter.nth(3));
+    assert_eq!(None, iter.next());
+}
+
+#[test]
+fn accessor_iter_sparse_without_view() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 16 } ],
+        "bufferViews": [
+            { "buffer": 0, "byteOffset": 0, "byteLength": 1 },
+            { "buffer": 0, "byteOffset": 4, "byteLength": 12 }
+        ],
+        "accessors": [
+            {
+                "componentType": 5126,
+                "count": 3,
+                "type": "VEC3",
+                "min": [0.0, 0.0, 0.0],
+                "max": [1.0, 2.0, 3.0],
+                "sparse": {
+                    "count": 1,
+                    "indices": { "bufferView": 0, "componentType": 5121 },
+                    "values": { "bufferView": 1 }
+                }
+            }
+        ],
+        "meshes": [ { "primitives": [ { "attributes": { "POSITION": 0 } } ] } ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    let mut data = vec![0; 16];
+    data[0] = 1;
+    byteorder::LE::write_f32_into(&[1.0, 2.0, 3.0], &mut data[4 .. 16]);
+    let expected = vec![[0.0, 0.0, 0.0], [1.0, 2.0, 3.0], [0.0, 0.0, 0.0]];
+
+    let accessor = gltf.accessors().nth(0).unwrap();
+    assert!(accessor.view().is_none());
+    let iter = accessor.iter::<[f32; 3], _>(|_| Some(&data)).unwrap();
+    assert_eq!(3, iter.len());
+    assert_eq!(expected, iter.collect::<Vec<_>>());
+
+    let mesh = gltf.meshes().nth(0).unwrap();
+    let primitive = mesh.primitives().nth(0).unwrap();
+    let reader = primitive.reader(|_| Some(&data));
+    assert_eq!(expected, reader.read_positions().unwrap().collect::<Vec<_>>());
+}
@@ -0,0 +1,51 @@
+extern crate byteorder;
+extern crate gltf;
+
+use byteorder::{ByteOrder, LE};
+use std::{fs, io};
+use std::io::Read;
+
+fn read_box_glb() -> Vec<u8> {
+    let file = fs::File::open("examples/Box.glb").unwrap();
+    let mut reader = io::BufReader::new(file);
+    let mut data = vec![];
+    reader.read_to_end(&mut data).unwrap();
+    data
+}
+
+#[test]
+fn chunk_bytes() {
+    let data = read_box_glb();
+    let json_length = LE::read_u32(&data[12..16]) as usize;
+    let json = &data[20..20 + json_length];
+    let bin_length = LE::read_u32(&data[20 + json_length..24 + json_length]) as usize;
+    let bin = &data[28 + json_length..28 + json_length + bin_length];
+
+    let glb = gltf::binary::Glb::from_slice(&data).unwrap();
+    let header = glb.header();
+    assert_eq!(&header.magic, b"glTF");
+    assert_eq!(header.version, 2);
+    assert_eq!(header.length as usize, data.len());
+    assert_eq!(glb.json(), json);
+    assert_eq!(glb.bin(), Some(bin));
+
+    let glb = gltf::binary::Glb::from_reader(io::Cursor::new(&data)).unwrap();
+    assert_eq!(glb.json(), json);
+    assert_eq!(glb.bin(), Some(bin));
+}
+
+#[test]
+fn missing_bin_chunk() {
+    let json = br#"{"asset":{"version":"2.0"}}"#;
+    let glb = gltf::binary::Glb {
+        header: gltf::binary::Header { magic: *b"glTF", version: 2, length: 0 },
+        json: json.to_vec().into(),
+        bin: None,
+    };
+    let data = glb.to_vec().unwrap();
+    let glb = gltf::binary::Glb::from_slice(&data).unwrap();
+    assert_eq!(glb.header().length as usize, data.len());
+    assert!(glb.json().starts_with(json));
+    assert_eq!(glb.json().len() % 4, 0);
+    assert!(glb.bin().is_none());
+}
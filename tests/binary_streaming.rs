@@ -0,0 +1,112 @@
+extern crate byteorder;
+extern crate gltf;
+
+use byteorder::{ByteOrder, LE};
+use gltf::binary::Glb;
+use std::{fs, io};
+use std::io::Read;
+
+fn read_box_glb() -> Vec<u8> {
+    let file = fs::File::open("examples/Box.glb").unwrap();
+    let mut reader = io::BufReader::new(file);
+    let mut data = vec![];
+    reader.read_to_end(&mut data).unwrap();
+    data
+}
+
+fn append_chunk(glb: &mut Vec<u8>, ty: &[u8; 4], contents: &[u8]) {
+    let mut header = [0u8; 8];
+    LE::write_u32(&mut header[0..4], contents.len() as u32);
+    header[4..8].copy_from_slice(ty);
+    glb.extend_from_slice(&header);
+    glb.extend_from_slice(contents);
+    let length = glb.len() as u32;
+    LE::write_u32(&mut glb[8..12], length);
+}
+
+#[test]
+fn stream_bin_chunk() {
+    let data = read_box_glb();
+    let expected = Glb::from_slice(&data).unwrap();
+    let glb = Glb::from_reader_streaming(io::Cursor::new(&data)).unwrap();
+    assert_eq!(&expected.json[..], &glb.json[..]);
+
+    let mut bin = vec![];
+    glb.bin.unwrap().read_to_end(&mut bin).unwrap();
+    assert_eq!(&expected.bin.unwrap()[..], &bin[..]);
+}
+
+#[test]
+fn stream_bin_chunk_after_unknown_chunk() {
+    let original = read_box_glb();
+    let expected = Glb::from_slice(&original).unwrap();
+    let json_only = Glb { header: expected.header, json: expected.json.clone(), bin: None };
+    let mut data = json_only.to_vec().unwrap();
+    append_chunk(&mut data, b"XVND", &[1, 2, 3, 4]);
+    append_chunk(&mut data, b"BIN\0", &expected.bin.as_ref().unwrap());
+    data.extend_from_slice(&[0xFF; 16]);
+
+    let glb = Glb::from_reader_streaming(io::Cursor::new(&data)).unwrap();
+    let mut bin = vec![];
+    glb.bin.unwrap().read_to_end(&mut bin).unwrap();
+    assert_eq!(&expected.bin.unwrap()[..], &bin[..]);
+}
+
+#[test]
+fn stream_without_bin_chunk() {
+    let original = read_box_glb();
+    let expected = Glb::from_slice(&original).unwrap();
+    let json_only = Glb { header: expected.header, json: expected.json.clone(), bin: None };
+    let data = json_only.to_vec().unwrap();
+    let glb = Glb::from_reader_streaming(io::Cursor::new(&data)).unwrap();
+    assert_eq!(&expected.json[..], &glb.json[..]);
+    assert!(glb.bin.is_none());
+}
+
+#[test]
+fn gltf_from_reader_reads_bin_chunk() {
+    let data = read_box_glb();
+    let expected = gltf::Gltf::from_slice(&data).unwrap();
+    let gltf = gltf::Gltf::from_reader(io::Cursor::new(&data)).unwrap();
+    assert_eq!(gltf.blob, expected.blob);
+    assert_eq!(gltf.meshes().len(), expected.meshes().len());
+
+    let truncated = &data[.. data.len() - 4];
+    assert!(gltf::Gltf::from_reader(io::Cursor::new(truncated)).is_err());
+}
+
+#[test]
+fn reject_malformed_lengths() {
+    let data = read_box_glb();
+
+    // A header length shorter than the header itself.
+    let mut short = data.clone();
+    LE::write_u32(&mut short[8..12], 4);
+    assert!(Glb::from_slice(&short).is_err());
+    assert!(Glb::from_reader(io::Cursor::new(&short)).is_err());
+    assert!(Glb::from_reader_streaming(io::Cursor::new(&short)).is_err());
+
+    // Lengths far beyond the data must fail without allocating them.
+    let mut huge = data.clone();
+    LE::write_u32(&mut huge[8..12], u32::max_value());
+    LE::write_u32(&mut huge[12..16], u32::max_value() - 64);
+    assert!(Glb::from_reader(io::Cursor::new(&huge)).is_err());
+    assert!(Glb::from_reader_streaming(io::Cursor::new(&huge)).is_err());
+}
+
+#[test]
+fn reject_truncated_unknown_chunk() {
+    let original = read_box_glb();
+    let expected = Glb::from_slice(&original).unwrap();
+    let json_only = Glb { header: expected.header, json: expected.json.clone(), bin: None };
+    let mut data = json_only.to_vec().unwrap();
+    append_chunk(&mut data, b"XVND", &[1, 2, 3, 4]);
+    // The chunk declares more contents than remain in the file.
+    let header = data.len() - 12;
+    LE::write_u32(&mut data[header .. header + 4], 8);
+
+    match Glb::from_reader_streaming(io::Cursor::new(&data)) {
+        Err(gltf::Error::Binary(gltf::binary::Error::ChunkLength { length: 8, length_read: 4, .. })) => {},
+        other => panic!("expected ChunkLength, got {:?}", other.map(|_| ())),
+    }
+}
@@ -0,0 +1,70 @@
+extern crate byteorder;
+extern crate gltf;
+
+use byteorder::{ByteOrder, LE};
+use std::{fs, io};
+use std::io::Read;
+
+fn read_box_glb() -> Vec<u8> {
+    let file = fs::File::open("examples/Box.glb").unwrap();
+    let mut reader = io::BufReader::new(file);
+    let mut data = vec![];
+    reader.read_to_end(&mut data).unwrap();
+    data
+}
+
+fn append_chunk(glb: &mut Vec<u8>, ty: &[u8; 4], contents: &[u8]) {
+    let mut header = [0u8; 8];
+    LE::write_u32(&mut header[0..4], contents.len() as u32);
+    header[4..8].copy_from_slice(ty);
+    glb.extend_from_slice(&header);
+    glb.extend_from_slice(contents);
+    let length = glb.len() as u32;
+    LE::write_u32(&mut glb[8..12], length);
+}
+
+#[test]
+fn skip_trailing_unknown_chunk() {
+    let original = read_box_glb();
+    let expected = gltf::binary::Glb::from_slice(&original).unwrap();
+
+    let mut modified = original.clone();
+    append_chunk(&mut modified, b"XVND", &[1, 2, 3, 4, 5, 6, 7, 8]);
+    let glb = gltf::binary::Glb::from_slice(&modified).unwrap();
+    assert_eq!(expected.json, glb.json);
+    assert_eq!(expected.bin, glb.bin);
+
+    let glb = gltf::binary::Glb::from_reader(io::Cursor::new(&modified)).unwrap();
+    assert_eq!(expected.bin, glb.bin);
+
+    let gltf = gltf::Gltf::from_slice(&modified).unwrap();
+    assert_eq!(expected.bin.map(|x| x.into_owned()), gltf.blob);
+}
+
+#[test]
+fn reject_duplicate_bin_chunk() {
+    let mut modified = read_box_glb();
+    append_chunk(&mut modified, b"BIN\0", &[0, 0, 0, 0]);
+    assert!(gltf::binary::Glb::from_slice(&modified).is_err());
+}
+
+#[test]
+fn reject_truncated_unknown_chunk() {
+    use gltf::binary::{ChunkType, Error};
+
+    let mut modified = read_box_glb();
+    append_chunk(&mut modified, b"XVND", &[1, 2, 3, 4, 5, 6, 7, 8]);
+    // The chunk declares more contents than remain in the file.
+    let header = modified.len() - 16;
+    LE::write_u32(&mut modified[header .. header + 4], 16);
+
+    match gltf::binary::Glb::from_slice(&modified) {
+        Err(gltf::Error::Binary(Error::ChunkLength {
+            ty: ChunkType::Unknown(ty),
+            length: 16,
+            length_read: 8,
+        })) => assert_eq!(&ty, b"XVND"),
+        other => panic!("expected ChunkLength, got {:?}", other),
+    }
+    assert!(gltf::binary::Glb::from_reader(io::Cursor::new(&modified)).is_err());
+}
@@ -0,0 +1,113 @@
+extern crate byteorder;
+extern crate gltf;
+
+use byteorder::{ByteOrder, LE};
+use gltf::accessor::{DataType, Dimensions};
+use gltf::mesh::util::interleave::{interleave_primitive, VertexAttribute};
+use gltf::Semantic;
+
+const JSON: &'static str = r#"{
+    "asset": { "version": "2.0" },
+    "buffers": [ { "byteLength": 48 } ],
+    "bufferViews": [
+        { "buffer": 0, "byteOffset": 0, "byteLength": 24 },
+        { "buffer": 0, "byteOffset": 24, "byteLength": 8, "byteStride": 4 },
+        { "buffer": 0, "byteOffset": 32, "byteLength": 8 },
+        { "buffer": 0, "byteOffset": 40, "byteLength": 1 },
+        { "buffer": 0, "byteOffset": 44, "byteLength": 4 }
+    ],
+    "accessors": [
+        { "bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC3",
+          "min": [0.0, 0.0, 0.0], "max": [1.0, 1.0, 1.0] },
+        { "bufferView": 1, "componentType": 5121, "normalized": true, "count": 2, "type": "VEC3" },
+        { "bufferView": 2, "componentType": 5123, "normalized": true, "count": 2, "type": "VEC2",
+          "sparse": {
+              "count": 1,
+              "indices": { "bufferView": 3, "componentType": 5121 },
+              "values": { "bufferView": 4 }
+          } }
+    ],
+    "meshes": [
+        {
+            "primitives": [
+                { "attributes": { "POSITION": 0, "COLOR_0": 1, "TEXCOORD_0": 2 } }
+            ]
+        }
+    ]
+}"#;
+
+fn buffer_data() -> Vec<u8> {
+    let mut data = vec![0xFF; 48];
+    LE::write_f32_into(&[0.0, 0.0, 0.0, 1.0, 1.0, 1.0], &mut data[0 .. 24]);
+    data[24 .. 27].copy_from_slice(&[1, 2, 3]);
+    data[28 .. 31].copy_from_slice(&[4, 5, 6]);
+    LE::write_u16_into(&[10, 20, 30, 40], &mut data[32 .. 40]);
+    data[40] = 1;
+    LE::write_u16_into(&[50, 60], &mut data[44 .. 48]);
+    data
+}
+
+#[test]
+fn interleave_attributes() {
+    let gltf = gltf::Gltf::from_slice(JSON.as_bytes()).unwrap();
+    let data = buffer_data();
+    let mesh = gltf.meshes().nth(0).unwrap();
+    let primitive = mesh.primitives().nth(0).unwrap();
+    let reader = primitive.reader(|_| Some(&data));
+
+    let semantics = [Semantic::TexCoords(0), Semantic::Colors(0), Semantic::Positions];
+    let (vertices, layout) = interleave_primitive(&reader, &semantics).unwrap();
+    assert_eq!(layout.stride, 20);
+    assert_eq!(layout.count, 2);
+    assert_eq!(layout.attributes, [
+        VertexAttribute {
+            semantic: Semantic::TexCoords(0),
+            offset: 0,
+            data_type: DataType::U16,
+            dimensions: Dimensions::Vec2,
+            normalized: true,
+        },
+        VertexAttribute {
+            semantic: Semantic::Colors(0),
+            offset: 4,
+            data_type: DataType::U8,
+            dimensions: Dimensions::Vec3,
+            normalized: true,
+        },
+        VertexAttribute {
+            semantic: Semantic::Positions,
+            offset: 8,
+            data_type: DataType::F32,
+            dimensions: Dimensions::Vec3,
+            normalized: false,
+        },
+    ]);
+
+    assert_eq!(vertices.len(), 40);
+    for (vertex, (tex_coord, color, position)) in vertices.chunks(20).zip(&[
+        ([10, 20], [1, 2, 3], [0.0, 0.0, 0.0]),
+        ([50, 60], [4, 5, 6], [1.0, 1.0, 1.0]),
+    ]) {
+        let mut tex_coords = [0; 2];
+        LE::read_u16_into(&vertex[0 .. 4], &mut tex_coords);
+        assert_eq!(&tex_coords, tex_coord);
+        assert_eq!(&vertex[4 .. 8], &[color[0], color[1], color[2], 0]);
+        let mut positions = [0.0; 3];
+        LE::read_f32_into(&vertex[8 .. 20], &mut positions);
+        assert_eq!(&positions, position);
+    }
+}
+
+#[test]
+fn interleave_missing_attribute() {
+    let gltf = gltf::Gltf::from_slice(JSON.as_bytes()).unwrap();
+    let data = buffer_data();
+    let mesh = gltf.meshes().nth(0).unwrap();
+    let primitive = mesh.primitives().nth(0).unwrap();
+    let reader = primitive.reader(|_| Some(&data));
+
+    assert!(interleave_primitive(&reader, &[Semantic::Positions, Semantic::Normals]).is_none());
+    let (vertices, layout) = interleave_primitive(&reader, &[]).unwrap();
+    assert!(vertices.is_empty());
+    assert_eq!(layout.stride, 0);
+}
@@ -0,0 +1,170 @@
+extern crate byteorder;
+extern crate gltf;
+
+use byteorder::{ByteOrder, LE};
+
+const VERTEX_COUNT: usize = 3;
+
+const JSON: &'static str = r#"{
+    "asset": { "version": "2.0" },
+    "buffers": [ { "byteLength": 120 } ],
+    "bufferViews": [
+        { "buffer": 0, "byteOffset": 0, "byteLength": 96, "byteStride": 32, "target": 34962 },
+        { "buffer": 0, "byteOffset": 96, "byteLength": 24, "byteStride": 8, "target": 34962 }
+    ],
+    "accessors": [
+        { "bufferView": 0, "byteOffset": 0, "componentType": 5126, "count": 3, "type": "VEC3",
+          "min": [0.0, 0.0, 0.0], "max": [2.0, 2.0, 2.0] },
+        { "bufferView": 0, "byteOffset": 12, "componentType": 5126, "count": 3, "type": "VEC3" },
+        { "bufferView": 0, "byteOffset": 24, "componentType": 5126, "count": 3, "type": "VEC2" },
+        { "bufferView": 1, "byteOffset": 0, "componentType": 5121, "normalized": true, "count": 3, "type": "VEC4" },
+        { "bufferView": 1, "byteOffset": 4, "componentType": 5123, "normalized": true, "count": 3, "type": "VEC2" }
+    ],
+    "meshes": [
+        {
+            "primitives": [
+                {
+                    "attributes": {
+                        "POSITION": 0,
+                        "NORMAL": 1,
+                        "TEXCOORD_0": 2,
+                        "COLOR_0": 3,
+                        "TEXCOORD_1": 4
+                    }
+                }
+            ]
+        }
+    ]
+}"#;
+
+fn position(i: usize) -> [f32; 3] {
+    [i as f32, i as f32, i as f32]
+}
+
+fn normal(i: usize) -> [f32; 3] {
+    [0.0, 0.0, if i % 2 == 0 { 1.0 } else { -1.0 }]
+}
+
+fn tex_coord_0(i: usize) -> [f32; 2] {
+    [0.25 * i as f32, 1.0 - 0.25 * i as f32]
+}
+
+fn color(i: usize) -> [u8; 4] {
+    [10 * i as u8, 20 * i as u8, 30 * i as u8, 255]
+}
+
+fn tex_coord_1(i: usize) -> [u16; 2] {
+    [1000 * i as u16, 65535 - 1000 * i as u16]
+}
+
+fn buffer_data() -> Vec<u8> {
+    let mut data = vec![0xFF; 120];
+    for i in 0 .. VERTEX_COUNT {
+        // 32-byte stride: position (12), normal (12), texture co-ordinate (8).
+        let vertex = &mut data[32 * i .. 32 * (i + 1)];
+        LE::write_f32_into(&position(i), &mut vertex[0 .. 12]);
+        LE::write_f32_into(&normal(i), &mut vertex[12 .. 24]);
+        LE::write_f32_into(&tex_coord_0(i), &mut vertex[24 .. 32]);
+    }
+    for i in 0 .. VERTEX_COUNT {
+        // 8-byte stride: color (4), texture co-ordinate (4).
+        let vertex = &mut data[96 + 8 * i .. 96 + 8 * (i + 1)];
+        vertex[0 .. 4].copy_from_slice(&color(i));
+        LE::write_u16_into(&tex_coord_1(i), &mut vertex[4 .. 8]);
+    }
+    data
+}
+
+#[test]
+fn read_interleaved_attributes() {
+    let gltf = gltf::Gltf::from_slice(JSON.as_bytes()).unwrap();
+    let data = buffer_data();
+    let mesh = gltf.meshes().nth(0).unwrap();
+    let primitive = mesh.primitives().nth(0).unwrap();
+    let reader = primitive.reader(|_| Some(&data));
+
+    let positions: Vec<_> = reader.read_positions().unwrap().collect();
+    assert_eq!(positions, (0 .. VERTEX_COUNT).map(position).collect::<Vec<_>>());
+
+    let normals: Vec<_> = reader.read_normals().unwrap().collect();
+    assert_eq!(normals, (0 .. VERTEX_COUNT).map(normal).collect::<Vec<_>>());
+
+    let tex_coords: Vec<_> = reader.read_tex_coords(0).unwrap().into_f32().collect();
+    assert_eq!(tex_coords, (0 .. VERTEX_COUNT).map(tex_coord_0).collect::<Vec<_>>());
+
+    let colors: Vec<_> = reader.read_colors(0).unwrap().into_rgba_u8().collect();
+    assert_eq!(colors, (0 .. VERTEX_COUNT).map(color).collect::<Vec<_>>());
+
+    let tex_coords: Vec<_> = reader.read_tex_coords(1).unwrap().into_u16().collect();
+    assert_eq!(tex_coords, (0 .. VERTEX_COUNT).map(tex_coord_1).collect::<Vec<_>>());
+}
+
+#[test]
+fn seek_interleaved_tex_coords() {
+    use gltf::mesh::util::ReadTexCoords;
+
+    let gltf = gltf::Gltf::from_slice(JSON.as_bytes()).unwrap();
+    let data = buffer_data();
+    let mesh = gltf.meshes().nth(0).unwrap();
+    let primitive = mesh.primitives().nth(0).unwrap();
+    let reader = primitive.reader(|_| Some(&data));
+
+    let iter = match reader.read_tex_coords(0).unwrap() {
+        ReadTexCoords::F32(iter) => iter,
+        _ => panic!("expected f32 texture co-ordinates"),
+    };
+    assert_eq!(VERTEX_COUNT, iter.len());
+    assert_eq!(Some(tex_coord_0(2)), iter.clone().last());
+    assert_eq!(Some(tex_coord_0(1)), iter.clone().nth(1));
+    assert_eq!(None, iter.clone().nth(VERTEX_COUNT));
+
+    let mut iter = iter;
+    assert_eq!(Some(tex_coord_0(0)), iter.next());
+    assert_eq!(VERTEX_COUNT - 1, iter.len());
+    assert_eq!(Some(tex_coord_0(2)), iter.nth(1));
+    assert_eq!(None, iter.next());
+}
+
+#[test]
+fn read_padded_interleaved_attributes() {
+    // A 36-byte stride with 4 bytes of padding after each vertex, in a buffer
+    // view that starts 4 bytes into the buffer and ends with the texture
+    // co-ordinate of the last vertex, so reading a full stride past it fails.
+    let json = r#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 108 } ],
+        "bufferViews": [
+            { "buffer": 0, "byteOffset": 4, "byteLength": 104, "byteStride": 36, "target": 34962 }
+        ],
+        "accessors": [
+            { "bufferView": 0, "byteOffset": 0, "componentType": 5126, "count": 3, "type": "VEC3",
+              "min": [0.0, 0.0, 0.0], "max": [2.0, 2.0, 2.0] },
+            { "bufferView": 0, "byteOffset": 12, "componentType": 5126, "count": 3, "type": "VEC3" },
+            { "bufferView": 0, "byteOffset": 24, "componentType": 5126, "count": 3, "type": "VEC2" }
+        ],
+        "meshes": [
+            { "primitives": [ { "attributes": { "POSITION": 0, "NORMAL": 1, "TEXCOORD_0": 2 } } ] }
+        ]
+    }"#;
+    let mut data = vec![0xFF; 108];
+    for i in 0 .. VERTEX_COUNT {
+        let vertex = &mut data[4 + 36 * i ..][.. 32];
+        LE::write_f32_into(&position(i), &mut vertex[0 .. 12]);
+        LE::write_f32_into(&normal(i), &mut vertex[12 .. 24]);
+        LE::write_f32_into(&tex_coord_0(i), &mut vertex[24 .. 32]);
+    }
+
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+    let mesh = gltf.meshes().nth(0).unwrap();
+    let primitive = mesh.primitives().nth(0).unwrap();
+    let reader = primitive.reader(|_| Some(&data));
+
+    let positions: Vec<_> = reader.read_positions().unwrap().collect();
+    assert_eq!(positions, (0 .. VERTEX_COUNT).map(position).collect::<Vec<_>>());
+
+    let normals: Vec<_> = reader.read_normals().unwrap().collect();
+    assert_eq!(normals, (0 .. VERTEX_COUNT).map(normal).collect::<Vec<_>>());
+
+    let tex_coords: Vec<_> = reader.read_tex_coords(0).unwrap().into_f32().collect();
+    assert_eq!(tex_coords, (0 .. VERTEX_COUNT).map(tex_coord_0).collect::<Vec<_>>());
+}
@@ -0,0 +1,80 @@
+extern crate gltf;
+
+use gltf::Normalize;
+
+#[test]
+fn normalize_endpoints_to_f32() {
+    let signed: [(f32, f32); 8] = [
+        (i8::min_value().normalize(), -1.0),
+        ((-127i8).normalize(), -1.0),
+        (0i8.normalize(), 0.0),
+        (127i8.normalize(), 1.0),
+        (i16::min_value().normalize(), -1.0),
+        ((-32767i16).normalize(), -1.0),
+        (0i16.normalize(), 0.0),
+        (32767i16.normalize(), 1.0),
+    ];
+    let unsigned: [(f32, f32); 4] = [
+        (0u8.normalize(), 0.0),
+        (255u8.normalize(), 1.0),
+        (0u16.normalize(), 0.0),
+        (65535u16.normalize(), 1.0),
+    ];
+    for &(actual, expected) in signed.iter().chain(unsigned.iter()) {
+        assert_eq!(actual, expected);
+    }
+}
+
+#[test]
+fn normalize_endpoints_between_integers() {
+    // Maximum values map to maximum values, and negative values clamp to
+    // zero when converted to unsigned types.
+    let u8s: [(u8, u8); 6] = [
+        (127i8.normalize(), 255),
+        ((-128i8).normalize(), 0),
+        (32767i16.normalize(), 255),
+        (65535u16.normalize(), 255),
+        (1.0f32.normalize(), 255),
+        (2.0f32.normalize(), 255),
+    ];
+    let u16s: [(u16, u16); 4] = [
+        (255u8.normalize(), 65535),
+        (127i8.normalize(), 65535),
+        (32767i16.normalize(), 65535),
+        ((-1i16).normalize(), 0),
+    ];
+    let i8s: [(i8, i8); 5] = [
+        (255u8.normalize(), 127),
+        (65535u16.normalize(), 127),
+        ((-32767i16).normalize(), -127),
+        ((-1.0f32).normalize(), -127),
+        ((-2.0f32).normalize(), -127),
+    ];
+    let i16s: [(i16, i16); 4] = [
+        (127i8.normalize(), 32767),
+        ((-127i8).normalize(), -32767),
+        (255u8.normalize(), 32767),
+        (65535u16.normalize(), 32767),
+    ];
+    for &(actual, expected) in &u8s {
+        assert_eq!(actual, expected);
+    }
+    for &(actual, expected) in &u16s {
+        assert_eq!(actual, expected);
+    }
+    for &(actual, expected) in &i8s {
+        assert_eq!(actual, expected);
+    }
+    for &(actual, expected) in &i16s {
+        assert_eq!(actual, expected);
+    }
+}
+
+#[test]
+fn normalize_rounds_to_nearest() {
+    // 51 / 255 is not exact in `f32`, so truncation would yield 50.
+    let x: f32 = 51u8.normalize();
+    assert_eq!(Normalize::<u8>::normalize(x), 51);
+    assert_eq!(Normalize::<u8>::normalize(0.5f32), 128);
+    assert_eq!(Normalize::<u16>::normalize(128u8), 32896);
+}
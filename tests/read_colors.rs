@@ -0,0 +1,65 @@
+extern crate gltf;
+
+const JSON: &'static str = r#"{
+    "asset": { "version": "2.0" },
+    "buffers": [ { "byteLength": 44 } ],
+    "bufferViews": [
+        { "buffer": 0, "byteOffset": 0, "byteLength": 36 },
+        { "buffer": 0, "byteOffset": 36, "byteLength": 8 }
+    ],
+    "accessors": [
+        { "bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC3",
+          "min": [0.0, 0.0, 0.0], "max": [1.0, 1.0, 1.0] },
+        { "bufferView": 1, "componentType": 5121, "normalized": true, "count": 2, "type": "VEC4" }
+    ],
+    "meshes": [
+        { "primitives": [ { "attributes": { "POSITION": 0, "COLOR_0": 1 } } ] }
+    ]
+}"#;
+
+fn buffer_data() -> Vec<u8> {
+    let mut data = vec![0; 44];
+    data[36 .. 44].copy_from_slice(&[128, 0, 255, 128, 255, 128, 0, 255]);
+    data
+}
+
+#[test]
+fn read_colors_linear_by_default() {
+    let gltf = gltf::Gltf::from_slice(JSON.as_bytes()).unwrap();
+    let data = buffer_data();
+    let mesh = gltf.meshes().nth(0).unwrap();
+    let primitive = mesh.primitives().nth(0).unwrap();
+    let reader = primitive.reader(|_| Some(&data));
+    let colors: Vec<_> = reader.read_colors(0).unwrap().into_rgba_u8().collect();
+    assert_eq!(colors, [[128, 0, 255, 128], [255, 128, 0, 255]]);
+}
+
+#[test]
+fn read_colors_srgb_to_linear() {
+    let gltf = gltf::Gltf::from_slice(JSON.as_bytes()).unwrap();
+    let data = buffer_data();
+    let mesh = gltf.meshes().nth(0).unwrap();
+    let primitive = mesh.primitives().nth(0).unwrap();
+    let reader = primitive.reader(|_| Some(&data));
+
+    let colors = reader.read_colors(0).unwrap().into_rgba_u8().srgb_to_linear();
+    assert_eq!(colors.len(), 2);
+    // ((128 / 255 + 0.055) / 1.055) ^ 2.4 * 255 = 55.0
+    assert_eq!(colors.collect::<Vec<_>>(), [[55, 0, 255, 128], [255, 55, 0, 255]]);
+}
+
+#[test]
+fn read_colors_ignores_missing_normalized_flag() {
+    // Integer colors must be normalized, so the reader normalizes them even
+    // when the flag is missing, which strict loading rejects.
+    let json = JSON.replace(r#""normalized": true, "#, "");
+    assert!(gltf::Gltf::from_slice(json.as_bytes()).is_err());
+
+    let gltf = gltf::Gltf::from_slice_without_validation(json.as_bytes()).unwrap();
+    let data = buffer_data();
+    let mesh = gltf.meshes().nth(0).unwrap();
+    let primitive = mesh.primitives().nth(0).unwrap();
+    let reader = primitive.reader(|_| Some(&data));
+    let colors: Vec<_> = reader.read_colors(0).unwrap().into_rgba_f32().collect();
+    assert_eq!(colors[1], [1.0, 128.0 / 255.0, 0.0, 1.0]);
+}
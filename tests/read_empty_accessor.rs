@@ -0,0 +1,65 @@
+extern crate gltf;
+
+use gltf::mesh::Semantic;
+
+#[test]
+fn read_zero_count_accessors() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 32 } ],
+        "bufferViews": [
+            { "buffer": 0, "byteLength": 32, "byteStride": 16 },
+            { "buffer": 0, "byteOffset": 28, "byteLength": 4 }
+        ],
+        "accessors": [
+            { "bufferView": 0, "componentType": 5126, "count": 0, "type": "VEC3" },
+            { "bufferView": 0, "byteOffset": 12, "componentType": 5123, "count": 0,
+              "type": "VEC2", "normalized": true },
+            { "bufferView": 0, "componentType": 5121, "count": 0, "type": "VEC4",
+              "normalized": true },
+            { "bufferView": 1, "byteOffset": 4, "componentType": 5125, "count": 0,
+              "type": "SCALAR" }
+        ],
+        "meshes": [
+            {
+                "primitives": [
+                    {
+                        "attributes": {
+                            "POSITION": 0,
+                            "NORMAL": 0,
+                            "TEXCOORD_0": 1,
+                            "COLOR_0": 2
+                        },
+                        "indices": 3
+                    }
+                ]
+            }
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice_without_validation(json).unwrap();
+    let data = [0u8; 32];
+    let mesh = gltf.meshes().nth(0).unwrap();
+    let primitive = mesh.primitives().nth(0).unwrap();
+    for (_, accessor) in primitive.attributes() {
+        assert_eq!(accessor.count(), 0);
+    }
+
+    let reader = primitive.reader(|_| Some(&data));
+    let positions = reader.read_positions().unwrap();
+    assert_eq!(positions.len(), 0);
+    assert_eq!(positions.count(), 0);
+    assert_eq!(reader.read_normals().unwrap().next(), None);
+    let tex_coords = reader.read_tex_coords(0).unwrap().into_f32();
+    assert_eq!(tex_coords.len(), 0);
+    assert_eq!(tex_coords.count(), 0);
+    assert_eq!(reader.read_colors(0).unwrap().into_rgba_f32().next(), None);
+
+    let indices = reader.read_indices().unwrap().into_u32();
+    assert_eq!(indices.len(), 0);
+    assert_eq!(indices.count(), 0);
+    assert_eq!(reader.read_indices_u32().unwrap().next(), None);
+
+    let positions = primitive.get(&Semantic::Positions).unwrap();
+    let items: Vec<[f32; 3]> = positions.iter(|_| Some(&data[..])).unwrap().collect();
+    assert!(items.is_empty());
+}
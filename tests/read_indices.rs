@@ -0,0 +1,145 @@
+extern crate gltf;
+
+#[test]
+fn read_indices_u32_indexed() {
+    let (document, buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    for mesh in document.meshes() {
+        for primitive in mesh.primitives() {
+            let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+            let expected: Vec<u32> = reader.read_indices().unwrap().into_u32().collect();
+            let indices = reader.read_indices_u32().unwrap();
+            assert_eq!(expected.len(), indices.len());
+            assert_eq!(expected, indices.collect::<Vec<_>>());
+        }
+    }
+}
+
+#[test]
+fn read_indices_u32_non_indexed() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 36 } ],
+        "bufferViews": [ { "buffer": 0, "byteLength": 36 } ],
+        "accessors": [
+            { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3",
+              "min": [0.0, 0.0, 0.0], "max": [1.0, 1.0, 0.0] }
+        ],
+        "meshes": [
+            {
+                "primitives": [
+                    { "attributes": { "POSITION": 0 } }
+                ]
+            }
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    let data = [0u8; 36];
+    let mesh = gltf.meshes().nth(0).unwrap();
+
+    let primitive = mesh.primitives().nth(0).unwrap();
+    let reader = primitive.reader(|_| Some(&data));
+    assert!(reader.read_indices().is_none());
+    let indices = reader.read_indices_u32().unwrap();
+    assert_eq!(3, indices.len());
+    assert_eq!(vec![0, 1, 2], indices.collect::<Vec<_>>());
+}
+
+#[test]
+fn index_bounds_from_accessor() {
+    use gltf::mesh::Bounds;
+
+    let (document, buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    let mesh = document.meshes().nth(0).unwrap();
+    let primitive = mesh.primitives().nth(0).unwrap();
+    let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+    assert_eq!(Some(Bounds { min: 0, max: 23 }), reader.index_bounds());
+}
+
+#[test]
+fn index_bounds_by_scanning() {
+    use gltf::mesh::Bounds;
+
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 44 } ],
+        "bufferViews": [
+            { "buffer": 0, "byteLength": 36 },
+            { "buffer": 0, "byteOffset": 36, "byteLength": 8 }
+        ],
+        "accessors": [
+            { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3",
+              "min": [0.0, 0.0, 0.0], "max": [1.0, 1.0, 0.0] },
+            { "bufferView": 1, "componentType": 5123, "count": 4, "type": "SCALAR" }
+        ],
+        "meshes": [
+            {
+                "primitives": [
+                    { "attributes": { "POSITION": 0 }, "indices": 1 },
+                    { "attributes": { "POSITION": 0 } }
+                ]
+            }
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    let mut data = vec![0u8; 44];
+    for (i, index) in [7u8, 2, 9, 4].iter().enumerate() {
+        data[36 + 2 * i] = *index;
+    }
+    let mesh = gltf.meshes().nth(0).unwrap();
+
+    let primitive = mesh.primitives().nth(0).unwrap();
+    let reader = primitive.reader(|_| Some(&data));
+    assert_eq!(Some(Bounds { min: 2, max: 9 }), reader.index_bounds());
+
+    let primitive = mesh.primitives().nth(1).unwrap();
+    let reader = primitive.reader(|_| Some(&data));
+    assert_eq!(Some(Bounds { min: 0, max: 2 }), reader.index_bounds());
+}
+
+#[test]
+fn indices_accessor_shared_between_primitives() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 42 } ],
+        "bufferViews": [
+            { "buffer": 0, "byteLength": 36 },
+            { "buffer": 0, "byteOffset": 36, "byteLength": 6, "target": 34963 }
+        ],
+        "accessors": [
+            { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3",
+              "min": [0.0, 0.0, 0.0], "max": [1.0, 1.0, 0.0] },
+            { "bufferView": 1, "componentType": 5123, "count": 3, "type": "SCALAR" }
+        ],
+        "meshes": [
+            {
+                "primitives": [
+                    { "attributes": { "POSITION": 0 }, "indices": 1 },
+                    { "attributes": { "POSITION": 0 }, "indices": 1 },
+                    { "attributes": { "POSITION": 0 } }
+                ]
+            }
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    let mut data = vec![0u8; 42];
+    data[38] = 1;
+    data[40] = 2;
+    let mesh = gltf.meshes().nth(0).unwrap();
+    let mut primitives = mesh.primitives();
+    let (first, second, third) = (
+        primitives.next().unwrap(),
+        primitives.next().unwrap(),
+        primitives.next().unwrap(),
+    );
+
+    let accessor = first.indices().unwrap();
+    assert_eq!(accessor.index(), second.indices().unwrap().index());
+    assert_eq!(accessor.view().unwrap().index(), 1);
+    assert_eq!(accessor.count(), 3);
+    assert!(third.indices().is_none());
+
+    let reader = first.reader(|_| Some(&data));
+    let indices: Vec<u32> = reader.read_indices().unwrap().into_u32().collect();
+    assert_eq!(vec![0, 1, 2], indices);
+    assert!(third.reader(|_| Some(&data)).read_indices().is_none());
+}
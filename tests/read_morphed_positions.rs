@@ -0,0 +1,68 @@
+extern crate byteorder;
+extern crate gltf;
+
+use byteorder::{ByteOrder, LE};
+
+const JSON: &'static str = r#"{
+    "asset": { "version": "2.0" },
+    "buffers": [ { "byteLength": 72 } ],
+    "bufferViews": [
+        { "buffer": 0, "byteOffset": 0, "byteLength": 24 },
+        { "buffer": 0, "byteOffset": 24, "byteLength": 24 },
+        { "buffer": 0, "byteOffset": 48, "byteLength": 24 }
+    ],
+    "accessors": [
+        { "bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC3",
+          "min": [0.0, 0.0, 0.0], "max": [1.0, 1.0, 1.0] },
+        { "bufferView": 1, "componentType": 5126, "count": 2, "type": "VEC3" },
+        { "bufferView": 2, "componentType": 5126, "count": 2, "type": "VEC3" }
+    ],
+    "meshes": [
+        {
+            "primitives": [
+                {
+                    "attributes": { "POSITION": 0 },
+                    "targets": [ { "POSITION": 1 }, { "NORMAL": 2 }, { "POSITION": 2 } ]
+                }
+            ],
+            "weights": [ 0.5, 1.0, 0.25 ]
+        }
+    ]
+}"#;
+
+fn buffer_data() -> Vec<u8> {
+    let mut data = vec![0; 72];
+    LE::write_f32_into(
+        &[
+            0.0, 0.0, 0.0,   1.0, 1.0, 1.0,
+            2.0, 0.0, 0.0,   0.0, 2.0, 0.0,
+            0.0, 0.0, 4.0,   4.0, 0.0, 0.0,
+        ],
+        &mut data,
+    );
+    data
+}
+
+#[test]
+fn read_morphed_positions() {
+    let gltf = gltf::Gltf::from_slice(JSON.as_bytes()).unwrap();
+    let data = buffer_data();
+    let mesh = gltf.meshes().nth(0).unwrap();
+    let primitive = mesh.primitives().nth(0).unwrap();
+    let reader = primitive.reader(|_| Some(&data));
+
+    let positions = reader.read_morphed_positions(mesh.weights().unwrap()).unwrap();
+    assert_eq!(positions, [[1.0, 0.0, 1.0], [2.0, 2.0, 1.0]]);
+    let positions = reader.read_morphed_positions(&[0.0, 0.0, 0.0]).unwrap();
+    assert_eq!(positions, [[0.0, 0.0, 0.0], [1.0, 1.0, 1.0]]);
+}
+
+#[test]
+fn read_morphed_positions_checks_weight_count() {
+    let gltf = gltf::Gltf::from_slice(JSON.as_bytes()).unwrap();
+    let data = buffer_data();
+    let mesh = gltf.meshes().nth(0).unwrap();
+    let primitive = mesh.primitives().nth(0).unwrap();
+    let reader = primitive.reader(|_| Some(&data));
+    assert!(reader.read_morphed_positions(&[1.0, 1.0]).is_none());
+}
@@ -0,0 +1,177 @@
+extern crate byteorder;
+extern crate gltf;
+
+use byteorder::{ByteOrder, LE};
+
+const JSON: &'static str = r#"{
+    "asset": { "version": "2.0" },
+    "extensionsUsed": [ "KHR_mesh_quantization" ],
+    "extensionsRequired": [ "KHR_mesh_quantization" ],
+    "buffers": [ { "byteLength": 112 } ],
+    "bufferViews": [
+        { "buffer": 0, "byteOffset": 0, "byteLength": 64, "byteStride": 8 },
+        { "buffer": 0, "byteOffset": 64, "byteLength": 32, "byteStride": 4 },
+        { "buffer": 0, "byteOffset": 96, "byteLength": 16 }
+    ],
+    "accessors": [
+        { "bufferView": 0, "componentType": 5122, "count": 8, "type": "VEC3",
+          "min": [-1, -1, -1], "max": [1, 1, 1] },
+        { "bufferView": 1, "componentType": 5120, "normalized": true, "count": 8, "type": "VEC3" },
+        { "bufferView": 2, "componentType": 5121, "count": 8, "type": "VEC2" }
+    ],
+    "meshes": [
+        {
+            "primitives": [
+                { "attributes": { "POSITION": 0, "NORMAL": 1, "TEXCOORD_0": 2 } }
+            ]
+        }
+    ]
+}"#;
+
+/// The corners of a cube.
+const CORNERS: [[i16; 3]; 8] = [
+    [-1, -1, -1], [1, -1, -1], [-1, 1, -1], [1, 1, -1],
+    [-1, -1, 1], [1, -1, 1], [-1, 1, 1], [1, 1, 1],
+];
+
+fn buffer_data() -> Vec<u8> {
+    let mut data = vec![0; 112];
+    for (i, corner) in CORNERS.iter().enumerate() {
+        LE::write_i16_into(corner, &mut data[8 * i .. 8 * i + 6]);
+        // Each corner has the normal of the face perpendicular to z.
+        data[64 + 4 * i + 2] = (corner[2] as i8 * 127) as u8;
+        data[96 + 2 * i] = (corner[0] + 1) as u8;
+        data[96 + 2 * i + 1] = (corner[1] + 1) as u8;
+    }
+    data
+}
+
+#[test]
+fn read_quantized_cube() {
+    let gltf = gltf::Gltf::from_slice(JSON.as_bytes()).unwrap();
+    let data = buffer_data();
+    let mesh = gltf.meshes().nth(0).unwrap();
+    let primitive = mesh.primitives().nth(0).unwrap();
+    let reader = primitive.reader(|_| Some(&data));
+
+    let positions: Vec<_> = reader.read_positions().unwrap().collect();
+    let expected: Vec<_> = CORNERS
+        .iter()
+        .map(|c| [c[0] as f32, c[1] as f32, c[2] as f32])
+        .collect();
+    assert_eq!(positions, expected);
+
+    let normals: Vec<_> = reader.read_normals().unwrap().collect();
+    let expected: Vec<_> = CORNERS.iter().map(|c| [0.0, 0.0, c[2] as f32]).collect();
+    assert_eq!(normals, expected);
+
+    let tex_coords: Vec<_> = reader.read_tex_coords(0).unwrap().into_f32().collect();
+    let expected: Vec<_> = CORNERS
+        .iter()
+        .map(|c| [(c[0] + 1) as f32, (c[1] + 1) as f32])
+        .collect();
+    assert_eq!(tex_coords, expected);
+}
+
+#[test]
+fn reject_quantized_cube_without_extension() {
+    let json = JSON.replace(r#""extensionsRequired": [ "KHR_mesh_quantization" ],"#, "");
+    assert!(gltf::Gltf::from_slice(json.as_bytes()).is_err());
+}
+
+const MORPH_JSON: &'static str = r#"{
+    "asset": { "version": "2.0" },
+    "extensionsUsed": [ "KHR_mesh_quantization" ],
+    "extensionsRequired": [ "KHR_mesh_quantization" ],
+    "buffers": [ { "byteLength": 48 } ],
+    "bufferViews": [
+        { "buffer": 0, "byteOffset": 0, "byteLength": 16, "byteStride": 8 },
+        { "buffer": 0, "byteOffset": 16, "byteLength": 8, "byteStride": 4 },
+        { "buffer": 0, "byteOffset": 24, "byteLength": 16, "byteStride": 8 },
+        { "buffer": 0, "byteOffset": 40, "byteLength": 8, "byteStride": 4 }
+    ],
+    "accessors": [
+        { "bufferView": 0, "componentType": 5122, "count": 2, "type": "VEC3",
+          "min": [0, 0, 0], "max": [2, 4, 6] },
+        { "bufferView": 1, "componentType": 5120, "normalized": true, "count": 2, "type": "VEC3" },
+        { "bufferView": 2, "componentType": 5122, "normalized": true, "count": 2, "type": "VEC3" },
+        { "bufferView": 3, "componentType": 5120, "normalized": true, "count": 2, "type": "VEC3" }
+    ],
+    "meshes": [
+        {
+            "primitives": [
+                {
+                    "attributes": { "POSITION": 0 },
+                    "targets": [ { "POSITION": 1, "NORMAL": 2, "TANGENT": 3 } ]
+                }
+            ],
+            "weights": [ 0.5 ]
+        }
+    ]
+}"#;
+
+fn morph_buffer_data() -> Vec<u8> {
+    let mut data = vec![0; 48];
+    LE::write_i16_into(&[0, 0, 0], &mut data[0 .. 6]);
+    LE::write_i16_into(&[2, 4, 6], &mut data[8 .. 14]);
+    for (i, &x) in [127i8, 0, -127, 0, 0, 127, 0].iter().enumerate() {
+        data[16 + i] = x as u8;
+    }
+    LE::write_i16_into(&[32767, 0, 0], &mut data[24 .. 30]);
+    LE::write_i16_into(&[0, -32767, 0], &mut data[32 .. 38]);
+    for (i, &x) in [0i8, 0, 127, 0, -127, 0, 0, 0].iter().enumerate() {
+        data[40 + i] = x as u8;
+    }
+    data
+}
+
+#[test]
+fn read_quantized_morph_targets() {
+    let gltf = gltf::Gltf::from_slice(MORPH_JSON.as_bytes()).unwrap();
+    let data = morph_buffer_data();
+    let mesh = gltf.meshes().nth(0).unwrap();
+    let primitive = mesh.primitives().nth(0).unwrap();
+    let reader = primitive.reader(|_| Some(&data));
+
+    let (positions, normals, tangents) = reader.read_morph_targets().nth(0).unwrap();
+    let positions: Vec<_> = positions.unwrap().collect();
+    assert_eq!(positions, [[1.0, 0.0, -1.0], [0.0, 1.0, 0.0]]);
+    let normals: Vec<_> = normals.unwrap().collect();
+    assert_eq!(normals, [[1.0, 0.0, 0.0], [0.0, -1.0, 0.0]]);
+    let tangents: Vec<_> = tangents.unwrap().collect();
+    assert_eq!(tangents, [[0.0, 0.0, 1.0], [-1.0, 0.0, 0.0]]);
+}
+
+#[test]
+fn reject_quantized_morph_targets_without_extension() {
+    let json = MORPH_JSON.replace(r#""extensionsRequired": [ "KHR_mesh_quantization" ],"#, "");
+    assert!(gltf::Gltf::from_slice(json.as_bytes()).is_err());
+}
+
+#[test]
+fn read_quantized_morphed_positions() {
+    let data = morph_buffer_data();
+    let gltf = gltf::Gltf::from_slice(MORPH_JSON.as_bytes()).unwrap();
+    let mesh = gltf.meshes().nth(0).unwrap();
+    let primitive = mesh.primitives().nth(0).unwrap();
+    let reader = primitive.reader(|_| Some(&data));
+    let positions = reader.read_morphed_positions(mesh.weights().unwrap()).unwrap();
+    assert_eq!(positions, [[0.5, 0.0, -0.5], [2.0, 4.5, 6.0]]);
+
+    // Normalized base positions are normalized before the displacements are
+    // added.
+    let json = MORPH_JSON.replace(
+        r#""componentType": 5122, "count": 2"#,
+        r#""componentType": 5122, "normalized": true, "count": 2"#,
+    );
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+    let mesh = gltf.meshes().nth(0).unwrap();
+    let primitive = mesh.primitives().nth(0).unwrap();
+    let reader = primitive.reader(|_| Some(&data));
+    let positions = reader.read_morphed_positions(mesh.weights().unwrap()).unwrap();
+    let base = |x: f32| x / 32767.0;
+    assert_eq!(positions, [
+        [0.5, 0.0, -0.5],
+        [base(2.0), base(4.0) + 0.5, base(6.0)],
+    ]);
+}
@@ -0,0 +1,126 @@
+extern crate byteorder;
+extern crate gltf;
+
+use byteorder::{ByteOrder, LE};
+
+const JSON: &'static str = r#"{
+    "asset": { "version": "2.0" },
+    "buffers": [ { "byteLength": 72 } ],
+    "bufferViews": [
+        { "buffer": 0, "byteOffset": 0, "byteLength": 24 },
+        { "buffer": 0, "byteOffset": 24, "byteLength": 8 },
+        { "buffer": 0, "byteOffset": 32, "byteLength": 16 },
+        { "buffer": 0, "byteOffset": 48, "byteLength": 8 },
+        { "buffer": 0, "byteOffset": 56, "byteLength": 16 }
+    ],
+    "accessors": [
+        { "bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC3",
+          "min": [0.0, 0.0, 0.0], "max": [1.0, 1.0, 1.0] },
+        { "bufferView": 1, "componentType": 5121, "count": 2, "type": "VEC4" },
+        { "bufferView": 2, "componentType": 5123, "count": 2, "type": "VEC4" },
+        { "bufferView": 3, "componentType": 5121, "normalized": true, "count": 2, "type": "VEC4" },
+        { "bufferView": 4, "componentType": 5123, "normalized": true, "count": 2, "type": "VEC4" }
+    ],
+    "meshes": [
+        {
+            "primitives": [
+                { "attributes": { "POSITION": 0, "JOINTS_0": 1, "WEIGHTS_0": 3 } },
+                { "attributes": { "POSITION": 0, "JOINTS_0": 2, "WEIGHTS_0": 4 } },
+                {
+                    "attributes": {
+                        "POSITION": 0,
+                        "JOINTS_0": 1, "WEIGHTS_0": 3,
+                        "JOINTS_1": 2, "WEIGHTS_1": 4
+                    }
+                }
+            ]
+        }
+    ]
+}"#;
+
+fn buffer_data() -> Vec<u8> {
+    let mut data = vec![0; 72];
+    data[24 .. 32].copy_from_slice(&[0, 1, 2, 3, 4, 5, 6, 255]);
+    LE::write_u16_into(&[0, 1, 2, 3, 300, 0, 65535, 7], &mut data[32 .. 48]);
+    data[48 .. 56].copy_from_slice(&[255, 0, 0, 0, 51, 204, 0, 0]);
+    LE::write_u16_into(&[65535, 0, 0, 0, 0, 13107, 52428, 0], &mut data[56 .. 72]);
+    data
+}
+
+fn assert_weights_eq(actual: &[[f32; 4]], expected: &[[f32; 4]]) {
+    assert_eq!(actual.len(), expected.len());
+    for (a, b) in actual.iter().flat_map(|x| x.iter()).zip(expected.iter().flat_map(|x| x.iter())) {
+        assert!((a - b).abs() < 1e-6, "{:?} != {:?}", actual, expected);
+    }
+}
+
+#[test]
+fn read_joints_into_u16() {
+    let gltf = gltf::Gltf::from_slice(JSON.as_bytes()).unwrap();
+    let data = buffer_data();
+    let mesh = gltf.meshes().nth(0).unwrap();
+
+    let primitive = mesh.primitives().nth(0).unwrap();
+    let reader = primitive.reader(|_| Some(&data));
+    let joints: Vec<_> = reader.read_joints(0).unwrap().into_u16().collect();
+    assert_eq!(joints, [[0, 1, 2, 3], [4, 5, 6, 255]]);
+
+    let primitive = mesh.primitives().nth(1).unwrap();
+    let reader = primitive.reader(|_| Some(&data));
+    let joints: Vec<_> = reader.read_joints(0).unwrap().into_u16().collect();
+    assert_eq!(joints, [[0, 1, 2, 3], [300, 0, 65535, 7]]);
+}
+
+#[test]
+fn read_weights_into_f32() {
+    let gltf = gltf::Gltf::from_slice(JSON.as_bytes()).unwrap();
+    let data = buffer_data();
+    let mesh = gltf.meshes().nth(0).unwrap();
+
+    let primitive = mesh.primitives().nth(0).unwrap();
+    let reader = primitive.reader(|_| Some(&data));
+    let weights: Vec<_> = reader.read_weights(0).unwrap().into_f32().collect();
+    assert_weights_eq(&weights, &[[1.0, 0.0, 0.0, 0.0], [0.2, 0.8, 0.0, 0.0]]);
+
+    let primitive = mesh.primitives().nth(1).unwrap();
+    let reader = primitive.reader(|_| Some(&data));
+    let weights: Vec<_> = reader.read_weights(0).unwrap().into_f32().collect();
+    assert_weights_eq(&weights, &[[1.0, 0.0, 0.0, 0.0], [0.0, 0.2, 0.8, 0.0]]);
+}
+
+#[test]
+fn check_weight_sums() {
+    let gltf = gltf::Gltf::from_slice(JSON.as_bytes()).unwrap();
+    let data = buffer_data();
+    let mesh = gltf.meshes().nth(0).unwrap();
+
+    for primitive in mesh.primitives().take(2) {
+        primitive.reader(|_| Some(&data)).check_weight_sums(1e-3).unwrap();
+    }
+
+    // The weights of both sets are summed, giving `2.0` per vertex.
+    let primitive = mesh.primitives().nth(2).unwrap();
+    match primitive.reader(|_| Some(&data)).check_weight_sums(1e-3) {
+        Err(gltf::Error::UnnormalizedWeights { vertex, sum }) => {
+            assert_eq!(vertex, 0);
+            assert!((sum - 2.0).abs() < 1e-6);
+        },
+        other => panic!("expected unnormalized weights, got {:?}", other),
+    }
+}
+
+#[test]
+fn read_weights_ignores_missing_normalized_flag() {
+    // Integer weights must be normalized, so the reader normalizes them even
+    // when the flag is missing, which strict loading rejects.
+    let json = JSON.replace(r#""normalized": true, "#, "");
+    assert!(gltf::Gltf::from_slice(json.as_bytes()).is_err());
+
+    let gltf = gltf::Gltf::from_slice_without_validation(json.as_bytes()).unwrap();
+    let data = buffer_data();
+    let mesh = gltf.meshes().nth(0).unwrap();
+    let primitive = mesh.primitives().nth(0).unwrap();
+    let reader = primitive.reader(|_| Some(&data));
+    let weights: Vec<_> = reader.read_weights(0).unwrap().into_f32().collect();
+    assert_weights_eq(&weights, &[[1.0, 0.0, 0.0, 0.0], [0.2, 0.8, 0.0, 0.0]]);
+}
@@ -0,0 +1,56 @@
+extern crate byteorder;
+extern crate gltf;
+
+use byteorder::{ByteOrder, LE};
+
+const JSON: &'static str = r#"{
+    "asset": { "version": "2.0" },
+    "extensionsUsed": [ "KHR_mesh_quantization" ],
+    "extensionsRequired": [ "KHR_mesh_quantization" ],
+    "buffers": [ { "byteLength": 64 } ],
+    "bufferViews": [
+        { "buffer": 0, "byteOffset": 0, "byteLength": 24 },
+        { "buffer": 0, "byteOffset": 24, "byteLength": 16 },
+        { "buffer": 0, "byteOffset": 40, "byteLength": 8 }
+    ],
+    "accessors": [
+        { "bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC3",
+          "min": [0.0, 0.0, 0.0], "max": [1.0, 1.0, 1.0] },
+        { "bufferView": 1, "componentType": 5122, "normalized": true, "count": 2, "type": "VEC4" },
+        { "bufferView": 2, "componentType": 5120, "normalized": true, "count": 2, "type": "VEC4" }
+    ],
+    "meshes": [
+        {
+            "primitives": [
+                { "attributes": { "POSITION": 0, "TANGENT": 1 } },
+                { "attributes": { "POSITION": 0, "TANGENT": 2 } }
+            ]
+        }
+    ]
+}"#;
+
+fn buffer_data() -> Vec<u8> {
+    let mut data = vec![0; 64];
+    LE::write_i16_into(&[32767, 0, 0, -32767, 0, -32767, 0, 32767], &mut data[24 .. 40]);
+    for (byte, value) in data[40 .. 48].iter_mut().zip(&[0i8, 127, 0, -127, -127, 0, 0, 1]) {
+        *byte = *value as u8;
+    }
+    data
+}
+
+#[test]
+fn read_normalized_tangents_preserves_handedness() {
+    let gltf = gltf::Gltf::from_slice(JSON.as_bytes()).unwrap();
+    let data = buffer_data();
+    let mesh = gltf.meshes().nth(0).unwrap();
+
+    let primitive = mesh.primitives().nth(0).unwrap();
+    let reader = primitive.reader(|_| Some(&data));
+    let tangents: Vec<_> = reader.read_tangents().unwrap().collect();
+    assert_eq!(tangents, [[1.0, 0.0, 0.0, -1.0], [0.0, -1.0, 0.0, 1.0]]);
+
+    let primitive = mesh.primitives().nth(1).unwrap();
+    let reader = primitive.reader(|_| Some(&data));
+    let tangents: Vec<_> = reader.read_tangents().unwrap().collect();
+    assert_eq!(tangents, [[0.0, 1.0, 0.0, -1.0], [-1.0, 0.0, 0.0, 1.0]]);
+}
@@ -0,0 +1,78 @@
+extern crate byteorder;
+extern crate gltf;
+
+use byteorder::{ByteOrder, LE};
+
+const JSON: &'static str = r#"{
+    "asset": { "version": "2.0" },
+    "extensionsUsed": [ "KHR_mesh_quantization" ],
+    "extensionsRequired": [ "KHR_mesh_quantization" ],
+    "buffers": [ { "byteLength": 32 } ],
+    "bufferViews": [
+        { "buffer": 0, "byteOffset": 0, "byteLength": 24 },
+        { "buffer": 0, "byteOffset": 24, "byteLength": 8 }
+    ],
+    "accessors": [
+        { "bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC3",
+          "min": [0.0, 0.0, 0.0], "max": [1.0, 1.0, 1.0] },
+        { "bufferView": 1, "componentType": 5123, "normalized": true, "count": 2, "type": "VEC2" },
+        { "bufferView": 1, "componentType": 5123, "count": 2, "type": "VEC2" },
+        { "bufferView": 1, "componentType": 5121, "normalized": true, "count": 2, "type": "VEC2" }
+    ],
+    "meshes": [
+        {
+            "primitives": [
+                { "attributes": { "POSITION": 0, "TEXCOORD_0": 1 } },
+                { "attributes": { "POSITION": 0, "TEXCOORD_0": 2 } },
+                { "attributes": { "POSITION": 0, "TEXCOORD_0": 3 } }
+            ]
+        }
+    ]
+}"#;
+
+fn buffer_data() -> Vec<u8> {
+    let mut data = vec![0; 32];
+    LE::write_u16_into(&[0, 65535, 13107, 2], &mut data[24 .. 32]);
+    data
+}
+
+#[test]
+fn read_normalized_tex_coords() {
+    let gltf = gltf::Gltf::from_slice(JSON.as_bytes()).unwrap();
+    let data = buffer_data();
+    let mesh = gltf.meshes().nth(0).unwrap();
+    let primitive = mesh.primitives().nth(0).unwrap();
+    assert!(primitive.get(&gltf::Semantic::TexCoords(0)).unwrap().normalized());
+
+    let reader = primitive.reader(|_| Some(&data));
+    let tex_coords: Vec<_> = reader.read_tex_coords(0).unwrap().into_f32().collect();
+    assert_eq!(tex_coords, [[0.0, 1.0], [0.2, 2.0 / 65535.0]]);
+}
+
+#[test]
+fn read_unnormalized_tex_coords() {
+    let gltf = gltf::Gltf::from_slice(JSON.as_bytes()).unwrap();
+    let data = buffer_data();
+    let mesh = gltf.meshes().nth(0).unwrap();
+    let primitive = mesh.primitives().nth(1).unwrap();
+    assert!(!primitive.get(&gltf::Semantic::TexCoords(0)).unwrap().normalized());
+
+    let reader = primitive.reader(|_| Some(&data));
+    let tex_coords: Vec<_> = reader.read_tex_coords(0).unwrap().into_f32().collect();
+    assert_eq!(tex_coords, [[0.0, 65535.0], [13107.0, 2.0]]);
+}
+
+#[test]
+fn read_normalized_u8_tex_coords() {
+    let gltf = gltf::Gltf::from_slice(JSON.as_bytes()).unwrap();
+    let data = buffer_data();
+    let mesh = gltf.meshes().nth(0).unwrap();
+    let primitive = mesh.primitives().nth(2).unwrap();
+
+    let reader = primitive.reader(|_| Some(&data));
+    let tex_coords: Vec<_> = reader.read_tex_coords(0).unwrap().into_f32().collect();
+    assert_eq!(tex_coords, [[0.0, 0.0], [1.0, 1.0]]);
+    let mut iter = reader.read_tex_coords(0).unwrap().into_f32();
+    assert_eq!(iter.nth(1), Some([1.0, 1.0]));
+    assert_eq!(reader.read_tex_coords(0).unwrap().into_f32().last(), Some([1.0, 1.0]));
+}
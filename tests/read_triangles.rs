@@ -0,0 +1,80 @@
+extern crate byteorder;
+extern crate gltf;
+
+use byteorder::{ByteOrder, LE};
+
+const POSITIONS: [[f32; 3]; 4] = [
+    [0.0, 0.0, 0.0],
+    [1.0, 0.0, 0.0],
+    [0.0, 1.0, 0.0],
+    [1.0, 1.0, 0.0],
+];
+
+fn document(mode: u32) -> gltf::Gltf {
+    let json = format!(r#"{{
+        "asset": {{ "version": "2.0" }},
+        "buffers": [ {{ "byteLength": 48 }} ],
+        "bufferViews": [ {{ "buffer": 0, "byteLength": 48 }} ],
+        "accessors": [
+            {{ "bufferView": 0, "componentType": 5126, "count": 4, "type": "VEC3",
+               "min": [0.0, 0.0, 0.0], "max": [1.0, 1.0, 0.0] }}
+        ],
+        "meshes": [
+            {{
+                "primitives": [
+                    {{ "attributes": {{ "POSITION": 0 }}, "mode": {} }}
+                ]
+            }}
+        ]
+    }}"#, mode);
+    gltf::Gltf::from_slice(json.as_bytes()).unwrap()
+}
+
+fn triangles(mode: u32) -> Option<Vec<[[f32; 3]; 3]>> {
+    let mut data = [0u8; 48];
+    for (i, position) in POSITIONS.iter().enumerate() {
+        LE::write_f32_into(position, &mut data[12 * i .. 12 * (i + 1)]);
+    }
+    let gltf = document(mode);
+    let mesh = gltf.meshes().nth(0).unwrap();
+    let primitive = mesh.primitives().nth(0).unwrap();
+    let reader = primitive.reader(|_| Some(&data));
+    reader.read_triangles().map(|triangles| triangles.collect())
+}
+
+#[test]
+fn read_triangle_strip() {
+    let [a, b, c, d] = POSITIONS;
+    // Every other triangle of a strip swaps its last two vertices, so both
+    // triangles are counter-clockwise.
+    assert_eq!(triangles(5).unwrap(), [[a, b, c], [b, d, c]]);
+}
+
+#[test]
+fn read_triangle_fan() {
+    let [a, b, c, d] = POSITIONS;
+    assert_eq!(triangles(6).unwrap(), [[b, c, a], [c, d, a]]);
+}
+
+#[test]
+fn read_triangles_non_triangle_mode() {
+    assert!(triangles(1).is_none());
+    assert!(triangles(3).is_none());
+}
+
+#[test]
+fn read_triangles_indexed() {
+    let (document, buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    let mesh = document.meshes().nth(0).unwrap();
+    let primitive = mesh.primitives().nth(0).unwrap();
+    let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+    let positions: Vec<_> = reader.read_positions().unwrap().collect();
+    let indices: Vec<_> = reader.read_indices_u32().unwrap().collect();
+    let triangles: Vec<_> = reader.read_triangles().unwrap().collect();
+    assert_eq!(triangles.len(), 12);
+    for (triangle, indices) in triangles.iter().zip(indices.chunks(3)) {
+        for (&position, &index) in triangle.iter().zip(indices) {
+            assert_eq!(position, positions[index as usize]);
+        }
+    }
+}
@@ -19,4 +19,2124 @@ fn test_accessor_bounds() {
     assert_eq!(bounds, Bounds { min: [-0.03, -0.04, -0.05], max: [1.0, 1.01, 0.02]});
 }
 
+#[test]
+fn test_accessor_min_max_values() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 8 } ],
+        "bufferViews": [ { "buffer": 0, "byteLength": 8 } ],
+        "accessors": [
+            { "bufferView": 0, "componentType": 5126, "count": 1, "type": "VEC2",
+              "min": [-1.5, 0.25], "max": [2.0, 0.75] },
+            { "bufferView": 0, "componentType": 5123, "count": 4, "type": "SCALAR",
+              "min": [3], "max": [65535.0] },
+            { "bufferView": 0, "componentType": 5121, "count": 8, "type": "SCALAR" },
+            { "bufferView": 0, "componentType": 5126, "count": 2, "type": "SCALAR",
+              "min": [0.0, 1.0], "max": [1.0, 2.0] }
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    let accessors: Vec<_> = gltf.accessors().collect();
+
+    assert_eq!(accessors[0].min_values(), Some(vec![-1.5, 0.25]));
+    assert_eq!(accessors[0].max_values(), Some(vec![2.0, 0.75]));
+    assert_eq!(accessors[0].min_values_int(), None);
+
+    assert_eq!(accessors[1].min_values(), Some(vec![3.0]));
+    assert_eq!(accessors[1].min_values_int(), Some(vec![3]));
+    assert_eq!(accessors[1].max_values_int(), Some(vec![65535]));
+
+    assert_eq!(accessors[2].min_values(), None);
+    assert_eq!(accessors[2].max_values_int(), None);
+
+    // The number of values does not match the accessor dimensions.
+    assert_eq!(accessors[3].min_values(), None);
+}
+
+#[test]
+fn test_asset_metadata() {
+    let json = br#"{
+        "asset": {
+            "version": "2.0",
+            "generator": "Blender",
+            "copyright": "CC-BY"
+        }
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    assert_eq!(gltf.gltf_version(), "2.0");
+    assert_eq!(gltf.generator(), Some("Blender"));
+    assert_eq!(gltf.copyright(), Some("CC-BY"));
+    assert_eq!(gltf.asset().min_version(), None);
+    assert!(gltf.asset().is_supported());
+
+    let versions = [
+        (r#""version": "2.5""#, true),
+        (r#""version": "2.1", "minVersion": "2.0""#, true),
+        (r#""version": "2.1", "minVersion": "2.1""#, false),
+        (r#""version": "3.0""#, false),
+        (r#""version": "two""#, false),
+    ];
+    for &(asset, supported) in &versions {
+        let json = format!(r#"{{ "asset": {{ {} }} }}"#, asset);
+        let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+        assert_eq!(gltf.asset().is_supported(), supported, "{}", asset);
+    }
+}
+
+#[cfg(feature = "extensions")]
+#[test]
+fn test_extension_value() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "nodes": [
+            { "extensions": { "VENDOR_node_data": { "answer": 42 } } },
+            {}
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    let node = gltf.nodes().nth(0).unwrap();
+    let value = node.extension_value("VENDOR_node_data").unwrap();
+    assert_eq!(value["answer"], 42);
+    assert!(node.extension_value("VENDOR_other").is_none());
+    assert!(gltf.nodes().nth(1).unwrap().extension_value("VENDOR_node_data").is_none());
+}
+
+#[cfg(all(feature = "extensions", feature = "KHR_texture_basisu"))]
+#[test]
+fn test_extension_value_of_typed_extension() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "extensionsUsed": [ "KHR_texture_basisu" ],
+        "images": [ { "uri": "a.png" }, { "uri": "a.ktx2" } ],
+        "textures": [
+            { "source": 0, "extensions": { "KHR_texture_basisu": { "source": 1 } } }
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    let texture = gltf.textures().nth(0).unwrap();
+    let value = texture.extension_value("KHR_texture_basisu").unwrap();
+    assert_eq!(value["source"], 1);
+    assert!(texture.extension_value("KHR_texture_transform").is_none());
+}
+
+#[cfg(feature = "KHR_materials_clearcoat")]
+#[test]
+fn test_clearcoat() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "images": [ { "uri": "image.png" } ],
+        "textures": [ { "source": 0 }, { "source": 0 }, { "source": 0 } ],
+        "materials": [
+            {
+                "extensions": {
+                    "KHR_materials_clearcoat": {
+                        "clearcoatFactor": 1.0,
+                        "clearcoatTexture": { "index": 0 },
+                        "clearcoatRoughnessFactor": 0.25,
+                        "clearcoatRoughnessTexture": { "index": 1, "texCoord": 1 },
+                        "clearcoatNormalTexture": { "index": 2, "scale": 0.5 }
+                    }
+                }
+            },
+            { "extensions": { "KHR_materials_clearcoat": {} } },
+            {}
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    let clearcoat = gltf.materials().nth(0).unwrap().clearcoat().unwrap();
+    assert_eq!(clearcoat.clearcoat_factor(), 1.0);
+    assert_eq!(clearcoat.clearcoat_texture().unwrap().texture().index(), 0);
+    assert_eq!(clearcoat.clearcoat_roughness_factor(), 0.25);
+    let roughness_texture = clearcoat.clearcoat_roughness_texture().unwrap();
+    assert_eq!(roughness_texture.texture().index(), 1);
+    assert_eq!(roughness_texture.tex_coord(), 1);
+    let normal_texture = clearcoat.clearcoat_normal_texture().unwrap();
+    assert_eq!(normal_texture.texture().index(), 2);
+    assert_eq!(normal_texture.scale(), 0.5);
+    assert_eq!(normal_texture.tex_coord(), 0);
+
+    let clearcoat = gltf.materials().nth(1).unwrap().clearcoat().unwrap();
+    assert_eq!(clearcoat.clearcoat_factor(), 0.0);
+    assert_eq!(clearcoat.clearcoat_roughness_factor(), 0.0);
+    assert!(clearcoat.clearcoat_texture().is_none());
+    assert!(clearcoat.clearcoat_roughness_texture().is_none());
+    assert!(clearcoat.clearcoat_normal_texture().is_none());
+
+    assert!(gltf.materials().nth(2).unwrap().clearcoat().is_none());
+}
+
+#[cfg(feature = "KHR_materials_emissive_strength")]
+#[test]
+fn test_emissive_strength() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "materials": [
+            { "extensions": { "KHR_materials_emissive_strength": { "emissiveStrength": 5.0 } } },
+            { "extensions": { "KHR_materials_emissive_strength": {} } },
+            {}
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    let strengths: Vec<_> = gltf.materials().map(|m| m.emissive_strength()).collect();
+    assert_eq!(strengths, [Some(5.0), Some(1.0), None]);
+}
+
+#[cfg(feature = "KHR_materials_ior")]
+#[test]
+fn test_ior() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "materials": [
+            { "extensions": { "KHR_materials_ior": { "ior": 1.33 } } },
+            { "extensions": { "KHR_materials_ior": {} } },
+            {}
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    let iors: Vec<_> = gltf.materials().map(|m| m.ior()).collect();
+    assert_eq!(iors, [Some(1.33), Some(1.5), None]);
+}
+
+#[cfg(feature = "KHR_materials_sheen")]
+#[test]
+fn test_sheen() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "images": [ { "uri": "image.png" } ],
+        "textures": [ { "source": 0 } ],
+        "materials": [
+            {
+                "extensions": {
+                    "KHR_materials_sheen": {
+                        "sheenColorFactor": [0.5, 0.25, 1.0],
+                        "sheenColorTexture": { "index": 0 },
+                        "sheenRoughnessFactor": 0.75,
+                        "sheenRoughnessTexture": { "index": 0, "texCoord": 1 }
+                    }
+                }
+            },
+            { "extensions": { "KHR_materials_sheen": {} } },
+            {}
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    let material = gltf.materials().nth(0).unwrap();
+    let sheen = material.sheen().unwrap();
+    assert_eq!(sheen.sheen_color_factor(), [0.5, 0.25, 1.0]);
+    assert_eq!(sheen.sheen_color_texture().unwrap().tex_coord(), 0);
+    assert_eq!(sheen.sheen_roughness_factor(), 0.75);
+    assert_eq!(sheen.sheen_roughness_texture().unwrap().tex_coord(), 1);
+    assert_eq!(material.used_tex_coord_sets(), [0, 1]);
+
+    let sheen = gltf.materials().nth(1).unwrap().sheen().unwrap();
+    assert_eq!(sheen.sheen_color_factor(), [0.0, 0.0, 0.0]);
+    assert!(sheen.sheen_color_texture().is_none());
+    assert_eq!(sheen.sheen_roughness_factor(), 0.0);
+    assert!(sheen.sheen_roughness_texture().is_none());
+
+    assert!(gltf.materials().nth(2).unwrap().sheen().is_none());
+}
+
+#[cfg(feature = "KHR_materials_specular")]
+#[test]
+fn test_specular() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "images": [ { "uri": "image.png" } ],
+        "textures": [ { "source": 0 } ],
+        "materials": [
+            {
+                "extensions": {
+                    "KHR_materials_specular": {
+                        "specularFactor": 0.5,
+                        "specularTexture": { "index": 0, "texCoord": 2 },
+                        "specularColorFactor": [2.0, 1.0, 0.5],
+                        "specularColorTexture": { "index": 0 }
+                    }
+                }
+            },
+            { "extensions": { "KHR_materials_specular": {} } },
+            {}
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    let material = gltf.materials().nth(0).unwrap();
+    let specular = material.specular().unwrap();
+    assert_eq!(specular.specular_factor(), 0.5);
+    assert_eq!(specular.specular_texture().unwrap().tex_coord(), 2);
+    assert_eq!(specular.specular_color_factor(), [2.0, 1.0, 0.5]);
+    assert_eq!(specular.specular_color_texture().unwrap().tex_coord(), 0);
+    assert_eq!(material.used_tex_coord_sets(), [0, 2]);
+
+    let specular = gltf.materials().nth(1).unwrap().specular().unwrap();
+    assert_eq!(specular.specular_factor(), 1.0);
+    assert!(specular.specular_texture().is_none());
+    assert_eq!(specular.specular_color_factor(), [1.0, 1.0, 1.0]);
+    assert!(specular.specular_color_texture().is_none());
+
+    assert!(gltf.materials().nth(2).unwrap().specular().is_none());
+}
+
+#[cfg(all(feature = "KHR_materials_transmission", feature = "KHR_materials_volume"))]
+#[test]
+fn test_transmission_and_volume() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "images": [ { "uri": "image.png" } ],
+        "textures": [ { "source": 0 } ],
+        "materials": [
+            {
+                "extensions": {
+                    "KHR_materials_transmission": {
+                        "transmissionFactor": 0.75,
+                        "transmissionTexture": { "index": 0 }
+                    },
+                    "KHR_materials_volume": {
+                        "thicknessFactor": 2.0,
+                        "thicknessTexture": { "index": 0, "texCoord": 1 },
+                        "attenuationDistance": 0.5,
+                        "attenuationColor": [1.0, 0.5, 0.25]
+                    }
+                }
+            },
+            { "extensions": { "KHR_materials_volume": {} } },
+            {}
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    let material = gltf.materials().nth(0).unwrap();
+    let transmission = material.transmission().unwrap();
+    assert_eq!(transmission.transmission_factor(), 0.75);
+    assert_eq!(transmission.transmission_texture().unwrap().texture().index(), 0);
+    let volume = material.volume().unwrap();
+    assert_eq!(volume.thickness_factor(), 2.0);
+    assert_eq!(volume.thickness_texture().unwrap().tex_coord(), 1);
+    assert_eq!(volume.attenuation_distance(), 0.5);
+    assert_eq!(volume.attenuation_color(), [1.0, 0.5, 0.25]);
+
+    let material = gltf.materials().nth(1).unwrap();
+    assert!(material.transmission().is_none());
+    let volume = material.volume().unwrap();
+    assert_eq!(volume.thickness_factor(), 0.0);
+    assert!(volume.thickness_texture().is_none());
+    assert_eq!(volume.attenuation_distance(), std::f32::INFINITY);
+    assert_eq!(volume.attenuation_color(), [1.0, 1.0, 1.0]);
+
+    let material = gltf.materials().nth(2).unwrap();
+    assert!(material.transmission().is_none());
+    assert!(material.volume().is_none());
+}
+
+#[cfg(feature = "EXT_lights_image_based")]
+#[test]
+fn test_image_based_light() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "extensionsUsed": [ "EXT_lights_image_based" ],
+        "extensions": {
+            "EXT_lights_image_based": {
+                "lights": [
+                    {
+                        "intensity": 2.0,
+                        "rotation": [0.0, 1.0, 0.0, 0.0],
+                        "irradianceCoefficients": [
+                            [0.0, 0.1, 0.2], [1.0, 1.1, 1.2], [2.0, 2.1, 2.2],
+                            [3.0, 3.1, 3.2], [4.0, 4.1, 4.2], [5.0, 5.1, 5.2],
+                            [6.0, 6.1, 6.2], [7.0, 7.1, 7.2], [8.0, 8.1, 8.2]
+                        ],
+                        "specularImageSize": 256,
+                        "specularImages": [
+                            [0, 1, 2, 3, 4, 5],
+                            [6, 7, 8, 9, 10, 11]
+                        ]
+                    }
+                ]
+            }
+        },
+        "images": [
+            { "uri": "0.png" }, { "uri": "1.png" }, { "uri": "2.png" },
+            { "uri": "3.png" }, { "uri": "4.png" }, { "uri": "5.png" },
+            { "uri": "6.png" }, { "uri": "7.png" }, { "uri": "8.png" },
+            { "uri": "9.png" }, { "uri": "10.png" }, { "uri": "11.png" }
+        ],
+        "scenes": [
+            { "nodes": [], "extensions": { "EXT_lights_image_based": { "light": 0 } } },
+            { "nodes": [] }
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    assert_eq!(gltf.image_based_lights().len(), 1);
+
+    let scene = gltf.scenes().nth(0).unwrap();
+    let light = scene.image_based_light().unwrap();
+    assert_eq!(light.index(), 0);
+    assert_eq!(light.intensity(), 2.0);
+    assert_eq!(light.rotation(), [0.0, 1.0, 0.0, 0.0]);
+    assert_eq!(light.specular_image_size(), 256);
+    let coefficients = light.irradiance_coefficients();
+    for (i, rgb) in coefficients.iter().enumerate() {
+        let i = i as f32;
+        assert_eq!(*rgb, [i, i + 0.1, i + 0.2]);
+    }
+
+    let cubemaps: Vec<Vec<usize>> = light
+        .specular_images()
+        .map(|faces| faces.iter().map(|image| image.index()).collect())
+        .collect();
+    assert_eq!(cubemaps, [vec![0, 1, 2, 3, 4, 5], vec![6, 7, 8, 9, 10, 11]]);
+
+    let scene = gltf.scenes().nth(1).unwrap();
+    assert!(scene.image_based_light().is_none());
+}
+
+#[test]
+fn test_scene_nodes_preorder() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "scenes": [ { "nodes": [0, 3] } ],
+        "nodes": [
+            { "children": [1, 2], "translation": [1.0, 0.0, 0.0] },
+            { "translation": [0.0, 2.0, 0.0] },
+            {},
+            { "children": [4] },
+            { "scale": [2.0, 2.0, 2.0] }
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    let scene = gltf.scenes().nth(0).unwrap();
+    let order: Vec<usize> = scene.nodes_preorder().map(|node| node.index()).collect();
+    assert_eq!(order, vec![0, 1, 2, 3, 4]);
+
+    let transforms: Vec<_> = scene.nodes_preorder().with_transforms().collect();
+    assert_eq!(transforms[1].0.index(), 1);
+    assert_eq!(transforms[1].1[3], [1.0, 2.0, 0.0, 1.0]);
+    assert_eq!(transforms[2].1[3], [1.0, 0.0, 0.0, 1.0]);
+    assert_eq!(transforms[4].1[0], [2.0, 0.0, 0.0, 0.0]);
+}
+
+#[test]
+fn test_node_descendants() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "nodes": [
+            { "children": [1, 4] },
+            { "children": [2, 3] },
+            {},
+            {},
+            { "children": [5] },
+            {}
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    let descendants = |index: usize| -> Vec<usize> {
+        let node = gltf.nodes().nth(index).unwrap();
+        node.descendants().map(|node| node.index()).collect()
+    };
+    assert_eq!(descendants(0), [1, 2, 3, 4, 5]);
+    assert_eq!(descendants(1), [2, 3]);
+    assert!(descendants(2).is_empty());
+}
+
+#[test]
+fn test_node_descendants_terminate_on_cycles() {
+    let json = r#"{
+        "asset": { "version": "2.0" },
+        "nodes": [
+            { "children": [1] },
+            { "children": [2, 0] },
+            { "children": [1, 2] }
+        ]
+    }"#;
+    let json = gltf::json::Root::from_str(json).unwrap();
+    let document = gltf::Document::from_json_without_validation(json);
+    let descendants = |index: usize| -> Vec<usize> {
+        let node = document.nodes().nth(index).unwrap();
+        node.descendants().map(|node| node.index()).collect()
+    };
+    assert_eq!(descendants(0), [1, 2]);
+    assert_eq!(descendants(1), [2, 0]);
+    assert_eq!(descendants(2), [1, 0]);
+}
+
+#[test]
+fn test_node_transform_relative_to() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "nodes": [
+            { "children": [1], "translation": [100, 0, 0] },
+            { "children": [2], "scale": [2, 2, 2] },
+            { "children": [3], "translation": [1, 0, 0] },
+            { "translation": [0, 1, 0] },
+            {}
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    let nodes: Vec<_> = gltf.nodes().collect();
+
+    let transform = nodes[3].transform_relative_to(&nodes[0]).unwrap();
+    assert_eq!(transform[0], [2.0, 0.0, 0.0, 0.0]);
+    assert_eq!(transform[3], [2.0, 2.0, 0.0, 1.0]);
+    let transform = nodes[3].transform_relative_to(&nodes[2]).unwrap();
+    assert_eq!(transform[3], [0.0, 1.0, 0.0, 1.0]);
+
+    assert!(nodes[0].transform_relative_to(&nodes[3]).is_none());
+    assert!(nodes[3].transform_relative_to(&nodes[3]).is_none());
+    assert!(nodes[3].transform_relative_to(&nodes[4]).is_none());
+}
+
+#[test]
+fn test_document_node_parents() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "nodes": [
+            { "children": [1, 2] },
+            { "children": [3] },
+            {},
+            {}
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    let parents = gltf.node_parents_strict().unwrap();
+    let parent = |index: usize| {
+        let node = gltf.nodes().nth(index).unwrap();
+        parents.parent_of(&node).map(|node| node.index())
+    };
+    assert_eq!(parent(0), None);
+    assert_eq!(parent(1), Some(0));
+    assert_eq!(parent(2), Some(0));
+    assert_eq!(parent(3), Some(1));
+
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "nodes": [
+            { "children": [2] },
+            { "children": [2] },
+            {}
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice_without_validation(json).unwrap();
+    let node = gltf.nodes().nth(2).unwrap();
+    assert_eq!(gltf.node_parents().parent_of(&node).unwrap().index(), 0);
+    match gltf.node_parents_strict() {
+        Err(gltf::Error::MultipleParents { node }) => assert_eq!(node, 2),
+        other => panic!("unexpected result: {:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn test_node_cycles_fail_validation() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "nodes": [
+            { "children": [1, 3] },
+            { "children": [2] },
+            { "children": [0, 2] },
+            {}
+        ]
+    }"#;
+    match gltf::Gltf::from_slice(json) {
+        Err(gltf::Error::Validation(errors)) => {
+            let paths: Vec<_> = errors.iter().map(|&(ref path, _)| path.as_str().to_string()).collect();
+            assert_eq!(paths, ["nodes[2].children", "nodes[2].children"]);
+        },
+        _ => panic!("expected validation error"),
+    }
+}
+
+#[test]
+fn test_gltf_into_parts() {
+    let file = fs::File::open("examples/Box.glb").unwrap();
+    let mut reader = io::BufReader::new(file);
+    let mut buffer = vec![];
+    reader.read_to_end(&mut buffer).unwrap();
+    let glb = gltf::binary::Glb::from_slice(&buffer).unwrap();
+    let gltf = gltf::Gltf::from_slice(&buffer).unwrap();
+    let (document, blob) = gltf.into_parts();
+    assert_eq!(document.meshes().count(), 1);
+    assert_eq!(blob.as_ref().map(|x| &x[..]), glb.bin.as_ref().map(|x| &x[..]));
+    assert!(blob.is_some());
+}
+
+#[test]
+fn test_image_source_mime_type() {
+    use gltf::image::Source;
+
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 4 } ],
+        "bufferViews": [ { "buffer": 0, "byteLength": 4 } ],
+        "images": [
+            { "bufferView": 0, "mimeType": "image/jpeg" },
+            { "uri": "data:image/png;base64,AAAA" },
+            { "uri": "data:;base64,AAAA" },
+            { "uri": "image.png" },
+            { "uri": "texture.bin", "mimeType": "image/png" }
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    let sources: Vec<_> = gltf.images().map(|image| image.source()).collect();
+    match sources[0] {
+        Source::View { ref view, mime_type } => {
+            assert_eq!(view.index(), 0);
+            assert_eq!(mime_type, "image/jpeg");
+        }
+        _ => panic!("expected a buffer view source"),
+    }
+    let uris: Vec<_> = sources[1..]
+        .iter()
+        .map(|source| match *source {
+            Source::Uri { uri, mime_type } => (uri, mime_type),
+            _ => panic!("expected a URI source"),
+        })
+        .collect();
+    assert_eq!(uris, [
+        ("data:image/png;base64,AAAA", Some("image/png")),
+        ("data:;base64,AAAA", None),
+        ("image.png", None),
+        ("texture.bin", Some("image/png")),
+    ]);
+}
+
+#[test]
+fn test_gltf_try_from_and_from_str() {
+    use std::convert::TryFrom;
+
+    let json = r#"{ "asset": { "version": "2.0" }, "scene": 0 }"#;
+    assert!(gltf::Gltf::try_from(json.as_bytes()).is_err());
+    assert!(json.parse::<gltf::Gltf>().is_err());
+    assert!(gltf::Gltf::from_json_str_without_validation(json).is_ok());
+
+    let json = r#"{ "asset": { "version": "2.0" }, "scenes": [ { "nodes": [] } ] }"#;
+    let gltf = gltf::Gltf::try_from(json.as_bytes()).unwrap();
+    assert_eq!(gltf.scenes().len(), 1);
+    let gltf: gltf::Gltf = json.parse().unwrap();
+    assert_eq!(gltf.scenes().len(), 1);
+    assert!(gltf.blob.is_none());
+}
+
+#[test]
+fn test_texture_default_sampler() {
+    use gltf::texture::WrappingMode;
+
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "images": [ { "uri": "image.png" } ],
+        "samplers": [ { "magFilter": 9728, "wrapS": 33071 } ],
+        "textures": [ { "source": 0 }, { "source": 0, "sampler": 0 } ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    let sampler = gltf.textures().nth(0).unwrap().sampler();
+    assert_eq!(sampler.index(), None);
+    assert_eq!(sampler.wrap_s(), WrappingMode::Repeat);
+    assert_eq!(sampler.wrap_t(), WrappingMode::Repeat);
+    assert!(sampler.mag_filter().is_none());
+    assert!(sampler.min_filter().is_none());
+
+    let sampler = gltf.textures().nth(1).unwrap().sampler();
+    assert_eq!(sampler.index(), Some(0));
+    assert_eq!(sampler.wrap_s(), WrappingMode::ClampToEdge);
+    assert_eq!(sampler.wrap_t(), WrappingMode::Repeat);
+    assert_eq!(sampler.mag_filter(), Some(gltf::texture::MagFilter::Nearest));
+}
+
+#[test]
+fn test_sampler_gl_enum_round_trip() {
+    use gltf::texture::{MagFilter, MinFilter, WrappingMode};
+
+    for filter in &[MagFilter::Nearest, MagFilter::Linear] {
+        assert_eq!(MagFilter::from_gl_enum(filter.as_gl_enum()), Some(*filter));
+    }
+    for filter in &[
+        MinFilter::Nearest,
+        MinFilter::Linear,
+        MinFilter::NearestMipmapNearest,
+        MinFilter::LinearMipmapNearest,
+        MinFilter::NearestMipmapLinear,
+        MinFilter::LinearMipmapLinear,
+    ] {
+        assert_eq!(MinFilter::from_gl_enum(filter.as_gl_enum()), Some(*filter));
+    }
+    for mode in &[WrappingMode::ClampToEdge, WrappingMode::MirroredRepeat, WrappingMode::Repeat] {
+        assert_eq!(WrappingMode::from_gl_enum(mode.as_gl_enum()), Some(*mode));
+    }
+    assert_eq!(MagFilter::from_gl_enum(9984), None);
+    assert_eq!(MinFilter::from_gl_enum(0), None);
+    assert_eq!(WrappingMode::from_gl_enum(9728), None);
+}
+
+#[test]
+fn test_min_filter_mipmap_intent() {
+    use gltf::texture::{Filter, MinFilter};
+
+    let expected = [
+        (MinFilter::Nearest, Filter::Nearest, None),
+        (MinFilter::Linear, Filter::Linear, None),
+        (MinFilter::NearestMipmapNearest, Filter::Nearest, Some(Filter::Nearest)),
+        (MinFilter::LinearMipmapNearest, Filter::Linear, Some(Filter::Nearest)),
+        (MinFilter::NearestMipmapLinear, Filter::Nearest, Some(Filter::Linear)),
+        (MinFilter::LinearMipmapLinear, Filter::Linear, Some(Filter::Linear)),
+    ];
+    for &(filter, base, mipmap) in &expected {
+        assert_eq!(filter.base_filter(), base);
+        assert_eq!(filter.mipmap_filter(), mipmap);
+        assert_eq!(filter.uses_mipmaps(), mipmap.is_some());
+    }
+}
+
+#[test]
+fn test_animation_channel_targets() {
+    use gltf::animation::Property;
+
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 8 } ],
+        "bufferViews": [ { "buffer": 0, "byteLength": 8 } ],
+        "accessors": [
+            { "bufferView": 0, "componentType": 5126, "count": 1, "type": "SCALAR",
+              "min": [0.0], "max": [0.0] },
+            { "bufferView": 0, "byteOffset": 4, "componentType": 5126, "count": 1, "type": "SCALAR" }
+        ],
+        "nodes": [ {}, {} ],
+        "animations": [
+            {
+                "samplers": [ { "input": 0, "output": 1 } ],
+                "channels": [
+                    { "sampler": 0, "target": { "node": 1, "path": "translation" } },
+                    { "sampler": 0, "target": { "node": 0, "path": "rotation" } },
+                    { "sampler": 0, "target": { "node": 1, "path": "scale" } },
+                    { "sampler": 0, "target": { "node": 0, "path": "weights" } }
+                ]
+            }
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    let animation = gltf.animations().nth(0).unwrap();
+    let targets: Vec<_> = animation
+        .channels()
+        .map(|channel| {
+            let target = channel.target();
+            (target.node().index(), target.property())
+        })
+        .collect();
+    assert_eq!(targets, [
+        (1, Property::Translation),
+        (0, Property::Rotation),
+        (1, Property::Scale),
+        (0, Property::MorphTargetWeights),
+    ]);
+}
+
+#[cfg(feature = "names")]
+#[test]
+fn test_retarget_animation() {
+    use std::collections::HashMap;
+
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 8 } ],
+        "bufferViews": [ { "buffer": 0, "byteLength": 8 } ],
+        "accessors": [
+            { "bufferView": 0, "componentType": 5126, "count": 1, "type": "SCALAR",
+              "min": [0.0], "max": [0.0] },
+            { "bufferView": 0, "byteOffset": 4, "componentType": 5126, "count": 1, "type": "SCALAR" }
+        ],
+        "nodes": [
+            { "name": "src_hip" },
+            { "name": "src_knee" },
+            { "name": "src_tail" },
+            { "name": "dst_hip" },
+            { "name": "dst_knee" }
+        ],
+        "animations": [
+            {
+                "samplers": [ { "input": 0, "output": 1 } ],
+                "channels": [
+                    { "sampler": 0, "target": { "node": 0, "path": "translation" } },
+                    { "sampler": 0, "target": { "node": 1, "path": "rotation" } },
+                    { "sampler": 0, "target": { "node": 2, "path": "scale" } }
+                ]
+            }
+        ]
+    }"#;
+    let mut gltf = gltf::Gltf::from_slice(json).unwrap();
+    let mut name_map = HashMap::new();
+    name_map.insert("src_hip".to_string(), "dst_hip".to_string());
+    name_map.insert("src_knee".to_string(), "dst_knee".to_string());
+    name_map.insert("src_tail".to_string(), "dst_tail".to_string());
+    gltf.document.retarget_animation(0, &name_map);
+    let animation = gltf.animations().nth(0).unwrap();
+    let targets: Vec<_> = animation.channels().map(|c| c.target().node().index()).collect();
+    assert_eq!(targets, [3, 4, 2]);
+}
+
+#[test]
+fn test_pretty_json_string_is_stable() {
+    let first = gltf::Gltf::open("examples/Box.gltf").unwrap();
+    let second = gltf::Gltf::open("examples/Box.gltf").unwrap();
+    let text = first.pretty_json_string().unwrap();
+    assert_eq!(text, second.pretty_json_string().unwrap());
+    assert_eq!(text, first.pretty_json_string().unwrap());
+
+    let accessors = text.find("\"accessors\"").unwrap();
+    let asset = text.find("\"asset\"").unwrap();
+    let nodes = text.find("\"nodes\"").unwrap();
+    assert!(accessors < asset && asset < nodes);
+    assert!(text.contains('\n'));
+
+    let reloaded = gltf::Gltf::from_slice(text.as_bytes()).unwrap();
+    assert_eq!(text, reloaded.pretty_json_string().unwrap());
+}
+
+#[test]
+fn test_node_resolved_weights() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 36 } ],
+        "bufferViews": [ { "buffer": 0, "byteLength": 36 } ],
+        "accessors": [
+            { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3",
+              "min": [0.0, 0.0, 0.0], "max": [1.0, 1.0, 0.0] }
+        ],
+        "meshes": [
+            {
+                "primitives": [
+                    {
+                        "attributes": { "POSITION": 0 },
+                        "targets": [ { "POSITION": 0 }, { "POSITION": 0 } ]
+                    }
+                ],
+                "weights": [0.5, 0.25]
+            }
+        ],
+        "nodes": [
+            { "mesh": 0, "weights": [1.0, 0.0] },
+            { "mesh": 0 },
+            {}
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    let weights: Vec<_> = gltf.nodes().map(|node| node.weights()).collect();
+    assert_eq!(weights, [Some(&[1.0, 0.0][..]), None, None]);
+    let resolved: Vec<_> = gltf.nodes().map(|node| node.resolved_weights()).collect();
+    assert_eq!(resolved, [Some(&[1.0, 0.0][..]), Some(&[0.5, 0.25][..]), None]);
+}
+
+#[test]
+fn test_primitive_attributes_order() {
+    use gltf::Semantic;
+
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 36 } ],
+        "bufferViews": [ { "buffer": 0, "byteLength": 36 } ],
+        "accessors": [
+            { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3",
+              "min": [0.0, 0.0, 0.0], "max": [1.0, 1.0, 0.0] },
+            { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC2" },
+            { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3" }
+        ],
+        "meshes": [
+            {
+                "primitives": [
+                    {
+                        "attributes": {
+                            "TEXCOORD_1": 1,
+                            "NORMAL": 2,
+                            "TEXCOORD_0": 1,
+                            "POSITION": 0
+                        }
+                    }
+                ]
+            }
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    let mesh = gltf.meshes().nth(0).unwrap();
+    let primitive = mesh.primitives().nth(0).unwrap();
+    let attributes: Vec<_> = primitive
+        .attributes()
+        .map(|(semantic, accessor)| (semantic, accessor.index()))
+        .collect();
+    assert_eq!(attributes, [
+        (Semantic::Positions, 0),
+        (Semantic::Normals, 2),
+        (Semantic::TexCoords(0), 1),
+        (Semantic::TexCoords(1), 1),
+    ]);
+    assert_eq!(primitive.attribute_count(), 4);
+    assert!(primitive.has_attribute(&Semantic::TexCoords(1)));
+    assert!(!primitive.has_attribute(&Semantic::TexCoords(2)));
+    assert!(!primitive.has_attribute(&Semantic::Tangents));
+}
+
+#[test]
+fn test_mismatched_attribute_counts_are_rejected() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 48 } ],
+        "bufferViews": [ { "buffer": 0, "byteLength": 48 } ],
+        "accessors": [
+            { "bufferView": 0, "componentType": 5126, "count": 4, "type": "VEC3",
+              "min": [0.0, 0.0, 0.0], "max": [1.0, 1.0, 1.0] },
+            { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3" }
+        ],
+        "meshes": [
+            { "primitives": [ { "attributes": { "POSITION": 0, "NORMAL": 1 } } ] }
+        ]
+    }"#;
+    match gltf::Gltf::from_slice(json) {
+        Err(gltf::Error::Validation(errors)) => {
+            let paths: Vec<_> = errors.iter().map(|&(ref path, _)| path.as_str()).collect();
+            assert_eq!(paths, ["meshes[0].primitives[0].attributes[\"NORMAL\"]"]);
+        },
+        _ => panic!("expected a validation error"),
+    }
+}
+
+#[test]
+fn test_document_builder() {
+    use gltf::build::{DocumentBuilder, Primitive};
+    use gltf::json::accessor::{ComponentType, Type};
+    use gltf::json::mesh::{Mode, Semantic};
+
+    let mut builder = DocumentBuilder::new();
+    let buffer = builder.add_buffer(42, Some("data.bin"));
+    let vertices = builder.add_view(buffer, 0, 36, None, None);
+    let indices = builder.add_view(buffer, 36, 6, None, None);
+    let positions = builder.add_accessor(vertices, 0, 3, ComponentType::F32, Type::Vec3);
+    builder.set_bounds(positions, &[0.0, 0.0, 0.0], &[1.0, 1.0, 0.0]);
+    let indices = builder.add_accessor(indices, 0, 3, ComponentType::U16, Type::Scalar);
+    let mesh = builder.add_mesh(vec![
+        Primitive::new()
+            .attribute(Semantic::Positions, positions)
+            .indices(indices)
+            .mode(Mode::Triangles),
+    ]);
+    let child = builder.add_node(Some(mesh), &[]);
+    let parent = builder.add_node(None, &[child]);
+    let scene = builder.add_scene(&[parent]);
+    builder.set_default_scene(scene);
+    let document = builder.build().unwrap();
+
+    assert_eq!(document.buffers().nth(0).unwrap().length(), 42);
+    let scene = document.default_scene().unwrap();
+    let root = scene.nodes().nth(0).unwrap();
+    let child = root.children().nth(0).unwrap();
+    let mesh = child.mesh().unwrap();
+    let primitive = mesh.primitives().nth(0).unwrap();
+    assert_eq!(primitive.get(&gltf::Semantic::Positions).unwrap().count(), 3);
+    assert_eq!(primitive.indices().unwrap().index(), 1);
+
+    let mut builder = DocumentBuilder::new();
+    let buffer = builder.add_buffer(12, None);
+    let view = builder.add_view(buffer, 0, 12, None, None);
+    builder.add_accessor(view, 0, 1, ComponentType::F32, Type::Vec3);
+    builder.json_mut().accessors[0].buffer_view = Some(gltf::json::Index::new(1));
+    assert!(builder.build().is_err());
+}
+
+#[cfg(feature = "import")]
+#[test]
+fn test_view_and_accessor_slice() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 16 } ],
+        "bufferViews": [
+            { "buffer": 0, "byteOffset": 2, "byteLength": 12, "byteStride": 4 },
+            { "buffer": 0, "byteOffset": 8, "byteLength": 12 }
+        ],
+        "accessors": [
+            { "bufferView": 0, "byteOffset": 1, "componentType": 5121, "count": 3, "type": "VEC2" },
+            { "bufferView": 0, "byteOffset": 8, "componentType": 5121, "count": 2, "type": "VEC2" }
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    let data = vec![gltf::buffer::Data((0 .. 16).collect())];
+
+    let views: Vec<_> = gltf.views().collect();
+    assert_eq!(views[0].slice(&data).unwrap(), &data[0][2 .. 14]);
+    match views[1].slice(&data) {
+        Err(gltf::Error::BufferViewBounds { view }) => assert_eq!(view, 1),
+        other => panic!("unexpected result: {:?}", other),
+    }
+    match views[0].slice(&[]) {
+        Err(gltf::Error::MissingBufferData { buffer }) => assert_eq!(buffer, 0),
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    let accessors: Vec<_> = gltf.accessors().collect();
+    assert_eq!(accessors[0].slice(&data).unwrap(), &data[0][3 .. 13]);
+    match accessors[1].slice(&data) {
+        Err(gltf::Error::AccessorBounds { accessor }) => assert_eq!(accessor, 1),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[cfg(feature = "import")]
+#[test]
+fn test_document_merge() {
+    let (mut document, mut buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    let (other, other_buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    let accessor_count = document.accessors().len();
+    let node_count = document.nodes().len();
+    document.merge(other);
+    buffers.extend(other_buffers);
+
+    assert_eq!(document.accessors().len(), 2 * accessor_count);
+    assert_eq!(document.nodes().len(), 2 * node_count);
+    assert_eq!(document.buffers().len(), 2);
+    assert_eq!(document.scenes().len(), 2);
+    assert_eq!(document.default_scene().unwrap().index(), 0);
+
+    let roots: Vec<_> = document.scenes().nth(1).unwrap().nodes().map(|node| node.index()).collect();
+    assert_eq!(roots, [node_count]);
+    let children: Vec<_> = document.nodes().nth(node_count).unwrap().children().map(|node| node.index()).collect();
+    assert_eq!(children, [node_count + 1]);
+
+    let meshes: Vec<_> = document.meshes().collect();
+    let (first, second) = (&meshes[0], &meshes[1]);
+    let first = first.primitives().nth(0).unwrap();
+    let second = second.primitives().nth(0).unwrap();
+    assert_eq!(second.get(&gltf::Semantic::Positions).unwrap().index(),
+               first.get(&gltf::Semantic::Positions).unwrap().index() + accessor_count);
+    assert_eq!(second.material().index().unwrap(), document.materials().len() - 1);
+    assert_eq!(second.indices().unwrap().view().unwrap().buffer().index(), 1);
+
+    let positions = |primitive: &gltf::Primitive| -> Vec<[f32; 3]> {
+        let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+        reader.read_positions().unwrap().collect()
+    };
+    assert_eq!(positions(&first), positions(&second));
+}
+
+#[test]
+fn test_document_merge_remaps_indices() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "extensionsUsed": [ "EXT_example" ],
+        "buffers": [ { "byteLength": 64 } ],
+        "bufferViews": [ { "buffer": 0, "byteLength": 64 } ],
+        "accessors": [
+            { "bufferView": 0, "componentType": 5126, "count": 1, "type": "MAT4" },
+            { "bufferView": 0, "componentType": 5126, "count": 1, "type": "SCALAR" }
+        ],
+        "animations": [
+            {
+                "channels": [ { "sampler": 0, "target": { "node": 1, "path": "scale" } } ],
+                "samplers": [ { "input": 1, "output": 1 } ]
+            }
+        ],
+        "cameras": [ { "type": "perspective", "perspective": { "yfov": 1.0, "znear": 0.1 } } ],
+        "images": [ { "uri": "image.png" } ],
+        "samplers": [ {} ],
+        "textures": [ { "source": 0, "sampler": 0 } ],
+        "materials": [ { "emissiveTexture": { "index": 0 } } ],
+        "nodes": [
+            { "children": [1], "camera": 0 },
+            { "skin": 0 }
+        ],
+        "skins": [ { "inverseBindMatrices": 0, "joints": [1], "skeleton": 0 } ],
+        "scenes": [ { "nodes": [0] } ]
+    }"#;
+    let mut document = gltf::Gltf::from_slice(json).unwrap().into_parts().0;
+    let other = gltf::Gltf::from_slice(json).unwrap().into_parts().0;
+    document.merge(other);
+
+    let root = document.into_json();
+    assert_eq!(root.extensions_used, ["EXT_example"]);
+    assert_eq!(root.buffer_views[1].buffer.value(), 1);
+    assert_eq!(root.accessors[2].buffer_view.unwrap().value(), 1);
+    assert_eq!(root.animations[1].channels[0].target.node.value(), 3);
+    assert_eq!(root.animations[1].channels[0].sampler.value(), 0);
+    assert_eq!(root.animations[1].samplers[0].input.value(), 3);
+    assert_eq!(root.textures[1].source.map(|source| source.value()), Some(1));
+    assert_eq!(root.textures[1].sampler.as_ref().unwrap().value(), 1);
+    assert_eq!(root.materials[1].emissive_texture.as_ref().unwrap().index.value(), 1);
+    assert_eq!(root.nodes[2].camera.as_ref().unwrap().value(), 1);
+    assert_eq!(root.nodes[3].skin.as_ref().unwrap().value(), 1);
+    assert_eq!(root.skins[1].inverse_bind_matrices.as_ref().unwrap().value(), 2);
+    assert_eq!(root.skins[1].joints[0].value(), 3);
+    assert_eq!(root.skins[1].skeleton.as_ref().unwrap().value(), 2);
+    assert!(gltf::Document::from_json(root).is_ok());
+}
+
+#[cfg(feature = "names")]
+#[test]
+fn test_node_path() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "nodes": [
+            { "name": "Head" },
+            { "name": "Armature", "children": [2] },
+            { "name": "Spine", "children": [0, 3] },
+            {}
+        ],
+        "scenes": [ { "nodes": [1] } ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    assert_eq!(gltf.node_path(0), [Some("Armature"), Some("Spine"), Some("Head")]);
+    assert_eq!(gltf.node_path(1), [Some("Armature")]);
+    assert_eq!(gltf.node_path(3), [Some("Armature"), Some("Spine"), None]);
+}
+
+#[test]
+fn test_pbr_metallic_roughness_defaults() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "images": [ { "uri": "texture.png" } ],
+        "textures": [ { "source": 0 } ],
+        "materials": [
+            {},
+            {
+                "pbrMetallicRoughness": {
+                    "baseColorFactor": [0.5, 0.25, 0.125, 1.0],
+                    "baseColorTexture": { "index": 0 },
+                    "metallicFactor": 0.0,
+                    "roughnessFactor": 0.5,
+                    "metallicRoughnessTexture": { "index": 0, "texCoord": 1 }
+                }
+            }
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    let materials: Vec<_> = gltf.materials().collect();
+
+    let pbr = materials[0].pbr_metallic_roughness();
+    assert_eq!(pbr.base_color_factor(), [1.0, 1.0, 1.0, 1.0]);
+    assert!(pbr.base_color_texture().is_none());
+    assert_eq!(pbr.metallic_factor(), 1.0);
+    assert_eq!(pbr.roughness_factor(), 1.0);
+    assert!(pbr.metallic_roughness_texture().is_none());
+
+    let pbr = materials[1].pbr_metallic_roughness();
+    assert_eq!(pbr.base_color_factor(), [0.5, 0.25, 0.125, 1.0]);
+    assert_eq!(pbr.base_color_texture().unwrap().texture().index(), 0);
+    assert_eq!(pbr.metallic_factor(), 0.0);
+    assert_eq!(pbr.roughness_factor(), 0.5);
+    assert_eq!(pbr.metallic_roughness_texture().unwrap().tex_coord(), 1);
+}
+
+#[test]
+fn test_material_used_tex_coord_sets() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "images": [ { "uri": "texture.png" } ],
+        "textures": [ { "source": 0 } ],
+        "materials": [
+            {},
+            {
+                "pbrMetallicRoughness": {
+                    "baseColorTexture": { "index": 0 },
+                    "metallicRoughnessTexture": { "index": 0, "texCoord": 2 }
+                },
+                "normalTexture": { "index": 0, "texCoord": 2 },
+                "occlusionTexture": { "index": 0 },
+                "emissiveTexture": { "index": 0, "texCoord": 1 }
+            }
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    let materials: Vec<_> = gltf.materials().collect();
+    assert!(materials[0].used_tex_coord_sets().is_empty());
+    assert_eq!(materials[1].used_tex_coord_sets(), [0, 1, 2]);
+    assert_eq!(materials[1].normal_texture().unwrap().tex_coord(), 2);
+    assert_eq!(materials[1].occlusion_texture().unwrap().tex_coord(), 0);
+    assert_eq!(materials[1].emissive_texture().unwrap().tex_coord(), 1);
+}
+
+#[test]
+fn test_primitive_material_index() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 36 } ],
+        "bufferViews": [ { "buffer": 0, "byteLength": 36 } ],
+        "accessors": [
+            { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3",
+              "min": [0.0, 0.0, 0.0], "max": [1.0, 1.0, 0.0] }
+        ],
+        "materials": [ {} ],
+        "meshes": [
+            {
+                "primitives": [
+                    { "attributes": { "POSITION": 0 }, "material": 0 },
+                    { "attributes": { "POSITION": 0 } }
+                ]
+            }
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    let mesh = gltf.meshes().nth(0).unwrap();
+    let primitives: Vec<_> = mesh.primitives().collect();
+    assert_eq!(primitives[0].material_index(), Some(0));
+    assert_eq!(primitives[1].material_index(), None);
+    for primitive in &primitives {
+        assert_eq!(primitive.material_index(), primitive.material().index());
+    }
+}
+
+#[test]
+fn test_scene_materials() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 36 } ],
+        "bufferViews": [ { "buffer": 0, "byteLength": 36 } ],
+        "accessors": [
+            { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3",
+              "min": [0.0, 0.0, 0.0], "max": [1.0, 1.0, 0.0] }
+        ],
+        "materials": [ {}, {}, {}, {} ],
+        "meshes": [
+            {
+                "primitives": [
+                    { "attributes": { "POSITION": 0 }, "material": 3 },
+                    { "attributes": { "POSITION": 0 }, "material": 1 }
+                ]
+            },
+            {
+                "primitives": [
+                    { "attributes": { "POSITION": 0 }, "material": 1 },
+                    { "attributes": { "POSITION": 0 } }
+                ]
+            },
+            { "primitives": [ { "attributes": { "POSITION": 0 }, "material": 2 } ] }
+        ],
+        "nodes": [
+            { "mesh": 0, "children": [1] },
+            { "mesh": 1 },
+            { "mesh": 2 },
+            { "mesh": 0 }
+        ],
+        "scenes": [
+            { "nodes": [0] },
+            { "nodes": [3] }
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+
+    let materials = gltf.scenes().nth(0).unwrap().materials();
+    assert_eq!(materials.len(), 3);
+    let indices: Vec<_> = materials.map(|material| material.index()).collect();
+    assert_eq!(indices, [Some(1), Some(3), None]);
+
+    let materials = gltf.scenes().nth(1).unwrap().materials();
+    let indices: Vec<_> = materials.map(|material| material.index()).collect();
+    assert_eq!(indices, [Some(1), Some(3)]);
+}
+
+#[test]
+fn test_from_slice_lenient() {
+    use gltf::json::validation::Error;
+
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "extensionsUsed": [ "EXT_unknown_used", "EXT_unknown_required" ],
+        "extensionsRequired": [ "EXT_unknown_required" ],
+        "nodes": [
+            { "name": "Root", "children": [1] },
+            { "matrix": [1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1], "scale": [2, 2, 2] }
+        ],
+        "scenes": [ { "nodes": [0] } ]
+    }"#;
+    let (gltf, diagnostics) = gltf::Gltf::from_slice_lenient(json).unwrap();
+    assert!(diagnostics.errors.is_empty());
+    let warnings: Vec<_> = diagnostics.warnings
+        .iter()
+        .map(|&(ref path, error)| (path.as_str(), error))
+        .collect();
+    assert_eq!(warnings, [
+        ("nodes[1]", Error::Invalid),
+        ("extensionsRequired[0]", Error::Unsupported),
+    ]);
+    let infos: Vec<_> = diagnostics.infos
+        .iter()
+        .map(|&(ref path, error)| (path.as_str(), error))
+        .collect();
+    assert_eq!(infos, [("extensionsUsed[0]", Error::Unsupported)]);
+    assert_eq!(gltf.nodes().len(), 2);
+    assert_eq!(gltf.scenes().nth(0).unwrap().nodes_preorder().count(), 2);
+}
+
+#[test]
+fn test_from_slice_lenient_errors() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "nodes": [ { "matrix": [1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1], "scale": [2, 2, 2] } ],
+        "scenes": [ { "nodes": [1] } ]
+    }"#;
+    let (_, diagnostics) = gltf::Gltf::from_slice_lenient(json).unwrap();
+    let errors: Vec<_> = diagnostics.errors.iter().map(|&(ref path, _)| path.as_str()).collect();
+    assert_eq!(errors, ["scenes[0].nodes[0]"]);
+    assert!(diagnostics.warnings.is_empty());
+}
+
+#[test]
+fn test_from_slice_lenient_clears_invalid_references() {
+    use gltf::json::validation::Error;
+
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "images": [ { "uri": "texture.png" } ],
+        "textures": [ { "source": 0, "sampler": 3 } ],
+        "materials": [
+            {
+                "pbrMetallicRoughness": { "baseColorTexture": { "index": 0 } },
+                "normalTexture": { "index": 7 }
+            }
+        ],
+        "nodes": [ { "mesh": 2 } ],
+        "scenes": [ { "nodes": [0] } ],
+        "scene": 1
+    }"#;
+    let (gltf, diagnostics) = gltf::Gltf::from_slice_lenient(json).unwrap();
+    assert!(diagnostics.errors.is_empty());
+    let warnings: Vec<_> = diagnostics.warnings
+        .iter()
+        .map(|&(ref path, error)| (path.as_str(), error))
+        .collect();
+    assert_eq!(warnings, [
+        ("scene", Error::IndexOutOfBounds),
+        ("materials[0].normalTexture", Error::IndexOutOfBounds),
+        ("nodes[0].mesh", Error::IndexOutOfBounds),
+        ("textures[0].sampler", Error::IndexOutOfBounds),
+    ]);
+    assert!(gltf.default_scene().is_none());
+    let material = gltf.materials().nth(0).unwrap();
+    assert!(material.normal_texture().is_none());
+    assert!(material.pbr_metallic_roughness().base_color_texture().is_some());
+    assert!(gltf.nodes().nth(0).unwrap().mesh().is_none());
+    assert!(gltf.textures().nth(0).unwrap().sampler().index().is_none());
+}
+
+#[cfg(all(feature = "KHR_materials_clearcoat", feature = "KHR_texture_basisu"))]
+#[test]
+fn test_from_slice_lenient_clears_invalid_extension_references() {
+    use gltf::json::validation::Error;
+
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "extensionsUsed": [ "KHR_materials_clearcoat", "KHR_texture_basisu" ],
+        "images": [ { "uri": "texture.png" } ],
+        "textures": [
+            { "source": 0, "extensions": { "KHR_texture_basisu": { "source": 4 } } }
+        ],
+        "materials": [
+            {
+                "extensions": {
+                    "KHR_materials_clearcoat": {
+                        "clearcoatTexture": { "index": 0 },
+                        "clearcoatNormalTexture": { "index": 5 }
+                    }
+                }
+            }
+        ]
+    }"#;
+    let (gltf, diagnostics) = gltf::Gltf::from_slice_lenient(json).unwrap();
+    assert!(diagnostics.errors.is_empty());
+    let warnings: Vec<_> = diagnostics.warnings
+        .iter()
+        .map(|&(ref path, error)| (path.as_str(), error))
+        .collect();
+    assert_eq!(warnings, [
+        (
+            "materials[0].extensions.KHR_materials_clearcoat.clearcoatNormalTexture",
+            Error::IndexOutOfBounds,
+        ),
+        ("textures[0].extensions.KHR_texture_basisu", Error::IndexOutOfBounds),
+    ]);
+    let clearcoat = gltf.materials().nth(0).unwrap().clearcoat().unwrap();
+    assert!(clearcoat.clearcoat_texture().is_some());
+    assert!(clearcoat.clearcoat_normal_texture().is_none());
+    let texture = gltf.textures().nth(0).unwrap();
+    assert!(texture.basisu_source().is_none());
+    assert_eq!(texture.source().index(), 0);
+}
+
+#[test]
+fn test_document_validate_with() {
+    use gltf::json::validation::Error;
+    use gltf::ValidationConfig;
+
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "extensionsUsed": [ "EXT_unknown_required" ],
+        "extensionsRequired": [ "EXT_unknown_required" ],
+        "nodes": [
+            { "matrix": [1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1], "scale": [2, 2, 2] }
+        ]
+    }"#;
+    let (gltf, _) = gltf::Gltf::from_slice_lenient(json).unwrap();
+    let paths = |problems: &[(gltf::json::Path, Error)]| -> Vec<String> {
+        problems.iter().map(|&(ref path, _)| path.as_str().to_string()).collect()
+    };
+
+    let diagnostics = gltf.validate_with(&ValidationConfig::default()).unwrap();
+    assert_eq!(paths(&diagnostics.warnings), ["nodes[0]", "extensionsRequired[0]"]);
+
+    let strict_extensions = ValidationConfig {
+        allow_unknown_extensions: false,
+        ..Default::default()
+    };
+    match gltf.validate_with(&strict_extensions) {
+        Err(gltf::Error::Validation(errors)) => assert_eq!(paths(&errors), ["extensionsRequired[0]"]),
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    let strict_nodes = ValidationConfig {
+        is_error: |path, _| path.as_str().starts_with("nodes"),
+        ..Default::default()
+    };
+    match gltf.validate_with(&strict_nodes) {
+        Err(gltf::Error::Validation(errors)) => assert_eq!(paths(&errors), ["nodes[0]"]),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+/// A reader that deliberately does not implement `io::Seek`.
+struct NoSeek<R>(R);
+
+impl<R: io::Read> io::Read for NoSeek<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.read(buf)
+    }
+}
+
+#[test]
+fn test_from_reader_no_seek() {
+    let file = fs::File::open("examples/Box.glb").unwrap();
+    let gltf = gltf::Gltf::from_reader_no_seek(NoSeek(io::BufReader::new(file))).unwrap();
+    assert_eq!(gltf.meshes().len(), 1);
+    assert_eq!(gltf.blob.as_ref().map(Vec::len), Some(648));
+
+    let file = fs::File::open("examples/Box.gltf").unwrap();
+    let gltf = gltf::Gltf::from_reader_no_seek(NoSeek(io::BufReader::new(file))).unwrap();
+    assert_eq!(gltf.meshes().len(), 1);
+    assert!(gltf.blob.is_none());
+}
+
+#[test]
+fn test_document_semantically_eq() {
+    let first = gltf::Gltf::from_json_str_without_validation(r#"{
+        "asset": { "version": "2.0" },
+        "materials": [ { "name": "Red", "pbrMetallicRoughness": { "baseColorFactor": [1, 0, 0, 1] } } ],
+        "meshes": [ { "primitives": [ { "attributes": {}, "material": 0 } ] } ],
+        "nodes": [
+            { "name": "Root", "children": [1, 2] },
+            { "name": "Left", "translation": [1, 2, 3], "scale": [2, 2, 2] },
+            { "name": "Right", "mesh": 0 }
+        ],
+        "scenes": [ { "nodes": [0] } ],
+        "scene": 0
+    }"#).unwrap();
+    let second = gltf::Gltf::from_json_str_without_validation(r#"{
+        "asset": { "version": "2.0", "generator": "elsewhere" },
+        "materials": [
+            { "name": "Unused" },
+            { "name": "Red", "pbrMetallicRoughness": { "baseColorFactor": [1, 0, 0, 1] } }
+        ],
+        "meshes": [ { "primitives": [ { "attributes": {}, "material": 1 } ] } ],
+        "nodes": [
+            { "name": "Right", "mesh": 0 },
+            { "name": "Left", "matrix": [2, 0, 0, 0, 0, 2, 0, 0, 0, 0, 2, 0, 1, 2, 3, 1] },
+            { "name": "Root", "children": [1, 0] }
+        ],
+        "scenes": [ { "nodes": [2] } ],
+        "scene": 0
+    }"#).unwrap();
+    assert!(first.semantically_eq(&second));
+    assert!(second.semantically_eq(&first));
+
+    let mut json = second.document.clone().into_json();
+    json.materials[1].pbr_metallic_roughness.base_color_factor.0[1] = 0.5;
+    let changed = gltf::Document::from_json_without_validation(json);
+    assert!(!first.semantically_eq(&changed));
+}
+
+#[cfg(feature = "import")]
+#[test]
+fn test_document_semantically_eq_with_data() {
+    let (first, first_buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    let (second, mut second_buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    assert!(first.semantically_eq_with_data(&first_buffers, &second, &second_buffers));
+
+    second_buffers[0].0[0] ^= 0xff;
+    assert!(second.semantically_eq(&first));
+    assert!(!first.semantically_eq_with_data(&first_buffers, &second, &second_buffers));
+}
+
+#[cfg(feature = "import")]
+#[test]
+fn test_import_in_stages() {
+    use std::path::Path;
+
+    let (document, buffers, images) = gltf::import("examples/Box.glb").unwrap();
+    let gltf::Gltf { document: staged, blob } = gltf::Gltf::open("examples/Box.glb").unwrap();
+    let staged_buffers = gltf::import_buffers(&staged, Path::new("examples"), blob).unwrap();
+    let staged_images = gltf::import_images(&staged, Path::new("examples"), &staged_buffers).unwrap();
+    assert_eq!(staged_buffers.len(), buffers.len());
+    assert_eq!(staged_buffers[0].0, buffers[0].0);
+    assert_eq!(staged_images.len(), images.len());
+    assert!(document.semantically_eq_with_data(&buffers, &staged, &staged_buffers));
+
+    match gltf::import_buffers(&staged, Path::new("examples"), None) {
+        Err(gltf::Error::MissingBlob { buffer: 0 }) => {},
+        other => panic!("unexpected result: {:?}", other.map(|_| ())),
+    }
+}
+
+#[cfg(feature = "import")]
+#[test]
+fn test_texture_image_data() {
+    use gltf::image::{Data, Format};
+
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "images": [ { "uri": "a.png" }, { "uri": "b.png" } ],
+        "textures": [ { "source": 1 }, { "source": 0 } ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    let images: Vec<Data> = (0 .. 2u8)
+        .map(|i| Data { pixels: vec![i], format: Format::R8, width: 1, height: 1 })
+        .collect();
+    let texture = gltf.textures().nth(0).unwrap();
+    assert_eq!(texture.source().index(), 1);
+    assert_eq!(texture.image_data(&images).unwrap().pixels, [1]);
+    assert_eq!(gltf.textures().nth(1).unwrap().image_data(&images).unwrap().pixels, [0]);
+    assert!(texture.image_data(&images[.. 1]).is_none());
+
+    // Textures without a source only exist in documents that were not
+    // validated.
+    let json = r#"{
+        "asset": { "version": "2.0" },
+        "textures": [ {} ]
+    }"#;
+    let json = gltf::json::Root::from_str(json).unwrap();
+    let document = gltf::Document::from_json_without_validation(json);
+    assert!(document.textures().nth(0).unwrap().image_data(&images).is_none());
+}
+
+#[cfg(feature = "extras")]
+#[test]
+fn test_extras_as() {
+    use std::collections::HashMap;
+
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "nodes": [ { "extras": { "layer": 2.0, "lod": 1.0 } }, {} ],
+        "scenes": [ { "nodes": [0, 1], "extras": ["editor", "preview"] } ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    let node = gltf.nodes().nth(0).unwrap();
+    let extras: HashMap<String, f32> = node.extras_as().unwrap().unwrap();
+    assert_eq!(extras["layer"], 2.0);
+    assert_eq!(extras["lod"], 1.0);
+    assert!(gltf.nodes().nth(1).unwrap().extras_as::<HashMap<String, f32>>().is_none());
+
+    let scene = gltf.scenes().nth(0).unwrap();
+    let tags: Vec<String> = scene.extras_as().unwrap().unwrap();
+    assert_eq!(tags, ["editor", "preview"]);
+    match scene.extras_as::<HashMap<String, f32>>() {
+        Some(Err(gltf::Error::Deserialize(_))) => {},
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn test_skin_skeleton() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "nodes": [ { "children": [1] }, {} ],
+        "skins": [ { "joints": [1], "skeleton": 0 }, { "joints": [1] } ],
+        "scenes": [ { "nodes": [0] } ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    let skeleton = gltf.skins().nth(0).unwrap().skeleton().unwrap();
+    assert_eq!(skeleton.index(), 0);
+    assert_eq!(skeleton.children().next().unwrap().index(), 1);
+    assert!(gltf.skins().nth(1).unwrap().skeleton().is_none());
+}
+
+#[cfg(feature = "KHR_texture_basisu")]
+#[test]
+fn test_texture_basisu() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "extensionsUsed": [ "KHR_texture_basisu" ],
+        "images": [ { "uri": "fallback.png" }, { "uri": "texture.ktx2", "mimeType": "image/ktx2" } ],
+        "textures": [
+            { "source": 0, "extensions": { "KHR_texture_basisu": { "source": 1 } } },
+            { "source": 0 }
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    let texture = gltf.textures().nth(0).unwrap();
+    assert_eq!(texture.source().index(), 0);
+    assert_eq!(texture.basisu_source().unwrap().index(), 1);
+    assert!(gltf.textures().nth(1).unwrap().basisu_source().is_none());
+}
+
+#[cfg(feature = "KHR_texture_basisu")]
+#[test]
+fn test_texture_basisu_without_fallback() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "extensionsUsed": [ "KHR_texture_basisu" ],
+        "extensionsRequired": [ "KHR_texture_basisu" ],
+        "images": [ { "uri": "texture.ktx2", "mimeType": "image/ktx2" } ],
+        "textures": [ { "extensions": { "KHR_texture_basisu": { "source": 0 } } } ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    let texture = gltf.textures().nth(0).unwrap();
+    assert_eq!(texture.source().index(), 0);
+    assert_eq!(texture.basisu_source().unwrap().index(), 0);
+
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "textures": [ {} ]
+    }"#;
+    match gltf::Gltf::from_slice(json) {
+        Err(gltf::Error::Validation(errs)) => {
+            let paths: Vec<_> = errs.iter().map(|&(ref path, _)| path.as_str()).collect();
+            assert_eq!(paths, ["textures[0].source"]);
+        },
+        other => panic!("expected validation error, got {:?}", other),
+    }
+}
+
+#[cfg(feature = "import")]
+#[test]
+fn test_accessor_matrix_padding() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 72 } ],
+        "bufferViews": [ { "buffer": 0, "byteLength": 72 } ],
+        "accessors": [
+            { "bufferView": 0, "componentType": 5121, "count": 2, "type": "MAT3" },
+            { "bufferView": 0, "componentType": 5126, "count": 2, "type": "MAT3" }
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    let buffers = [gltf::buffer::Data(vec![0; 72])];
+    let bytes = gltf.accessors().nth(0).unwrap();
+    assert!(bytes.dimensions().is_matrix());
+    assert_eq!(bytes.size(), 12);
+    assert_eq!(bytes.slice(&buffers).unwrap().len(), 24);
+    let floats = gltf.accessors().nth(1).unwrap();
+    assert_eq!(floats.size(), 36);
+    assert_eq!(floats.slice(&buffers).unwrap().len(), 72);
+}
+
+#[test]
+fn test_gltf_with_blob() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 36 }, { "byteLength": 4, "uri": "data.bin" } ],
+        "bufferViews": [ { "buffer": 0, "byteLength": 36 } ],
+        "accessors": [
+            { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3",
+              "min": [0.0, 0.0, 0.0], "max": [0.0, 0.0, 0.0] }
+        ],
+        "meshes": [ { "primitives": [ { "attributes": { "POSITION": 0 } } ] } ]
+    }"#;
+    let document = gltf::Gltf::from_slice(json).unwrap().document;
+    let mut gltf = gltf::Gltf::with_blob(document, vec![1; 40]);
+    assert_eq!(gltf.set_blob(vec![0; 40]), Some(vec![1; 40]));
+
+    assert_eq!(gltf.buffer_data(gltf.buffers().nth(0).unwrap()).unwrap().len(), 36);
+    assert!(gltf.buffer_data(gltf.buffers().nth(1).unwrap()).is_none());
+
+    let mesh = gltf.meshes().nth(0).unwrap();
+    let primitive = mesh.primitives().nth(0).unwrap();
+    let reader = primitive.reader(|buffer| gltf.buffer_data(buffer));
+    let positions: Vec<_> = reader.read_positions().unwrap().collect();
+    assert_eq!(positions, [[0.0; 3]; 3]);
+}
+
+#[test]
+fn test_camera_frustum_corners() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "cameras": [
+            { "type": "perspective",
+              "perspective": { "yfov": 1.5707964, "aspectRatio": 2.0, "znear": 1.0, "zfar": 10.0 } },
+            { "type": "perspective", "perspective": { "yfov": 1.5707964, "znear": 1.0 } },
+            { "type": "orthographic",
+              "orthographic": { "xmag": 2.0, "ymag": 1.0, "znear": 0.5, "zfar": 5.0 } }
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    let identity = [
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ];
+    let corners = |camera: usize, view| {
+        let corners = gltf.cameras().nth(camera).unwrap().frustum_corners(view, 1.0, 100.0).unwrap();
+        let round = |x: f32| (x * 1e4).round() / 1e4;
+        corners.iter().map(|c| [round(c[0]), round(c[1]), round(c[2])]).collect::<Vec<_>>()
+    };
+
+    // With a 90 degree field of view, the half height of a plane equals its
+    // distance.
+    assert_eq!(corners(0, identity), [
+        [-2.0, -1.0, -1.0], [2.0, -1.0, -1.0], [2.0, 1.0, -1.0], [-2.0, 1.0, -1.0],
+        [-20.0, -10.0, -10.0], [20.0, -10.0, -10.0], [20.0, 10.0, -10.0], [-20.0, 10.0, -10.0],
+    ]);
+    assert_eq!(corners(1, identity)[6], [100.0, 100.0, -100.0]);
+    assert_eq!(corners(2, identity)[0], [-2.0, -1.0, -0.5]);
+    assert_eq!(corners(2, identity)[6], [2.0, 1.0, -5.0]);
+
+    // A view matrix translating the world by -3 along X places the camera at
+    // X = 3.
+    let mut view = identity;
+    view[3][0] = -3.0;
+    assert_eq!(corners(0, view)[0], [1.0, -1.0, -1.0]);
+
+    let singular = [[0.0; 4]; 4];
+    assert!(gltf.cameras().nth(0).unwrap().frustum_corners(singular, 1.0, 100.0).is_none());
+}
+
+#[cfg(feature = "extensions")]
+#[test]
+fn test_remove_extension() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "extensionsUsed": [ "EXT_fallback", "EXT_geometry" ],
+        "extensionsRequired": [ "EXT_geometry" ],
+        "materials": [
+            { "extensions": { "EXT_fallback": { "value": 1 } } },
+            { "extensions": { "EXT_fallback": {}, "EXT_other": {} } },
+            { "extras": { "extensions": { "EXT_fallback": {} } } }
+        ],
+        "nodes": [ { "extensions": { "EXT_fallback": {} } }, {} ]
+    }"#;
+    let mut gltf = gltf::Gltf::from_slice_without_validation(json).unwrap();
+
+    match gltf.remove_extension("EXT_geometry") {
+        Err(gltf::Error::RequiredExtension { ref name }) => assert_eq!(name, "EXT_geometry"),
+        _ => panic!("expected a required extension error"),
+    }
+    assert_eq!(gltf.extensions_used().count(), 2);
+
+    let modified = gltf.remove_extension("EXT_fallback").unwrap();
+    let modified: Vec<_> = modified.iter().map(|path| path.as_str()).collect();
+    assert_eq!(modified, ["materials[0]", "materials[1]", "nodes[0]"]);
+    assert_eq!(gltf.extensions_used().collect::<Vec<_>>(), ["EXT_geometry"]);
+    let materials: Vec<_> = gltf.materials().collect();
+    assert!(materials[0].extension_value("EXT_fallback").is_none());
+    assert!(materials[1].extension_value("EXT_other").is_some());
+    assert!(gltf.nodes().nth(0).unwrap().extension_value("EXT_fallback").is_none());
+    assert!(gltf.remove_extension("EXT_fallback").unwrap().is_empty());
+}
+
+#[test]
+fn test_scene_bounding_box() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "accessors": [
+            { "componentType": 5126, "count": 8, "type": "VEC3",
+              "min": [0.0, 0.0, 0.0], "max": [1.0, 1.0, 1.0] }
+        ],
+        "meshes": [ { "primitives": [ { "attributes": { "POSITION": 0 } } ] } ],
+        "scenes": [ { "nodes": [0, 2] }, { "nodes": [3] } ],
+        "nodes": [
+            { "children": [1], "translation": [10.0, 0.0, 0.0] },
+            { "mesh": 0, "rotation": [0.0, 0.0, 1.0, 0.0] },
+            { "mesh": 0, "scale": [2.0, 2.0, 2.0] },
+            {}
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    let bounds = gltf.scenes().nth(0).unwrap().bounding_box();
+    assert_eq!(bounds.min, [0.0, -1.0, 0.0]);
+    assert_eq!(bounds.max, [10.0, 2.0, 2.0]);
+
+    let bounds = gltf.scenes().nth(1).unwrap().bounding_box();
+    assert!(bounds.min.iter().zip(&bounds.max).all(|(min, max)| min > max));
+}
+
+#[test]
+fn test_scene_bounding_box_of_quantized_positions() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "extensionsUsed": [ "KHR_mesh_quantization" ],
+        "extensionsRequired": [ "KHR_mesh_quantization" ],
+        "accessors": [
+            { "componentType": 5122, "normalized": true, "count": 8, "type": "VEC3",
+              "min": [-32767, 0, 0], "max": [32767, 32767, 0] },
+            { "componentType": 5121, "normalized": true, "count": 8, "type": "VEC3",
+              "min": [0, 0, 0], "max": [255, 255, 255] },
+            { "componentType": 5123, "count": 8, "type": "VEC3",
+              "min": [0, 0, 0], "max": [4, 4, 4] }
+        ],
+        "meshes": [
+            { "primitives": [ { "attributes": { "POSITION": 0 } } ] },
+            { "primitives": [ { "attributes": { "POSITION": 1 } } ] },
+            { "primitives": [ { "attributes": { "POSITION": 2 } } ] }
+        ],
+        "scenes": [ { "nodes": [0, 1] }, { "nodes": [2] } ],
+        "nodes": [
+            { "mesh": 0, "scale": [2.0, 2.0, 2.0] },
+            { "mesh": 1, "translation": [0.0, 0.0, 1.0] },
+            { "mesh": 2 }
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    let bounds = gltf.scenes().nth(0).unwrap().bounding_box();
+    assert_eq!(bounds.min, [-2.0, 0.0, 0.0]);
+    assert_eq!(bounds.max, [2.0, 2.0, 2.0]);
+
+    // Bounds of positions that are not normalized are used as is.
+    let bounds = gltf.scenes().nth(1).unwrap().bounding_box();
+    assert_eq!(bounds.min, [0.0, 0.0, 0.0]);
+    assert_eq!(bounds.max, [4.0, 4.0, 4.0]);
+}
+
+#[test]
+fn test_animation_duration() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 12 } ],
+        "bufferViews": [ { "buffer": 0, "byteLength": 12 } ],
+        "accessors": [
+            { "bufferView": 0, "componentType": 5126, "count": 3, "type": "SCALAR",
+              "min": [0.0], "max": [2.0] },
+            { "bufferView": 0, "componentType": 5126, "count": 2, "type": "SCALAR",
+              "min": [0.5], "max": [1.5] },
+            { "bufferView": 0, "componentType": 5126, "count": 3, "type": "SCALAR" }
+        ],
+        "nodes": [ {} ],
+        "animations": [
+            {
+                "samplers": [ { "input": 1, "output": 2 }, { "input": 0, "output": 2 } ],
+                "channels": [
+                    { "sampler": 0, "target": { "node": 0, "path": "translation" } },
+                    { "sampler": 1, "target": { "node": 0, "path": "scale" } }
+                ]
+            },
+            { "samplers": [], "channels": [] }
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice_without_validation(json).unwrap();
+    assert_eq!(gltf.animations().nth(0).unwrap().duration(), 2.0);
+    assert_eq!(gltf.animations().nth(1).unwrap().duration(), 0.0);
+}
+
+#[cfg(feature = "names")]
+#[test]
+fn test_lookup_by_name() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "materials": [ { "name": "Metal" }, { "name": "Wood" }, { "name": "Wood" } ],
+        "meshes": [ { "name": "Cube", "primitives": [] } ],
+        "nodes": [ {}, { "name": "Head" }, { "name": "Head" } ],
+        "samplers": [ { "name": "Nearest" } ],
+        "skins": [ { "name": "Armature", "joints": [1] } ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice_without_validation(json).unwrap();
+    assert_eq!(gltf.material_by_name("Wood").and_then(|material| material.index()), Some(1));
+    assert!(gltf.material_by_name("Glass").is_none());
+    assert_eq!(gltf.mesh_by_name("Cube").map(|mesh| mesh.index()), Some(0));
+    assert_eq!(gltf.node_by_name("Head").map(|node| node.index()), Some(1));
+    assert!(gltf.node_by_name("").is_none());
+    assert_eq!(gltf.samplers().nth(0).unwrap().name(), Some("Nearest"));
+    assert_eq!(gltf.skins().nth(0).unwrap().name(), Some("Armature"));
+}
+
+#[test]
+fn test_triangle_and_vertex_counts() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "accessors": [
+            { "componentType": 5126, "count": 6, "type": "VEC3",
+              "min": [0.0, 0.0, 0.0], "max": [1.0, 1.0, 1.0] },
+            { "componentType": 5123, "count": 9, "type": "SCALAR" }
+        ],
+        "meshes": [
+            {
+                "primitives": [
+                    { "attributes": { "POSITION": 0 }, "indices": 1 },
+                    { "attributes": { "POSITION": 0 }, "mode": 5 },
+                    { "attributes": { "POSITION": 0 }, "mode": 1 }
+                ]
+            },
+            { "primitives": [ { "attributes": { "POSITION": 0 } } ] }
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    let mesh = gltf.meshes().nth(0).unwrap();
+    assert_eq!(mesh.triangle_count(), 3 + 4);
+    assert_eq!(mesh.vertex_count(), 18);
+    assert_eq!(gltf.triangle_count(), 3 + 4 + 2);
+    assert_eq!(gltf.vertex_count(), 24);
+
+    let gltf = gltf::Gltf::open("examples/Box.gltf").unwrap();
+    assert_eq!((gltf.triangle_count(), gltf.vertex_count()), (12, 24));
+}
+
+#[cfg(feature = "names")]
+#[test]
+fn test_remove_node() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "accessors": [
+            { "componentType": 5126, "count": 2, "type": "SCALAR", "min": [0.0], "max": [1.0] },
+            { "componentType": 5126, "count": 2, "type": "VEC3" }
+        ],
+        "animations": [
+            {
+                "channels": [
+                    { "sampler": 0, "target": { "node": 1, "path": "translation" } }
+                ],
+                "samplers": [ { "input": 0, "output": 1 } ]
+            },
+            {
+                "channels": [
+                    { "sampler": 0, "target": { "node": 1, "path": "translation" } },
+                    { "sampler": 0, "target": { "node": 3, "path": "translation" } }
+                ],
+                "samplers": [ { "input": 0, "output": 1 } ]
+            }
+        ],
+        "nodes": [
+            { "name": "root", "children": [1, 3] },
+            { "name": "removed", "children": [2] },
+            { "name": "orphan" },
+            { "name": "joint" }
+        ],
+        "scenes": [ { "nodes": [0, 1] } ],
+        "skins": [ { "joints": [3], "skeleton": 1 } ]
+    }"#;
+    let mut gltf = gltf::Gltf::from_slice(json).unwrap();
+    match gltf.document.clone().remove_node(3) {
+        Err(gltf::Error::Referenced { path }) => assert_eq!(path.0, "skins[0].joints[0]"),
+        other => panic!("expected Error::Referenced, got {:?}", other),
+    }
+
+    let modified = gltf.remove_node(1).unwrap();
+    let modified: Vec<_> = modified.iter().map(|path| path.as_str()).collect();
+    assert_eq!(modified, ["animations[0]", "nodes[0]", "scenes[0]", "skins[0]"]);
+    assert!(gltf.validate_lenient().errors.is_empty());
+
+    let names: Vec<_> = gltf.nodes().map(|node| node.name().unwrap().to_string()).collect();
+    assert_eq!(names, ["root", "orphan", "joint"]);
+    let root = gltf.nodes().nth(0).unwrap();
+    let children: Vec<_> = root.children().map(|child| child.index()).collect();
+    assert_eq!(children, [2]);
+    let roots: Vec<_> = gltf.scenes().nth(0).unwrap().nodes().map(|node| node.index()).collect();
+    assert_eq!(roots, [0]);
+    let skin = gltf.skins().nth(0).unwrap();
+    assert_eq!(skin.joints().nth(0).unwrap().name(), Some("joint"));
+    assert!(skin.skeleton().is_none());
+    // The first animation only targeted the removed node.
+    assert_eq!(gltf.animations().len(), 1);
+    let targets: Vec<_> = gltf.animations().nth(0).unwrap()
+        .channels()
+        .map(|channel| channel.target().node().name().map(String::from))
+        .collect();
+    assert_eq!(targets, [Some("joint".to_string())]);
+}
+
+#[cfg(feature = "names")]
+#[test]
+fn test_remove_mesh_and_material() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "accessors": [
+            { "componentType": 5126, "count": 3, "type": "VEC3",
+              "min": [0.0, 0.0, 0.0], "max": [1.0, 1.0, 1.0] }
+        ],
+        "materials": [ { "name": "a" }, { "name": "b" } ],
+        "meshes": [
+            { "name": "a", "primitives": [ { "attributes": { "POSITION": 0 }, "material": 0 } ] },
+            { "name": "b", "primitives": [ { "attributes": { "POSITION": 0 }, "material": 1 } ] }
+        ],
+        "nodes": [ { "mesh": 0, "weights": [] }, { "mesh": 1 } ]
+    }"#;
+    let mut gltf = gltf::Gltf::from_slice(json).unwrap();
 
+    let modified = gltf.remove_material(0);
+    assert_eq!(modified.len(), 1);
+    assert_eq!(modified[0].as_str(), "meshes[0].primitives[0]");
+    let materials: Vec<_> = gltf.meshes()
+        .map(|mesh| mesh.primitives().nth(0).unwrap().material().name().map(String::from))
+        .collect();
+    assert_eq!(materials, [None, Some("b".to_string())]);
+
+    let modified = gltf.remove_mesh(0);
+    assert_eq!(modified.len(), 1);
+    assert_eq!(modified[0].as_str(), "nodes[0]");
+    assert!(gltf.nodes().nth(0).unwrap().mesh().is_none());
+    assert!(gltf.nodes().nth(0).unwrap().weights().is_none());
+    assert_eq!(gltf.nodes().nth(1).unwrap().mesh().unwrap().name(), Some("b"));
+    assert!(gltf.validate_lenient().errors.is_empty());
+}
+
+#[test]
+fn test_set_default_scene_and_scene_nodes() {
+    use gltf::json::validation::Error as ValidationError;
+
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "nodes": [ {}, {}, {} ],
+        "scenes": [ { "nodes": [0] }, { "nodes": [] } ]
+    }"#;
+    let mut gltf = gltf::Gltf::from_slice(json).unwrap();
+    assert!(gltf.default_scene().is_none());
+
+    gltf.set_default_scene(1).unwrap();
+    assert_eq!(gltf.default_scene().unwrap().index(), 1);
+    match gltf.set_default_scene(2) {
+        Err(gltf::Error::Validation(errs)) => {
+            assert_eq!(errs, [(gltf::json::Path("scene".into()), ValidationError::IndexOutOfBounds)]);
+        },
+        other => panic!("expected validation error, got {:?}", other),
+    }
+    assert_eq!(gltf.default_scene().unwrap().index(), 1);
+
+    gltf.set_scene_nodes(1, &[1, 2]).unwrap();
+    let nodes: Vec<_> = gltf.scenes().nth(1).unwrap().nodes().map(|node| node.index()).collect();
+    assert_eq!(nodes, [1, 2]);
+    match gltf.set_scene_nodes(0, &[2, 3, 4]) {
+        Err(gltf::Error::Validation(errs)) => {
+            let paths: Vec<_> = errs.iter().map(|&(ref path, _)| path.as_str()).collect();
+            assert_eq!(paths, ["scenes[0].nodes[1]", "scenes[0].nodes[2]"]);
+        },
+        other => panic!("expected validation error, got {:?}", other),
+    }
+    let nodes: Vec<_> = gltf.scenes().nth(0).unwrap().nodes().map(|node| node.index()).collect();
+    assert_eq!(nodes, [0]);
+
+    let json = gltf.document.clone().into_json();
+    assert_eq!(json.scene.unwrap().value(), 1);
+    assert!(gltf::Document::from_json(json).is_ok());
+}
+
+#[test]
+fn test_image_color_spaces() {
+    use gltf::image::ColorSpace::{Linear, Srgb};
+
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "images": [
+            { "uri": "base_color.png" },
+            { "uri": "normal.png" },
+            { "uri": "shared.png" },
+            { "uri": "unused.png" }
+        ],
+        "textures": [ { "source": 0 }, { "source": 1 }, { "source": 2 } ],
+        "materials": [
+            {
+                "pbrMetallicRoughness": {
+                    "baseColorTexture": { "index": 0 },
+                    "metallicRoughnessTexture": { "index": 2 }
+                },
+                "normalTexture": { "index": 1 }
+            },
+            { "emissiveTexture": { "index": 2 }, "occlusionTexture": { "index": 1 } }
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    assert_eq!(gltf.image_color_spaces(), [Some(Srgb), Some(Linear), Some(Srgb), None]);
+}
+
+#[test]
+fn test_joint_nodes() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "nodes": [ { "children": [1] }, { "children": [2] }, {}, {} ],
+        "skins": [ { "joints": [1, 2] }, { "joints": [2, 3] } ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    let mut joints: Vec<_> = gltf.joint_nodes().into_iter().collect();
+    joints.sort();
+    assert_eq!(joints, [1, 2, 3]);
+    let is_joint: Vec<_> = gltf.nodes().map(|node| node.is_joint()).collect();
+    assert_eq!(is_joint, [false, true, true, true]);
+
+    let gltf = gltf::Gltf::open("examples/Box.gltf").unwrap();
+    assert!(gltf.joint_nodes().is_empty());
+    assert!(gltf.nodes().all(|node| !node.is_joint()));
+}
+
+#[test]
+fn test_default_material() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "accessors": [
+            { "componentType": 5126, "count": 3, "type": "VEC3",
+              "min": [0.0, 0.0, 0.0], "max": [1.0, 1.0, 1.0] }
+        ],
+        "materials": [ { "pbrMetallicRoughness": { "roughnessFactor": 0.5 } } ],
+        "meshes": [
+            {
+                "primitives": [
+                    { "attributes": { "POSITION": 0 } },
+                    { "attributes": { "POSITION": 0 }, "material": 0 }
+                ]
+            }
+        ]
+    }"#;
+    let gltf = gltf::Gltf::from_slice(json).unwrap();
+    let material = gltf.default_material();
+    assert!(material.index().is_none());
+    let pbr = material.pbr_metallic_roughness();
+    assert_eq!(pbr.base_color_factor(), [1.0, 1.0, 1.0, 1.0]);
+    assert_eq!(pbr.metallic_factor(), 1.0);
+    assert_eq!(pbr.roughness_factor(), 1.0);
+    assert!(pbr.base_color_texture().is_none());
+    assert_eq!(material.emissive_factor(), [0.0, 0.0, 0.0]);
+    match material.alpha_mode() {
+        gltf::material::AlphaMode::Opaque => {},
+        other => panic!("expected opaque alpha mode, got {:?}", other),
+    }
+    assert!(!material.double_sided());
+    assert_eq!(gltf.materials().count(), 1);
+
+    let mesh = gltf.meshes().nth(0).unwrap();
+    let primitive = mesh.primitives().nth(0).unwrap();
+    let defaulted = primitive.material();
+    assert!(defaulted.index().is_none());
+    assert_eq!(defaulted.pbr_metallic_roughness().roughness_factor(), 1.0);
+    let primitive = mesh.primitives().nth(1).unwrap();
+    let explicit = primitive.material();
+    assert_eq!(explicit.index(), Some(0));
+    assert_eq!(explicit.pbr_metallic_roughness().roughness_factor(), 0.5);
+}
+
+#[test]
+fn test_float_joints_fail_validation() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 28 } ],
+        "bufferViews": [ { "buffer": 0, "byteLength": 28 } ],
+        "accessors": [
+            { "bufferView": 0, "componentType": 5126, "count": 1, "type": "VEC3",
+              "min": [0.0, 0.0, 0.0], "max": [0.0, 0.0, 0.0] },
+            { "bufferView": 0, "byteOffset": 12, "componentType": 5126, "count": 1, "type": "VEC4" }
+        ],
+        "meshes": [
+            { "primitives": [ { "attributes": { "POSITION": 0, "JOINTS_0": 1 } } ] }
+        ]
+    }"#;
+    match gltf::Gltf::from_slice(json) {
+        Err(gltf::Error::Validation(errs)) => {
+            let paths: Vec<_> = errs.iter().map(|&(ref path, _)| path.as_str()).collect();
+            assert_eq!(paths, ["meshes[0].primitives[0].attributes[\"JOINTS_0\"]"]);
+        },
+        other => panic!("expected validation error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_accessor_without_buffer_view_roundtrip() {
+    let json = r#"{
+        "asset": { "version": "2.0" },
+        "accessors": [ { "componentType": 5126, "count": 2, "type": "SCALAR" } ]
+    }"#;
+    let root = gltf::json::Root::from_str(json).unwrap();
+    let string = root.to_string().unwrap();
+    assert!(!string.contains("\"bufferView\""), "{}", string);
+    let root = gltf::json::Root::from_str(&string).unwrap();
+    assert!(root.accessors[0].buffer_view.is_none());
+}
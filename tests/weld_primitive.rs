@@ -0,0 +1,87 @@
+extern crate byteorder;
+extern crate gltf;
+
+use byteorder::{ByteOrder, LE};
+use gltf::mesh::util::weld::{weld_primitive, Options, Vertex};
+
+const JSON: &'static str = r#"{
+    "asset": { "version": "2.0" },
+    "buffers": [ { "byteLength": 150 } ],
+    "bufferViews": [
+        { "buffer": 0, "byteOffset": 0, "byteLength": 72 },
+        { "buffer": 0, "byteOffset": 72, "byteLength": 72 },
+        { "buffer": 0, "byteOffset": 144, "byteLength": 6 }
+    ],
+    "accessors": [
+        { "bufferView": 0, "componentType": 5126, "count": 6, "type": "VEC3",
+          "min": [0.0, 0.0, 0.0], "max": [1.0, 1.0, 0.0] },
+        { "bufferView": 1, "componentType": 5126, "count": 6, "type": "VEC3" },
+        { "bufferView": 2, "componentType": 5123, "count": 3, "type": "SCALAR" }
+    ],
+    "meshes": [
+        {
+            "primitives": [
+                { "attributes": { "POSITION": 0, "NORMAL": 1 } },
+                { "attributes": { "POSITION": 0, "NORMAL": 1 }, "indices": 2 }
+            ]
+        }
+    ]
+}"#;
+
+fn buffer_data() -> Vec<u8> {
+    let positions = [
+        0.0, 0.0, 0.0,
+        1.0, 0.0, 0.0,
+        0.0, 1.0, 0.0,
+        0.0, 1.0, 0.0,
+        1.0, 0.0, 0.0,
+        1.0, 1.0, 0.0,
+    ];
+    let normals = [
+        0.0, 0.0, 1.0,
+        0.0, 0.0, 1.0,
+        0.0, 0.0, 1.0,
+        0.0, 1.0, 0.0,
+        0.0, 0.0, 1.0,
+        0.0, 0.0, 1.0,
+    ];
+    let mut data = vec![0; 150];
+    LE::write_f32_into(&positions, &mut data[0 .. 72]);
+    LE::write_f32_into(&normals, &mut data[72 .. 144]);
+    LE::write_u16_into(&[0, 1, 6], &mut data[144 .. 150]);
+    data
+}
+
+#[test]
+fn weld_triangle_soup() {
+    let gltf = gltf::Gltf::from_slice(JSON.as_bytes()).unwrap();
+    let data = buffer_data();
+    let mesh = gltf.meshes().nth(0).unwrap();
+    let primitive = mesh.primitives().nth(0).unwrap();
+    let reader = primitive.reader(|_| Some(&data));
+
+    let (vertices, indices) = weld_primitive(&reader, &Options::default()).unwrap();
+    assert_eq!(vertices.len(), 5);
+    assert_eq!(indices, [0, 1, 2, 3, 1, 4]);
+    assert_eq!(vertices[3], Vertex {
+        position: [0.0, 1.0, 0.0],
+        normal: [0.0, 1.0, 0.0],
+        tex_coord: [0.0, 0.0],
+    });
+
+    let options = Options { normals: false, ..Options::default() };
+    let (vertices, indices) = weld_primitive(&reader, &options).unwrap();
+    assert_eq!(vertices.len(), 4);
+    assert_eq!(indices, [0, 1, 2, 2, 1, 3]);
+    assert_eq!(vertices[2].normal, [0.0, 0.0, 1.0]);
+}
+
+#[test]
+fn weld_out_of_range_index() {
+    let gltf = gltf::Gltf::from_slice(JSON.as_bytes()).unwrap();
+    let data = buffer_data();
+    let mesh = gltf.meshes().nth(0).unwrap();
+    let primitive = mesh.primitives().nth(1).unwrap();
+    let reader = primitive.reader(|_| Some(&data));
+    assert!(weld_primitive(&reader, &Options::default()).is_none());
+}